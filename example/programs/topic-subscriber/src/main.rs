@@ -34,14 +34,19 @@ async fn main() -> Result<(), Box<dyn Error>> {
         info!("Received subscription item: {item:?}");
         let value: Result<String, MomentoError> = item.try_into();
         match value {
-            Ok(v) => {
-                let o: MomentoModel = serde_json::from_str(v.as_str())?;
-                info!(
-                    "(Value)={}|(MoModel)={o:?}|(MillisecondsBetween)={}",
-                    v,
-                    o.milliseconds_between_publish_and_received()
-                );
-            }
+            Ok(v) => match serde_json::from_str::<MomentoModel>(v.as_str()) {
+                Ok(o) => {
+                    info!(
+                        "(Value)={}|(MoModel)={o:?}|(MillisecondsBetween)={}",
+                        v,
+                        o.milliseconds_between_publish_and_received()
+                    );
+                }
+                // Skip malformed messages rather than ending the subscription
+                Err(e) => {
+                    error!("(Error Deserializing)={}|(Value)={}", e, v);
+                }
+            },
             Err(e) => {
                 error!("(Error Momento)={}", e);
             }
@@ -5,7 +5,7 @@ use crate::auth::auth_client_builder::{AuthClientBuilder, NeedsCredentialProvide
 use crate::grpc::header_interceptor::HeaderInterceptor;
 
 use crate::auth::messages::MomentoRequest;
-use crate::{utils, CredentialProvider, MomentoResult};
+use crate::{utils, CredentialProvider, MomentoError, MomentoErrorCode, MomentoResult};
 
 use crate::auth::expiration::ExpiresIn;
 
@@ -122,6 +122,67 @@ impl AuthClient {
         request.send(self).await
     }
 
+    /// Generates a replacement for a disposable token that has not expired yet.
+    ///
+    /// The new token has the same permission scope and token id as `existing_token`, and is valid
+    /// for the same duration, counted from now. The existing token remains valid until it expires.
+    ///
+    /// Disposable tokens cannot generate other tokens, so this uses the credentials of this
+    /// client, like [generate_disposable_token](AuthClient::generate_disposable_token).
+    ///
+    /// # Arguments
+    ///
+    /// * `existing_token` - The response of the request that generated the token to refresh.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `FailedPreconditionError` if `existing_token` has already expired.
+    ///
+    /// # Example
+    /// Assumes that an AuthClient named `auth_client` has been created and is available.
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use momento_test_util::create_doctest_auth_client;
+    /// # tokio_test::block_on(async {
+    /// # let auth_client = create_doctest_auth_client();
+    /// use momento::auth::{ExpiresIn, DisposableTokenScopes};
+    ///
+    /// let permission_scope = DisposableTokenScopes::cache_key_read_write("cache", "key");
+    /// let token = auth_client
+    ///     .generate_disposable_token(permission_scope, ExpiresIn::minutes(5))
+    ///     .await?;
+    ///
+    /// // Later, before the token expires
+    /// let refreshed_token = auth_client.refresh_disposable_token(&token).await?;
+    /// # assert_eq!(refreshed_token.scope(), token.scope());
+    /// println!("Refreshed disposable token: {}", refreshed_token);
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    pub async fn refresh_disposable_token(
+        &self,
+        existing_token: &GenerateDisposableTokenResponse,
+    ) -> MomentoResult<GenerateDisposableTokenResponse> {
+        if existing_token.clone().expires_at().is_expired() {
+            return Err(MomentoError {
+                message: "Disposable token has already expired and cannot be refreshed".into(),
+                error_code: MomentoErrorCode::FailedPreconditionError,
+                inner_error: None,
+                details: None,
+                context: None,
+            });
+        }
+        let mut request = GenerateDisposableTokenRequest::new(
+            existing_token.scope().clone(),
+            existing_token.expires_in().clone(),
+        );
+        if let Some(token_id) = existing_token.token_id() {
+            request = request.token_id(token_id.to_string());
+        }
+        request.send(self).await
+    }
+
     /// Lower-level API to send any type of MomentoRequest to the server. This is used for cases when
     /// you want to set optional fields on a request that are not supported by the short-hand API for
     /// that request type.
@@ -13,6 +13,7 @@ pub struct NeedsCredentialProvider(pub ());
 
 pub struct ReadyToBuild {
     credential_provider: CredentialProvider,
+    user_agent: Option<String>,
 }
 
 impl AuthClientBuilder<NeedsCredentialProvider> {
@@ -22,13 +23,31 @@ impl AuthClientBuilder<NeedsCredentialProvider> {
     ) -> AuthClientBuilder<ReadyToBuild> {
         AuthClientBuilder(ReadyToBuild {
             credential_provider,
+            user_agent: Some(utils::user_agent("auth")),
         })
     }
 }
 
 impl AuthClientBuilder<ReadyToBuild> {
+    /// Overrides the value of the `agent` header the client sends to identify the SDK.
+    pub fn with_user_agent(self, user_agent: impl Into<String>) -> AuthClientBuilder<ReadyToBuild> {
+        AuthClientBuilder(ReadyToBuild {
+            user_agent: Some(user_agent.into()),
+            ..self.0
+        })
+    }
+
+    /// Stops the client from sending the `agent` and `runtime-version` headers that identify
+    /// the SDK and its version.
+    pub fn without_user_agent(self) -> AuthClientBuilder<ReadyToBuild> {
+        AuthClientBuilder(ReadyToBuild {
+            user_agent: None,
+            ..self.0
+        })
+    }
+
     pub fn build(self) -> MomentoResult<AuthClient> {
-        let agent_value = &utils::user_agent("auth");
+        let agent_value = self.0.user_agent.as_deref();
         let channel = connect_channel_lazily(&self.0.credential_provider.token_endpoint)?;
         let authorized_channel = InterceptedService::new(
             channel,
@@ -1,5 +1,6 @@
 use crate::{MomentoError, MomentoErrorCode, MomentoResult};
 use derive_more::Display;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 /// Trait for determining if an object expires.
 pub trait Expiration {
@@ -75,6 +76,7 @@ impl ExpiresIn {
                 error_code: MomentoErrorCode::InvalidArgumentError,
                 inner_error: None,
                 details: None,
+                context: None,
             }),
         }
     }
@@ -118,6 +120,24 @@ impl ExpiresAt {
     pub fn epoch(&self) -> u64 {
         self.valid_until
     }
+
+    /// Returns the time remaining until the object expires, or None if it has already expired.
+    /// If the object never expires, it will return `Duration::MAX`.
+    pub fn remaining(&self) -> Option<Duration> {
+        if !self.does_expire() {
+            return Some(Duration::MAX);
+        }
+        let expires_at = UNIX_EPOCH + Duration::from_secs(self.valid_until);
+        match expires_at.duration_since(SystemTime::now()) {
+            Ok(remaining) if !remaining.is_zero() => Some(remaining),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the object expires and its expiration time has passed.
+    pub fn is_expired(&self) -> bool {
+        self.remaining().is_none()
+    }
 }
 
 #[cfg(test)]
@@ -176,4 +196,35 @@ mod tests {
         assert_eq!(expires_at.epoch(), now);
         assert!(expires_at.does_expire());
     }
+
+    #[test]
+    fn expires_at_remaining_when_never_expires() {
+        let expires_at = super::ExpiresAt::new(None);
+        assert_eq!(expires_at.remaining(), Some(std::time::Duration::MAX));
+        assert!(!expires_at.is_expired());
+    }
+
+    #[test]
+    fn expires_at_remaining_when_in_the_future() {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let expires_at = super::ExpiresAt::from_epoch(now + 60);
+        let remaining = expires_at.remaining().expect("should not be expired");
+        assert!(remaining <= std::time::Duration::from_secs(60));
+        assert!(remaining > std::time::Duration::from_secs(55));
+        assert!(!expires_at.is_expired());
+    }
+
+    #[test]
+    fn expires_at_remaining_when_in_the_past() {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let expires_at = super::ExpiresAt::from_epoch(now - 60);
+        assert_eq!(expires_at.remaining(), None);
+        assert!(expires_at.is_expired());
+    }
 }
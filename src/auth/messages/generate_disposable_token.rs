@@ -3,7 +3,7 @@ use crate::{
         expiration::{ExpiresAt, ExpiresIn},
         permissions::disposable_token_scope::DisposableTokenScope,
     },
-    utils::is_disposable_token_id_valid,
+    utils::{is_disposable_token_id_valid, is_disposable_token_scope_valid},
     AuthClient, MomentoResult,
 };
 
@@ -23,7 +23,8 @@ pub struct DisposableTokenProps {
 ///
 /// # Arguments
 ///
-/// * `scope` - The permission scope that the token will have.
+/// * `scope` - The permission scope that the token will have. Key prefixes in the scope must be
+///   non-empty.
 /// * `expires_in` - The duration for which the token will be valid.
 ///
 /// # Optional Arguments
@@ -84,22 +85,18 @@ impl MomentoRequest for GenerateDisposableTokenRequest {
     type Response = GenerateDisposableTokenResponse;
 
     async fn send(self, client: &AuthClient) -> MomentoResult<Self::Response> {
+        is_disposable_token_scope_valid(&self.scope)?;
+        let token_id = self.props.and_then(|props| props.token_id);
+        if let Some(token_id) = &token_id {
+            is_disposable_token_id_valid(token_id)?;
+        }
         let request = momento_protos::token::GenerateDisposableTokenRequest {
             expires: Some(Expires {
                 valid_for_seconds: self.expires_in.to_seconds() as u32,
             }),
             auth_token: client.credentials().auth_token,
-            permissions: Some(permissions_from_disposable_token_scope(self.scope)),
-            token_id: match self.props {
-                Some(props) => match props.token_id {
-                    Some(token_id) => {
-                        is_disposable_token_id_valid(&token_id)?;
-                        token_id
-                    }
-                    None => "".to_string(),
-                },
-                None => "".to_string(),
-            },
+            permissions: Some(permissions_from_disposable_token_scope(self.scope.clone())),
+            token_id: token_id.clone().unwrap_or_default(),
         };
         let response = client
             .token_client()
@@ -118,16 +115,25 @@ impl MomentoRequest for GenerateDisposableTokenRequest {
             auth_token,
             endpoint: response.endpoint,
             expires_at: ExpiresAt::from_epoch(response.valid_until),
+            scope: self.scope,
+            expires_in: self.expires_in,
+            token_id,
         })
     }
 }
 
 /// Response for a generate disposable token operation.
+///
+/// Besides the token, the response keeps the scope, validity duration and token id it was
+/// generated with, so that [AuthClient::refresh_disposable_token] can mint a replacement.
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct GenerateDisposableTokenResponse {
     auth_token: String,
     endpoint: String,
     expires_at: ExpiresAt,
+    scope: DisposableTokenScope,
+    expires_in: ExpiresIn,
+    token_id: Option<String>,
 }
 
 impl std::fmt::Display for GenerateDisposableTokenResponse {
@@ -155,4 +161,19 @@ impl GenerateDisposableTokenResponse {
     pub fn expires_at(self) -> ExpiresAt {
         self.expires_at
     }
+
+    /// Returns the permission scope the token was generated with.
+    pub fn scope(&self) -> &DisposableTokenScope {
+        &self.scope
+    }
+
+    /// Returns the duration the token was generated to be valid for.
+    pub fn expires_in(&self) -> &ExpiresIn {
+        &self.expires_in
+    }
+
+    /// Returns the token id the token was generated with, if any.
+    pub fn token_id(&self) -> Option<&str> {
+        self.token_id.as_deref()
+    }
 }
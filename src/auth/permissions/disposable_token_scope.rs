@@ -4,7 +4,7 @@ use super::permission_scope::{CacheRole, CacheSelector, Permissions};
 use derive_more::Display;
 
 /// A key for a specific item in a cache.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CacheItemKey {
     /// The cache item key
     pub key: Vec<u8>,
@@ -26,7 +26,7 @@ impl<K: IntoBytes> From<K> for CacheItemKey {
 }
 
 /// A key prefix for items in a cache.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CacheItemKeyPrefix {
     /// The key prefix
     pub key_prefix: Vec<u8>,
@@ -49,7 +49,7 @@ impl<K: IntoBytes> From<K> for CacheItemKeyPrefix {
 
 /// A component of a [DisposableTokenCachePermission].
 /// Specifies the cache item(s) to which the permission applies.
-#[derive(Debug, Display, Clone, PartialEq)]
+#[derive(Debug, Display, Clone, PartialEq, Eq)]
 pub enum CacheItemSelector {
     /// Access to all cache items
     AllCacheItems,
@@ -61,7 +61,7 @@ pub enum CacheItemSelector {
 
 /// A permission to be granted to a new disposable access token, specifying
 /// access to specific cache items.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct DisposableTokenCachePermission {
     /// The type of access granted by the permission.
     pub role: CacheRole,
@@ -82,7 +82,7 @@ impl std::fmt::Display for DisposableTokenCachePermission {
 }
 
 /// A set of permissions to be granted to a new disposable access token.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct DisposableTokenCachePermissions {
     pub(crate) permissions: Vec<DisposableTokenCachePermission>,
 }
@@ -98,7 +98,7 @@ impl std::fmt::Display for DisposableTokenCachePermissions {
 }
 
 /// The permission scope for creating a new disposable access token.
-#[derive(Debug, Display, Clone, PartialEq)]
+#[derive(Debug, Display, Clone, PartialEq, Eq)]
 pub enum DisposableTokenScope {
     /// Set of permissions to be granted to a new token on the level of a cache or topic
     Permissions(Permissions),
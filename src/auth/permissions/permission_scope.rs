@@ -2,7 +2,7 @@ use derive_more::Display;
 
 /// A component of a [CachePermission].
 /// Type of access granted by the permission.
-#[derive(Debug, Display, Clone, PartialEq)]
+#[derive(Debug, Display, Clone, PartialEq, Eq)]
 pub enum CacheRole {
     /// Allows read-write access to a cache
     ReadWrite,
@@ -14,7 +14,7 @@ pub enum CacheRole {
 
 /// A component of a [CachePermission].
 /// A permission can be restricted to a specific cache or to all caches.
-#[derive(Debug, Display, Clone, PartialEq)]
+#[derive(Debug, Display, Clone, PartialEq, Eq)]
 pub enum CacheSelector {
     /// Apply permission to all caches
     AllCaches,
@@ -42,7 +42,7 @@ impl From<&str> for CacheSelector {
 }
 
 /// Defines access permissions for a cache.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CachePermission {
     /// The type of access granted by the permission.
     pub role: CacheRole,
@@ -62,7 +62,7 @@ impl std::fmt::Display for CachePermission {
 
 /// A component of a [TopicPermission].
 /// Type of access granted by the permission.
-#[derive(Debug, Display, Clone, PartialEq)]
+#[derive(Debug, Display, Clone, PartialEq, Eq)]
 pub enum TopicRole {
     /// Allows both publishing and subscribing to a topic
     PublishSubscribe,
@@ -74,7 +74,7 @@ pub enum TopicRole {
 
 /// A component of a [TopicPermission].
 /// A permission can be restricted to a specific topic or to all topics in a cache.
-#[derive(Debug, Display, Clone, PartialEq)]
+#[derive(Debug, Display, Clone, PartialEq, Eq)]
 pub enum TopicSelector {
     /// Apply permission to all topics
     AllTopics,
@@ -102,7 +102,7 @@ impl From<&str> for TopicSelector {
 }
 
 /// Defines access permissions for a topic in a cache.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TopicPermission {
     /// The type of access granted by the permission.
     pub role: TopicRole,
@@ -123,7 +123,7 @@ impl std::fmt::Display for TopicPermission {
 }
 
 /// A component of a [PermissionScope].
-#[derive(Debug, Display, Clone, PartialEq)]
+#[derive(Debug, Display, Clone, PartialEq, Eq)]
 pub enum Permission {
     /// Defines the permissions for a cache.
     CachePermission(CachePermission),
@@ -132,7 +132,7 @@ pub enum Permission {
 }
 
 /// Permissions object contains the set of permissions to be granted to a new API key.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Permissions {
     /// The set of permissions to be granted to a new API key.
     pub permissions: Vec<Permission>,
@@ -164,7 +164,7 @@ impl Permissions {
 }
 
 /// The permission scope for creating a new API key.
-#[derive(Debug, Display, Clone, PartialEq)]
+#[derive(Debug, Display, Clone, PartialEq, Eq)]
 pub enum PermissionScope {
     /// Set of permissions to be granted to a new API key
     Permissions(Permissions),
@@ -0,0 +1,397 @@
+use std::future::Future;
+
+use tokio::runtime::{Builder, Handle, Runtime};
+
+use crate::cache::{
+    DeleteResponse, DictionaryFetchResponse, DictionaryGetFieldResponse,
+    DictionarySetFieldResponse, GetResponse, IncrementResponse, ItemGetTtlResponse,
+    KeyExistsResponse, ListConcatenateBackResponse, ListFetchResponse, ListPushBackResponse,
+    MomentoRequest, SetAddElementsResponse, SetFetchResponse, SetIfAbsentResponse,
+    SetRemoveElementsResponse, SetResponse, SortedSetFetchResponse, SortedSetOrder,
+    SortedSetPutElementResponse,
+};
+use crate::{
+    ErrorSource, IntoBytes, IntoBytesIterable, MomentoError, MomentoErrorCode, MomentoResult,
+};
+
+/// The runtime a blocking client runs its requests on.
+pub(crate) enum BlockingRuntime {
+    /// A current-thread runtime owned by the client.
+    Owned(Runtime),
+    /// A runtime shared with the rest of the application.
+    Shared(Handle),
+}
+
+impl BlockingRuntime {
+    pub(crate) fn new(handle: Option<Handle>) -> MomentoResult<Self> {
+        match handle {
+            Some(handle) => Ok(BlockingRuntime::Shared(handle)),
+            None => Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .map(BlockingRuntime::Owned)
+                .map_err(|e| MomentoError {
+                    message: "Unable to start a runtime for the blocking client".to_string(),
+                    error_code: MomentoErrorCode::UnknownError,
+                    inner_error: Some(ErrorSource::Unknown(Box::new(e))),
+                    details: None,
+                    context: None,
+                }),
+        }
+    }
+
+    pub(crate) fn handle(&self) -> &Handle {
+        match self {
+            BlockingRuntime::Owned(runtime) => runtime.handle(),
+            BlockingRuntime::Shared(handle) => handle,
+        }
+    }
+
+    fn block_on<T>(&self, future: impl Future<Output = MomentoResult<T>>) -> MomentoResult<T> {
+        // Tokio panics if a thread that is running async code blocks on a runtime
+        if Handle::try_current().is_ok() {
+            return Err(MomentoError {
+                message: "The blocking client cannot be called from within an async context; use the async CacheClient instead".to_string(),
+                error_code: MomentoErrorCode::FailedPreconditionError,
+                inner_error: None,
+                details: None,
+                context: None,
+            });
+        }
+        match self {
+            BlockingRuntime::Owned(runtime) => runtime.block_on(future),
+            BlockingRuntime::Shared(handle) => handle.block_on(future),
+        }
+    }
+}
+
+/// A synchronous client for Momento Cache, for programs that do not run inside an async runtime.
+///
+/// It wraps a [CacheClient](crate::CacheClient) and blocks the calling thread until each
+/// request completes. The methods have the same names and arguments as their async
+/// counterparts; the most common scalar and collection methods are covered, and any other
+/// request can be sent with [send_request](CacheClient::send_request).
+///
+/// Build one from [CacheClient::builder](crate::CacheClient::builder) with `build_blocking()`,
+/// which gives the client its own current-thread runtime, or with
+/// `build_blocking_with_runtime(handle)` to run it on a multi-threaded runtime you already have.
+///
+/// The blocking client cannot be called from within an async context, such as a task running
+/// on a tokio runtime, where blocking would stall the runtime. Calls made there return a
+/// [FailedPreconditionError](MomentoErrorCode::FailedPreconditionError); use the async
+/// [CacheClient](crate::CacheClient) instead.
+///
+/// # Example
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// # let cache_name = momento_test_util::get_test_cache_name();
+/// use momento::{cache::configurations, CredentialProvider, CacheClient};
+/// use std::convert::TryInto;
+/// use std::time::Duration;
+///
+/// let cache_client = CacheClient::builder()
+///     .default_ttl(Duration::from_secs(60))
+///     .configuration(configurations::Laptop::latest())
+///     .credential_provider(CredentialProvider::from_env_var("MOMENTO_API_KEY".to_string())?)
+///     .build_blocking()?;
+///
+/// cache_client.set(&cache_name, "key", "value")?;
+/// let value: String = cache_client.get(&cache_name, "key")?.try_into()?;
+/// # assert_eq!(value, "value");
+/// # Ok(())
+/// # }
+/// ```
+pub struct CacheClient {
+    client: crate::CacheClient,
+    runtime: BlockingRuntime,
+}
+
+impl CacheClient {
+    pub(crate) fn new(client: crate::CacheClient, runtime: BlockingRuntime) -> Self {
+        Self { client, runtime }
+    }
+
+    /// Returns the async client that this client wraps.
+    pub fn async_client(&self) -> &crate::CacheClient {
+        &self.client
+    }
+
+    /// Sends any request, blocking until it completes. See
+    /// [CacheClient::send_request](crate::CacheClient::send_request).
+    pub fn send_request<R: MomentoRequest>(&self, request: R) -> MomentoResult<R::Response> {
+        self.runtime.block_on(self.client.send_request(request))
+    }
+
+    /// See [CacheClient::get](crate::CacheClient::get).
+    pub fn get(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+    ) -> MomentoResult<GetResponse> {
+        self.runtime.block_on(self.client.get(cache_name, key))
+    }
+
+    /// See [CacheClient::set](crate::CacheClient::set).
+    pub fn set(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+        value: impl IntoBytes,
+    ) -> MomentoResult<SetResponse> {
+        self.runtime
+            .block_on(self.client.set(cache_name, key, value))
+    }
+
+    /// See [CacheClient::delete](crate::CacheClient::delete).
+    pub fn delete(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+    ) -> MomentoResult<DeleteResponse> {
+        self.runtime.block_on(self.client.delete(cache_name, key))
+    }
+
+    /// See [CacheClient::increment](crate::CacheClient::increment).
+    pub fn increment(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+        amount: i64,
+    ) -> MomentoResult<IncrementResponse> {
+        self.runtime
+            .block_on(self.client.increment(cache_name, key, amount))
+    }
+
+    /// See [CacheClient::set_if_absent](crate::CacheClient::set_if_absent).
+    pub fn set_if_absent(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+        value: impl IntoBytes,
+    ) -> MomentoResult<SetIfAbsentResponse> {
+        self.runtime
+            .block_on(self.client.set_if_absent(cache_name, key, value))
+    }
+
+    /// See [CacheClient::key_exists](crate::CacheClient::key_exists).
+    pub fn key_exists(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+    ) -> MomentoResult<KeyExistsResponse> {
+        self.runtime
+            .block_on(self.client.key_exists(cache_name, key))
+    }
+
+    /// See [CacheClient::item_get_ttl](crate::CacheClient::item_get_ttl).
+    pub fn item_get_ttl(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+    ) -> MomentoResult<ItemGetTtlResponse> {
+        self.runtime
+            .block_on(self.client.item_get_ttl(cache_name, key))
+    }
+
+    /// See [CacheClient::dictionary_set_field](crate::CacheClient::dictionary_set_field).
+    pub fn dictionary_set_field(
+        &self,
+        cache_name: impl Into<String>,
+        dictionary_name: impl IntoBytes,
+        field: impl IntoBytes,
+        value: impl IntoBytes,
+    ) -> MomentoResult<DictionarySetFieldResponse> {
+        self.runtime.block_on(self.client.dictionary_set_field(
+            cache_name,
+            dictionary_name,
+            field,
+            value,
+        ))
+    }
+
+    /// See [CacheClient::dictionary_get_field](crate::CacheClient::dictionary_get_field).
+    pub fn dictionary_get_field(
+        &self,
+        cache_name: impl Into<String>,
+        dictionary_name: impl IntoBytes,
+        field: impl IntoBytes,
+    ) -> MomentoResult<DictionaryGetFieldResponse> {
+        self.runtime.block_on(
+            self.client
+                .dictionary_get_field(cache_name, dictionary_name, field),
+        )
+    }
+
+    /// See [CacheClient::dictionary_fetch](crate::CacheClient::dictionary_fetch).
+    pub fn dictionary_fetch(
+        &self,
+        cache_name: impl Into<String>,
+        dictionary_name: impl IntoBytes,
+    ) -> MomentoResult<DictionaryFetchResponse> {
+        self.runtime
+            .block_on(self.client.dictionary_fetch(cache_name, dictionary_name))
+    }
+
+    /// See [CacheClient::set_add_elements](crate::CacheClient::set_add_elements).
+    pub fn set_add_elements<E: IntoBytesIterable>(
+        &self,
+        cache_name: impl Into<String>,
+        set_name: impl IntoBytes,
+        elements: E,
+    ) -> MomentoResult<SetAddElementsResponse> {
+        self.runtime
+            .block_on(self.client.set_add_elements(cache_name, set_name, elements))
+    }
+
+    /// See [CacheClient::set_fetch](crate::CacheClient::set_fetch).
+    pub fn set_fetch(
+        &self,
+        cache_name: impl Into<String>,
+        set_name: impl IntoBytes,
+    ) -> MomentoResult<SetFetchResponse> {
+        self.runtime
+            .block_on(self.client.set_fetch(cache_name, set_name))
+    }
+
+    /// See [CacheClient::set_remove_elements](crate::CacheClient::set_remove_elements).
+    pub fn set_remove_elements<E: IntoBytes>(
+        &self,
+        cache_name: impl Into<String>,
+        set_name: impl IntoBytes,
+        elements: Vec<E>,
+    ) -> MomentoResult<SetRemoveElementsResponse> {
+        self.runtime.block_on(
+            self.client
+                .set_remove_elements(cache_name, set_name, elements),
+        )
+    }
+
+    /// See [CacheClient::list_push_back](crate::CacheClient::list_push_back).
+    pub fn list_push_back(
+        &self,
+        cache_name: impl Into<String>,
+        list_name: impl IntoBytes,
+        value: impl IntoBytes,
+    ) -> MomentoResult<ListPushBackResponse> {
+        self.runtime
+            .block_on(self.client.list_push_back(cache_name, list_name, value))
+    }
+
+    /// See [CacheClient::list_concatenate_back](crate::CacheClient::list_concatenate_back).
+    pub fn list_concatenate_back(
+        &self,
+        cache_name: impl Into<String>,
+        list_name: impl IntoBytes,
+        values: impl IntoBytesIterable,
+    ) -> MomentoResult<ListConcatenateBackResponse> {
+        self.runtime.block_on(
+            self.client
+                .list_concatenate_back(cache_name, list_name, values),
+        )
+    }
+
+    /// See [CacheClient::list_fetch](crate::CacheClient::list_fetch).
+    pub fn list_fetch(
+        &self,
+        cache_name: impl Into<String>,
+        list_name: impl IntoBytes,
+    ) -> MomentoResult<ListFetchResponse> {
+        self.runtime
+            .block_on(self.client.list_fetch(cache_name, list_name))
+    }
+
+    /// See [CacheClient::sorted_set_put_element](crate::CacheClient::sorted_set_put_element).
+    pub fn sorted_set_put_element(
+        &self,
+        cache_name: impl Into<String>,
+        sorted_set_name: impl IntoBytes,
+        value: impl IntoBytes,
+        score: f64,
+    ) -> MomentoResult<SortedSetPutElementResponse> {
+        self.runtime.block_on(self.client.sorted_set_put_element(
+            cache_name,
+            sorted_set_name,
+            value,
+            score,
+        ))
+    }
+
+    /// See [CacheClient::sorted_set_fetch_by_score](crate::CacheClient::sorted_set_fetch_by_score).
+    pub fn sorted_set_fetch_by_score(
+        &self,
+        cache_name: impl Into<String>,
+        sorted_set_name: impl IntoBytes,
+        order: impl Into<Option<SortedSetOrder>>,
+    ) -> MomentoResult<SortedSetFetchResponse> {
+        self.runtime.block_on(self.client.sorted_set_fetch_by_score(
+            cache_name,
+            sorted_set_name,
+            order,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tokio::runtime::{Builder, Handle};
+
+    use super::{BlockingRuntime, CacheClient};
+    use crate::cache::configurations;
+    use crate::{CredentialProvider, MomentoErrorCode, MomentoResult};
+
+    const V1_TOKEN: &str = "eyJlbmRwb2ludCI6Im1vbWVudG9fZW5kcG9pbnQiLCJhcGlfa2V5IjoiZXlKaGJHY2lPaUpJVXpJMU5pSjkuZXlKemRXSWlPaUowWlhOMElITjFZbXBsWTNRaUxDSjJaWElpT2pFc0luQWlPaUlpZlEuaGcyd01iV2Utd2VzUVZ0QTd3dUpjUlVMalJwaFhMUXdRVFZZZlFMM0w3YyJ9Cg==";
+
+    fn blocking_client(handle: Option<Handle>) -> MomentoResult<CacheClient> {
+        let builder = crate::CacheClient::builder()
+            .default_ttl(Duration::from_secs(60))
+            .configuration(configurations::Laptop::latest())
+            .credential_provider(CredentialProvider::from_string(V1_TOKEN)?);
+        match handle {
+            Some(handle) => builder.build_blocking_with_runtime(handle),
+            None => builder.build_blocking(),
+        }
+    }
+
+    #[test]
+    fn builds_its_own_runtime_outside_a_runtime() -> MomentoResult<()> {
+        let client = blocking_client(None)?;
+        assert!(matches!(client.runtime, BlockingRuntime::Owned(_)));
+
+        // An invalid cache name fails before anything is sent, but still runs on the runtime
+        let error = client.get("", "key").unwrap_err();
+        assert_eq!(error.error_code, MomentoErrorCode::InvalidArgumentError);
+        Ok(())
+    }
+
+    #[test]
+    fn runs_on_a_shared_multi_thread_runtime() -> MomentoResult<()> {
+        let runtime = Builder::new_multi_thread()
+            .worker_threads(1)
+            .enable_all()
+            .build()
+            .expect("should be able to build a runtime");
+        let client = blocking_client(Some(runtime.handle().clone()))?;
+        assert!(matches!(client.runtime, BlockingRuntime::Shared(_)));
+
+        let error = client.get("", "key").unwrap_err();
+        assert_eq!(error.error_code, MomentoErrorCode::InvalidArgumentError);
+        Ok(())
+    }
+
+    #[test]
+    fn calls_from_an_async_context_fail_without_panicking() -> MomentoResult<()> {
+        let client = blocking_client(None)?;
+        let runtime = Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("should be able to build a runtime");
+
+        let error = runtime
+            .block_on(async { client.get("cache", "key") })
+            .unwrap_err();
+        assert_eq!(error.error_code, MomentoErrorCode::FailedPreconditionError);
+        Ok(())
+    }
+}
@@ -1,43 +1,60 @@
 use std::convert::TryInto;
+use std::future::Future;
+use std::ops::RangeBounds;
 use std::sync::atomic::{AtomicUsize, Ordering};
-use std::time::Duration;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
+use futures::{stream, Stream, TryStreamExt};
 use momento_protos::cache_client::scs_client::ScsClient;
 use momento_protos::control_client::scs_control_client::ScsControlClient;
+use tokio::sync::OwnedSemaphorePermit;
 use tonic::codegen::InterceptedService;
 use tonic::transport::Channel;
 
+use crate::auth::ExpiresAt;
+use crate::cache::messages::control::list_caches::ListCachesPageRequest;
 use crate::cache::{
-    Configuration, CreateCacheRequest, CreateCacheResponse, DecreaseTtlRequest,
+    CacheInfo, Configuration, CreateCacheRequest, CreateCacheResponse, DecreaseTtlRequest,
     DecreaseTtlResponse, DeleteCacheRequest, DeleteCacheResponse, DeleteRequest, DeleteResponse,
-    DictionaryFetchRequest, DictionaryFetchResponse, DictionaryGetFieldRequest,
+    DictionaryCopyRequest, DictionaryCopyResponse, DictionaryFetchRequest, DictionaryFetchResponse,
+    DictionaryGetFieldBatchRequest, DictionaryGetFieldBatchResponse, DictionaryGetFieldRequest,
     DictionaryGetFieldResponse, DictionaryGetFieldsRequest, DictionaryGetFieldsResponse,
     DictionaryIncrementRequest, DictionaryIncrementResponse, DictionaryLengthRequest,
     DictionaryLengthResponse, DictionaryRemoveFieldRequest, DictionaryRemoveFieldResponse,
     DictionaryRemoveFieldsRequest, DictionaryRemoveFieldsResponse, DictionarySetFieldRequest,
-    DictionarySetFieldResponse, DictionarySetFieldsRequest, DictionarySetFieldsResponse,
-    FlushCacheRequest, FlushCacheResponse, GetBatchRequest, GetBatchResponse, GetRequest,
-    GetResponse, IncreaseTtlRequest, IncreaseTtlResponse, IncrementRequest, IncrementResponse,
-    IntoDictionaryFieldValuePairs, IntoSortedSetElements, ItemGetTtlRequest, ItemGetTtlResponse,
-    ItemGetTypeRequest, ItemGetTypeResponse, KeyExistsRequest, KeyExistsResponse, KeysExistRequest,
-    KeysExistResponse, ListCachesRequest, ListCachesResponse, ListConcatenateBackRequest,
-    ListConcatenateBackResponse, ListConcatenateFrontRequest, ListConcatenateFrontResponse,
+    DictionarySetFieldResponse, DictionarySetFieldsIfNotExistsRequest,
+    DictionarySetFieldsIfNotExistsResponse, DictionarySetFieldsRequest,
+    DictionarySetFieldsResponse, FlushCacheRequest, FlushCacheResponse, GetBatchRequest,
+    GetBatchResponse, GetRequest, GetResponse, GetSlidingRequest, GetWithMetadataRequest,
+    GetWithMetadataResponse, IncreaseTtlRequest, IncreaseTtlResponse, IncrementRequest,
+    IncrementResponse, IntoDictionaryFieldValuePairs, IntoSortedSetElements, ItemGetTtlRequest,
+    ItemGetTtlResponse, ItemGetTypeBatchRequest, ItemGetTypeBatchResponse, ItemGetTypeRequest,
+    ItemGetTypeResponse, KeyExistsRequest, KeyExistsResponse, KeysExistRequest, KeysExistResponse,
+    ListCachesRequest, ListCachesResponse, ListConcatenateBackRequest, ListConcatenateBackResponse,
+    ListConcatenateFrontRequest, ListConcatenateFrontResponse, ListCopyRequest, ListCopyResponse,
     ListFetchRequest, ListFetchResponse, ListLengthRequest, ListLengthResponse, ListPopBackRequest,
     ListPopBackResponse, ListPopFrontRequest, ListPopFrontResponse, ListPushBackRequest,
     ListPushBackResponse, ListPushFrontRequest, ListPushFrontResponse, ListRemoveValueRequest,
-    ListRemoveValueResponse, MomentoRequest, SetAddElementsRequest, SetAddElementsResponse,
-    SetBatchRequest, SetBatchResponse, SetFetchRequest, SetFetchResponse,
-    SetIfAbsentOrEqualRequest, SetIfAbsentOrEqualResponse, SetIfAbsentRequest, SetIfAbsentResponse,
-    SetIfEqualRequest, SetIfEqualResponse, SetIfNotEqualRequest, SetIfNotEqualResponse,
-    SetIfPresentAndNotEqualRequest, SetIfPresentAndNotEqualResponse, SetIfPresentRequest,
-    SetIfPresentResponse, SetRemoveElementsRequest, SetRemoveElementsResponse, SetRequest,
-    SetResponse, SortedSetFetchByRankRequest, SortedSetFetchByScoreRequest, SortedSetFetchResponse,
-    SortedSetGetRankRequest, SortedSetGetRankResponse, SortedSetGetScoreRequest,
-    SortedSetGetScoreResponse, SortedSetGetScoresRequest, SortedSetGetScoresResponse,
-    SortedSetLengthRequest, SortedSetLengthResponse, SortedSetOrder, SortedSetPutElementRequest,
-    SortedSetPutElementResponse, SortedSetPutElementsRequest, SortedSetPutElementsResponse,
-    SortedSetRemoveElementsRequest, SortedSetRemoveElementsResponse, UpdateTtlRequest,
-    UpdateTtlResponse,
+    ListRemoveValueResponse, ListRetainRequest, ListRetainResponse, MomentoRawRequest,
+    MomentoRequest, ScoreBound, SetAddElementsRequest, SetAddElementsResponse, SetBatchRequest,
+    SetBatchResponse, SetFetchRequest, SetFetchResponse, SetIfAbsentBatchRequest,
+    SetIfAbsentBatchResponse, SetIfAbsentOrEqualRequest, SetIfAbsentOrEqualResponse,
+    SetIfAbsentRequest, SetIfAbsentResponse, SetIfEqualRequest, SetIfEqualResponse,
+    SetIfNotEqualRequest, SetIfNotEqualResponse, SetIfPresentAndNotEqualRequest,
+    SetIfPresentAndNotEqualResponse, SetIfPresentRequest, SetIfPresentResponse,
+    SetRemoveElementsRequest, SetRemoveElementsResponse, SetRequest, SetResponse,
+    SortedSetCopyRequest, SortedSetCopyResponse, SortedSetFetchByRankRequest,
+    SortedSetFetchByScoreRequest, SortedSetFetchExtremesRequest, SortedSetFetchExtremesResponse,
+    SortedSetFetchResponse, SortedSetGetRankRequest, SortedSetGetRankResponse,
+    SortedSetGetScoreRequest, SortedSetGetScoreResponse, SortedSetGetScoresRequest,
+    SortedSetGetScoresResponse, SortedSetIncrementScoreAndGetRankRequest,
+    SortedSetIncrementScoreAndGetRankResponse, SortedSetLengthByScoreRequest,
+    SortedSetLengthByScoreResponse, SortedSetLengthRequest, SortedSetLengthResponse,
+    SortedSetOrder, SortedSetPutElementRequest, SortedSetPutElementResponse,
+    SortedSetPutElementsRequest, SortedSetPutElementsResponse, SortedSetRemoveElementsRequest,
+    SortedSetRemoveElementsResponse, SortedSetUnionRequest, TouchTtlIfBelowRequest,
+    TouchTtlIfBelowResponse, UpdateTtlRequest, UpdateTtlResponse,
 };
 use crate::grpc::header_interceptor::HeaderInterceptor;
 
@@ -45,8 +62,27 @@ use crate::cache::cache_client_builder::{CacheClientBuilder, NeedsDefaultTtl};
 use crate::cache::messages::data::sorted_set::sorted_set_increment_score::{
     SortedSetIncrementScoreRequest, SortedSetIncrementScoreResponse,
 };
+use crate::cache::messages::data::sorted_set::sorted_set_increment_scores::{
+    SortedSetIncrementScoresRequest, SortedSetIncrementScoresResponse,
+};
+use crate::cache::request_limiter::RequestLimiter;
+#[cfg(feature = "tracing")]
+use crate::cache::request_span;
+use crate::cache::{HitRateTracker, Middleware, RequestMetadata, ResponseMetadata};
+use crate::topics::{IntoTopicValue, TopicPublishResponse};
 use crate::utils::IntoBytesIterable;
-use crate::{utils, IntoBytes, MomentoResult};
+use crate::{
+    utils, ErrorContext, IntoBytes, MomentoError, MomentoErrorCode, MomentoResult, TopicClient,
+};
+
+/// Key used by [CacheClient::cache_exists] to check whether a cache exists.
+const CACHE_EXISTS_PROBE_KEY: &str = "__momento_cache_exists_probe__";
+
+/// Errors that reads return as a miss on a client built with `treat_errors_as_miss`.
+const MISS_ON_ERROR_CODES: [MomentoErrorCode; 2] = [
+    MomentoErrorCode::ServerUnavailable,
+    MomentoErrorCode::TimeoutError,
+];
 
 /// Client to work with Momento Cache, the serverless caching service.
 ///
@@ -82,10 +118,15 @@ pub struct CacheClient {
     control_client: ScsControlClient<InterceptedService<Channel, HeaderInterceptor>>,
     configuration: Configuration,
     item_default_ttl: Duration,
+    request_limiter: Option<RequestLimiter>,
+    default_sorted_set_order: SortedSetOrder,
+    hit_rate_tracker: Option<HitRateTracker>,
+    connection_pinning: bool,
+    next_data_client_index: Arc<AtomicUsize>,
+    middlewares: Vec<Arc<dyn Middleware>>,
+    treat_errors_as_miss: bool,
 }
 
-static NEXT_DATA_CLIENT_INDEX: AtomicUsize = AtomicUsize::new(0);
-
 impl CacheClient {
     /// Constructs a CacheClient to use Momento Cache.
     ///
@@ -153,7 +194,7 @@ impl CacheClient {
         cache_name: impl Into<String>,
     ) -> MomentoResult<CreateCacheResponse> {
         let request = CreateCacheRequest::new(cache_name);
-        request.send(self).await
+        self.send_request(request).await
     }
 
     /// Deletes the cache with the given name.
@@ -190,7 +231,7 @@ impl CacheClient {
         cache_name: impl Into<String>,
     ) -> MomentoResult<DeleteCacheResponse> {
         let request = DeleteCacheRequest::new(cache_name);
-        request.send(self).await
+        self.send_request(request).await
     }
 
     /// Lists all caches in your account.
@@ -215,7 +256,80 @@ impl CacheClient {
     /// You can also use the [send_request](CacheClient::send_request) method to list caches using a [ListCachesRequest].
     pub async fn list_caches(&self) -> MomentoResult<ListCachesResponse> {
         let request = ListCachesRequest {};
-        request.send(self).await
+        self.send_request(request).await
+    }
+
+    /// Lists all caches in your account as a stream, fetching one page of results at a time.
+    ///
+    /// Unlike [list_caches](CacheClient::list_caches), the next page is only requested once the
+    /// caches from the current page have been consumed, so you can stop early without fetching
+    /// the rest. If a page fails to load, the stream yields the error and ends.
+    ///
+    /// # Examples
+    /// Assumes that a CacheClient named `cache_client` has been created and is available.
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use momento_test_util::create_doctest_cache_client;
+    /// # tokio_test::block_on(async {
+    /// use futures::TryStreamExt;
+    /// # let (cache_client, cache_name) = create_doctest_cache_client();
+    ///
+    /// let mut caches = Box::pin(cache_client.list_caches_stream());
+    /// while let Some(cache) = caches.try_next().await? {
+    ///     println!("Cache: {}", cache.name);
+    /// }
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    pub fn list_caches_stream(&self) -> impl Stream<Item = MomentoResult<CacheInfo>> + '_ {
+        stream::try_unfold(Some(None), move |next_token| async move {
+            // `None` means the last page has already been fetched.
+            let next_token = match next_token {
+                Some(next_token) => next_token,
+                None => return Ok(None),
+            };
+            let (response, next_token) = self
+                .send_request(ListCachesPageRequest::new(next_token))
+                .await?;
+            let caches = stream::iter(response.caches.into_iter().map(Ok));
+            Ok(Some((caches, next_token.map(Some))))
+        })
+        .try_flatten()
+    }
+
+    /// Checks whether a cache with the given name exists in your account.
+    ///
+    /// This issues a single key existence check against the cache rather than listing every
+    /// cache, and returns `false` if the service reports that the cache was not found. Any other
+    /// error is returned as-is.
+    ///
+    /// # Arguments
+    ///
+    /// * `cache_name` - The name of the cache to check for.
+    ///
+    /// # Examples
+    /// Assumes that a CacheClient named `cache_client` has been created and is available.
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use momento_test_util::create_doctest_cache_client;
+    /// # tokio_test::block_on(async {
+    /// # let (cache_client, cache_name) = create_doctest_cache_client();
+    ///
+    /// if !cache_client.cache_exists(&cache_name).await? {
+    ///     cache_client.create_cache(&cache_name).await?;
+    /// }
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    pub async fn cache_exists(&self, cache_name: impl Into<String>) -> MomentoResult<bool> {
+        let request = KeyExistsRequest::new(cache_name, CACHE_EXISTS_PROBE_KEY);
+        match self.send_request(request).await {
+            Ok(_) => Ok(true),
+            Err(e) if e.error_code == MomentoErrorCode::CacheNotFoundError => Ok(false),
+            Err(e) => Err(e),
+        }
     }
 
     /// Flushes the cache with the given name.
@@ -254,7 +368,7 @@ impl CacheClient {
         cache_name: impl Into<String>,
     ) -> MomentoResult<FlushCacheResponse> {
         let request = FlushCacheRequest::new(cache_name);
-        request.send(self).await
+        self.send_request(request).await
     }
 
     /// Sets an item in a Momento Cache
@@ -302,7 +416,108 @@ impl CacheClient {
         value: impl IntoBytes,
     ) -> MomentoResult<SetResponse> {
         let request = SetRequest::new(cache_name, key, value);
-        request.send(self).await
+        self.send_request(request).await
+    }
+
+    /// Sets an item in a Momento Cache that expires at an absolute point in time
+    ///
+    /// # Arguments
+    ///
+    /// * `cache_name` - name of cache
+    /// * `key` - key of the item whose value we are setting
+    /// * `value` - data to stored in the cache item
+    /// * `expires_at` - the time at which the item should expire. The remaining time-to-live is
+    ///   computed when the request is sent; an expiry time in the past is rejected with an
+    ///   `InvalidArgumentError`.
+    ///
+    /// # Examples
+    /// Assumes that a CacheClient named `cache_client` has been created and is available.
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use momento_test_util::create_doctest_cache_client;
+    /// # tokio_test::block_on(async {
+    /// # let (cache_client, cache_name) = create_doctest_cache_client();
+    /// use std::time::{SystemTime, UNIX_EPOCH};
+    /// use momento::auth::ExpiresAt;
+    ///
+    /// let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    /// let expires_at = ExpiresAt::from_epoch(now + 60);
+    ///
+    /// match cache_client.set_with_expires_at(&cache_name, "k1", "v1", expires_at).await {
+    ///     Ok(_) => println!("SetResponse successful"),
+    ///     Err(e) => eprintln!("Error setting value in cache {}: {}", &cache_name, e),
+    /// }
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    /// You can also use the [send_request](CacheClient::send_request) method with a [SetRequest]
+    /// and its [expires_at](SetRequest::expires_at) option.
+    pub async fn set_with_expires_at(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+        value: impl IntoBytes,
+        expires_at: ExpiresAt,
+    ) -> MomentoResult<SetResponse> {
+        let request = SetRequest::new(cache_name, key, value).expires_at(expires_at);
+        self.send_request(request).await
+    }
+
+    /// Sets an item in a Momento Cache and then publishes a message to a topic, e.g. to notify
+    /// other processes that the item changed.
+    ///
+    /// This is best-effort and not transactional: the message is only published if the set
+    /// succeeds, but if the publish fails the item stays set. In that case the returned error
+    /// keeps the publish error's code and its message says that the item was set, so callers
+    /// can tell the two failures apart and retry the publish.
+    ///
+    /// # Arguments
+    ///
+    /// * `topic_client` - the [TopicClient] to publish the message with
+    /// * `cache_name` - name of cache, also used as the namespace for the topic
+    /// * `key` - key of the item whose value we are setting
+    /// * `value` - data to stored in the cache item
+    /// * `topic` - the topic to publish to
+    /// * `message` - the message to publish once the item is set
+    ///
+    /// # Examples
+    /// Assumes that a CacheClient named `cache_client` has been created and is available.
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use momento_test_util::{create_doctest_cache_client, create_doctest_topic_client};
+    /// # tokio_test::block_on(async {
+    /// # let (cache_client, cache_name) = create_doctest_cache_client();
+    /// # let (topic_client, _) = create_doctest_topic_client();
+    /// cache_client
+    ///     .set_and_publish(&topic_client, &cache_name, "k1", "v1", "invalidations", "k1")
+    ///     .await?;
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    pub async fn set_and_publish(
+        &self,
+        topic_client: &TopicClient,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+        value: impl IntoBytes,
+        topic: impl Into<String>,
+        message: impl IntoTopicValue + Send,
+    ) -> MomentoResult<TopicPublishResponse> {
+        let cache_name = cache_name.into();
+        let topic = topic.into();
+        self.set(&cache_name, key, value).await?;
+        topic_client
+            .publish(cache_name, &topic, message)
+            .await
+            .map_err(|e| MomentoError {
+                message: format!(
+                    "The item was set, but publishing to topic {} failed: {}",
+                    topic, e.message
+                ),
+                ..e
+            })
     }
 
     /// Sets a batch of items in a Momento Cache
@@ -350,7 +565,7 @@ impl CacheClient {
         items: impl IntoIterator<Item = (K, V)>,
     ) -> MomentoResult<SetBatchResponse> {
         let request = SetBatchRequest::new(cache_name, items);
-        request.send(self).await
+        self.send_request(request).await
     }
 
     /// Gets an item from a Momento Cache
@@ -389,7 +604,94 @@ impl CacheClient {
         key: impl IntoBytes,
     ) -> MomentoResult<GetResponse> {
         let request = GetRequest::new(cache_name, key);
-        request.send(self).await
+        self.send_request(request).await
+    }
+
+    /// Gets an item from a cache and, if it is found, resets its ttl, for sliding expiration
+    /// where every read keeps the item alive.
+    ///
+    /// A get and a ttl update are sent at the same time, so this takes about one round trip. They
+    /// are not atomic; see [GetSlidingRequest] for what that means for items that expire or are
+    /// written concurrently.
+    ///
+    /// # Arguments
+    ///
+    /// * `cache_name` - name of cache
+    /// * `key` - key of the item to get
+    /// * `ttl` - the ttl to give the item if it is found
+    ///
+    /// # Examples
+    /// Assumes that a CacheClient named `cache_client` has been created and is available.
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use momento_test_util::create_doctest_cache_client;
+    /// # tokio_test::block_on(async {
+    /// use std::time::Duration;
+    /// use momento::cache::GetResponse;
+    /// # let (cache_client, cache_name) = create_doctest_cache_client();
+    /// # cache_client.set(&cache_name, "session", "data").await?;
+    ///
+    /// let response = cache_client.get_sliding(&cache_name, "session", Duration::from_secs(1800)).await?;
+    ///
+    /// if response == GetResponse::Miss {
+    ///     println!("Session expired");
+    /// }
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    /// You can also use the [send_request](CacheClient::send_request) method to get an item using a [GetSlidingRequest].
+    pub async fn get_sliding(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+        ttl: Duration,
+    ) -> MomentoResult<GetResponse> {
+        let request = GetSlidingRequest::new(cache_name, key, ttl);
+        self.send_request(request).await
+    }
+
+    /// Gets an item from a Momento Cache along with its remaining time-to-live.
+    ///
+    /// The service cannot return an item and its ttl in one call, so this sends a get and an
+    /// item_get_ttl request concurrently. The two reads are not atomic: if the item is
+    /// overwritten between them, the ttl may belong to the newer item. Use [get](CacheClient::get)
+    /// when you only need the value.
+    ///
+    /// # Arguments
+    ///
+    /// * `cache_name` - name of cache
+    /// * `key` - key of entry within the cache.
+    ///
+    /// # Examples
+    /// Assumes that a CacheClient named `cache_client` has been created and is available.
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use momento_test_util::create_doctest_cache_client;
+    /// # tokio_test::block_on(async {
+    /// # let (cache_client, cache_name) = create_doctest_cache_client();
+    /// use std::convert::TryInto;
+    /// use std::time::Duration;
+    /// # cache_client.set(&cache_name, "key", "value").await?;
+    ///
+    /// let (item, remaining_ttl): (String, Duration) =
+    ///     cache_client.get_with_metadata(&cache_name, "key").await?.try_into()?;
+    /// # assert_eq!(item, "value");
+    /// # assert!(remaining_ttl > Duration::ZERO);
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    /// You can also use the [send_request](CacheClient::send_request) method to get an item and its ttl using a [GetWithMetadataRequest].
+    ///
+    /// For more examples of handling the response, see [GetWithMetadataResponse].
+    pub async fn get_with_metadata(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+    ) -> MomentoResult<GetWithMetadataResponse> {
+        let request = GetWithMetadataRequest::new(cache_name, key);
+        self.send_request(request).await
     }
 
     /// Gets a batch of items from a Momento Cache
@@ -438,7 +740,7 @@ impl CacheClient {
         keys: impl IntoBytesIterable,
     ) -> MomentoResult<GetBatchResponse> {
         let request = GetBatchRequest::new(cache_name, keys);
-        request.send(self).await
+        self.send_request(request).await
     }
 
     /// Deletes an item in a Momento Cache
@@ -477,7 +779,7 @@ impl CacheClient {
         key: impl IntoBytes,
     ) -> MomentoResult<DeleteResponse> {
         let request = DeleteRequest::new(cache_name, key);
-        request.send(self).await
+        self.send_request(request).await
     }
 
     /// Fetches a dictionary from a cache.
@@ -528,7 +830,53 @@ impl CacheClient {
         dictionary_name: impl IntoBytes,
     ) -> MomentoResult<DictionaryFetchResponse> {
         let request = DictionaryFetchRequest::new(cache_name, dictionary_name);
-        request.send(self).await
+        self.send_request(request).await
+    }
+
+    /// Copies a dictionary to a new name, replacing anything stored under the destination name, and
+    /// returns the number of fields copied.
+    ///
+    /// The dictionary is fetched and written back through the client, so the copy is not atomic. See
+    /// [DictionaryCopyRequest] for what that means for concurrent changes.
+    ///
+    /// # Arguments
+    ///
+    /// * `cache_name` - The name of the cache containing the dictionary.
+    /// * `source_name` - The name of the dictionary to copy.
+    /// * `destination_name` - The name to copy the dictionary to.
+    /// * `ttl` - The time-to-live of the copy. If `None`, the client's default time-to-live is used.
+    ///
+    /// # Examples
+    /// Assumes that a CacheClient named `cache_client` has been created and is available.
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use momento_test_util::create_doctest_cache_client;
+    /// # tokio_test::block_on(async {
+    /// use momento::cache::DictionaryCopyResponse;
+    /// # let (cache_client, cache_name) = create_doctest_cache_client();
+    /// # cache_client.dictionary_set_fields(&cache_name, "config", vec![("region", "us-west-2"), ("retries", "3")]).await?;
+    ///
+    /// let response = cache_client.dictionary_copy(&cache_name, "config", "config-v1", None).await?;
+    ///
+    /// if let DictionaryCopyResponse::Copied { count } = response {
+    ///     println!("Copied {} fields", count);
+    /// }
+    /// # assert_eq!(response, DictionaryCopyResponse::Copied { count: 2 });
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    /// You can also use the [send_request](CacheClient::send_request) method to copy a dictionary using a [DictionaryCopyRequest].
+    pub async fn dictionary_copy(
+        &self,
+        cache_name: impl Into<String>,
+        source_name: impl IntoBytes,
+        destination_name: impl IntoBytes,
+        ttl: impl Into<Option<Duration>>,
+    ) -> MomentoResult<DictionaryCopyResponse> {
+        let request =
+            DictionaryCopyRequest::new(cache_name, source_name, destination_name).ttl(ttl);
+        self.send_request(request).await
     }
 
     /// Gets a field from a dictionary.
@@ -582,7 +930,48 @@ impl CacheClient {
         field: impl IntoBytes,
     ) -> MomentoResult<DictionaryGetFieldResponse> {
         let request = DictionaryGetFieldRequest::new(cache_name, dictionary_name, field);
-        request.send(self).await
+        self.send_request(request).await
+    }
+
+    /// Gets one field from each of several dictionaries, e.g. the same field from many users'
+    /// dictionaries. The fields are fetched concurrently.
+    ///
+    /// The response holds one [DictionaryGetFieldResponse] per (dictionary, field) pair, in the
+    /// order they were given. A pair is a miss if its dictionary or its field does not exist.
+    ///
+    /// # Arguments
+    /// * `cache_name` - The name of the cache containing the dictionaries.
+    /// * `items` - The (dictionary name, field) pairs to get.
+    ///
+    /// # Examples
+    /// Assumes that a CacheClient named `cache_client` has been created and is available.
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use momento_test_util::create_doctest_cache_client;
+    /// # use std::convert::TryInto;
+    /// # tokio_test::block_on(async {
+    /// # let (cache_client, cache_name) = create_doctest_cache_client();
+    /// # cache_client.dictionary_set_field(&cache_name, "user_1", "last_seen", "today").await?;
+    /// let response = cache_client
+    ///     .dictionary_get_field_batch(&cache_name, vec![("user_1", "last_seen"), ("user_2", "last_seen")])
+    ///     .await?;
+    ///
+    /// let last_seen: Vec<Option<String>> = response.try_into().expect("I stored strings!");
+    /// # assert_eq!(last_seen, vec![Some("today".to_string()), None]);
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    /// You can also use the [send_request](CacheClient::send_request) method to get the fields using a [DictionaryGetFieldBatchRequest].
+    ///
+    /// For more examples of handling the response, see [DictionaryGetFieldBatchResponse].
+    pub async fn dictionary_get_field_batch<D: IntoBytes, F: IntoBytes>(
+        &self,
+        cache_name: impl Into<String>,
+        items: impl IntoIterator<Item = (D, F)>,
+    ) -> MomentoResult<DictionaryGetFieldBatchResponse> {
+        let request = DictionaryGetFieldBatchRequest::new(cache_name, items);
+        self.send_request(request).await
     }
 
     /// Gets fields from a dictionary.
@@ -637,7 +1026,7 @@ impl CacheClient {
         fields: F,
     ) -> MomentoResult<DictionaryGetFieldsResponse<F>> {
         let request = DictionaryGetFieldsRequest::new(cache_name, dictionary_name, fields);
-        request.send(self).await
+        self.send_request(request).await
     }
 
     /// Increments a field in a dictionary.
@@ -683,7 +1072,7 @@ impl CacheClient {
         amount: i64,
     ) -> MomentoResult<DictionaryIncrementResponse> {
         let request = DictionaryIncrementRequest::new(cache_name, dictionary_name, field, amount);
-        request.send(self).await
+        self.send_request(request).await
     }
 
     /// Gets the number of elements in the given dictionary.
@@ -720,7 +1109,7 @@ impl CacheClient {
         dictionary_name: impl IntoBytes,
     ) -> MomentoResult<DictionaryLengthResponse> {
         let request = DictionaryLengthRequest::new(cache_name, dictionary_name);
-        request.send(self).await
+        self.send_request(request).await
     }
 
     /// Removes a field from a dictionary.
@@ -760,7 +1149,7 @@ impl CacheClient {
         field: impl IntoBytes,
     ) -> MomentoResult<DictionaryRemoveFieldResponse> {
         let request = DictionaryRemoveFieldRequest::new(cache_name, dictionary_name, field);
-        request.send(self).await
+        self.send_request(request).await
     }
 
     /// Removes fields from a dictionary.
@@ -806,7 +1195,7 @@ impl CacheClient {
         fields: F,
     ) -> MomentoResult<DictionaryRemoveFieldsResponse> {
         let request = DictionaryRemoveFieldsRequest::new(cache_name, dictionary_name, fields);
-        request.send(self).await
+        self.send_request(request).await
     }
 
     /// Sets a field in a dictionary. If the field already exists, its value is updated.
@@ -859,7 +1248,7 @@ impl CacheClient {
         value: impl IntoBytes,
     ) -> MomentoResult<DictionarySetFieldResponse> {
         let request = DictionarySetFieldRequest::new(cache_name, dictionary_name, field, value);
-        request.send(self).await
+        self.send_request(request).await
     }
 
     /// Sets multiple fields in a dictionary. If the dictionary does not exist, it will be created.
@@ -908,7 +1297,62 @@ impl CacheClient {
         elements: impl IntoDictionaryFieldValuePairs<F, V>,
     ) -> MomentoResult<DictionarySetFieldsResponse> {
         let request = DictionarySetFieldsRequest::new(cache_name, dictionary_name, elements);
-        request.send(self).await
+        self.send_request(request).await
+    }
+
+    /// Sets the fields of a dictionary that are not already present, leaving existing fields
+    /// unchanged. If the dictionary does not exist, it will be created with all of the fields.
+    ///
+    /// This is not atomic: the fields are read first and the absent ones are then written, so a
+    /// field set by another client in between is overwritten. See
+    /// [DictionarySetFieldsIfNotExistsRequest] for details.
+    ///
+    /// # Arguments
+    /// * `cache_name` - The name of the cache containing the dictionary.
+    /// * `dictionary_name` - The name of the dictionary to set fields in.
+    /// * `elements` - The fields and values to set in the dictionary if the fields are absent.
+    ///
+    /// # Optional Arguments
+    /// If you use [send_request](CacheClient::send_request) to set fields using a
+    /// [DictionarySetFieldsIfNotExistsRequest], you can also provide the following optional arguments:
+    ///
+    /// * `collection_ttl` - The time-to-live for the collection. If not provided, the client's default time-to-live is used.
+    ///
+    /// # Examples
+    /// Assumes that a CacheClient named `cache_client` has been created and is available.
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use momento_test_util::create_doctest_cache_client;
+    /// # tokio_test::block_on(async {
+    /// # let (cache_client, cache_name) = create_doctest_cache_client();
+    /// let dictionary_name = "dictionary";
+    /// # cache_client.dictionary_set_field(&cache_name, dictionary_name, "field1", "existing").await?;
+    ///
+    /// let response = cache_client.dictionary_set_fields_if_not_exists(
+    ///    cache_name,
+    ///    dictionary_name,
+    ///    vec![("field1", "value1"), ("field2", "value2")]
+    /// ).await?;
+    ///
+    /// for field in response.written_fields() {
+    ///     println!("Set {}", String::from_utf8_lossy(field));
+    /// }
+    /// # assert_eq!(response.written_fields(), &[b"field2".to_vec()]);
+    /// # assert_eq!(response.existing_fields(), &[b"field1".to_vec()]);
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    /// You can also use the [send_request](CacheClient::send_request) method to set fields using a [DictionarySetFieldsIfNotExistsRequest]
+    pub async fn dictionary_set_fields_if_not_exists<F: IntoBytes, V: IntoBytes>(
+        &self,
+        cache_name: impl Into<String>,
+        dictionary_name: impl IntoBytes,
+        elements: impl IntoDictionaryFieldValuePairs<F, V>,
+    ) -> MomentoResult<DictionarySetFieldsIfNotExistsResponse> {
+        let request =
+            DictionarySetFieldsIfNotExistsRequest::new(cache_name, dictionary_name, elements);
+        self.send_request(request).await
     }
 
     /// Adds elements to the given set. Creates the set if it does not exist.
@@ -959,11 +1403,15 @@ impl CacheClient {
         elements: E,
     ) -> MomentoResult<SetAddElementsResponse> {
         let request = SetAddElementsRequest::new(cache_name, set_name, elements);
-        request.send(self).await
+        self.send_request(request).await
     }
 
     /// Fetch the elements in the given set.
     ///
+    /// The service returns the whole set in a single response. It has no way to page through a
+    /// set, so there is no streaming variant of this method: one would still have to receive the
+    /// whole set before yielding its first element.
+    ///
     /// # Arguments
     ///
     /// * `cache_name` - The name of the cache containing the set.
@@ -997,7 +1445,45 @@ impl CacheClient {
         set_name: impl IntoBytes,
     ) -> MomentoResult<SetFetchResponse> {
         let request = SetFetchRequest::new(cache_name, set_name);
-        request.send(self).await
+        self.send_request(request).await
+    }
+
+    /// Fetch the elements in the given set as UTF-8 strings.
+    ///
+    /// Returns `None` if the set does not exist, and an error if any element is not valid UTF-8.
+    ///
+    /// # Arguments
+    ///
+    /// * `cache_name` - The name of the cache containing the set.
+    /// * `set_name` - The name of the set to fetch.
+    ///
+    /// # Examples
+    /// Assumes that a CacheClient named `cache_client` has been created and is available.
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use momento_test_util::create_doctest_cache_client;
+    /// # tokio_test::block_on(async {
+    /// # let (cache_client, cache_name) = create_doctest_cache_client();
+    /// let set_name = "set";
+    /// # cache_client.set_add_elements(&cache_name, set_name, vec!["value1", "value2"]).await?;
+    ///
+    /// match cache_client.set_fetch_strings(cache_name, set_name).await? {
+    ///     Some(elements) => println!("Elements: {:?}", elements),
+    ///     None => println!("Set not found"),
+    /// }
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    pub async fn set_fetch_strings(
+        &self,
+        cache_name: impl Into<String>,
+        set_name: impl IntoBytes,
+    ) -> MomentoResult<Option<Vec<String>>> {
+        match self.set_fetch(cache_name, set_name).await? {
+            SetFetchResponse::Hit { values } => values.try_into().map(Some),
+            SetFetchResponse::Miss => Ok(None),
+        }
     }
 
     /// Removes multiple elements from an existing set. If the set is emptied as a result, the set is deleted.
@@ -1036,7 +1522,7 @@ impl CacheClient {
         elements: Vec<E>,
     ) -> MomentoResult<SetRemoveElementsResponse> {
         let request = SetRemoveElementsRequest::new(cache_name, set_name, elements);
-        request.send(self).await
+        self.send_request(request).await
     }
 
     /// Adds an element to the given sorted set. If the element already exists, its score is updated.
@@ -1090,7 +1576,7 @@ impl CacheClient {
         score: f64,
     ) -> MomentoResult<SortedSetPutElementResponse> {
         let request = SortedSetPutElementRequest::new(cache_name, sorted_set_name, value, score);
-        request.send(self).await
+        self.send_request(request).await
     }
 
     /// Adds elements to the given sorted set. If an element already exists, its score is updated.
@@ -1141,7 +1627,67 @@ impl CacheClient {
         elements: impl IntoSortedSetElements<V>,
     ) -> MomentoResult<SortedSetPutElementsResponse> {
         let request = SortedSetPutElementsRequest::new(cache_name, sorted_set_name, elements);
-        request.send(self).await
+        self.send_request(request).await
+    }
+
+    /// Adds elements to the given sorted set, computing each element's value and score from an
+    /// item. If an element already exists, its score is updated. Creates the sorted set if it
+    /// does not exist.
+    ///
+    /// This is a convenience over [sorted_set_put_elements](CacheClient::sorted_set_put_elements)
+    /// for when the elements come from your own types, such as updating a leaderboard from a list
+    /// of players.
+    ///
+    /// # Arguments
+    ///
+    /// * `cache_name` - The name of the cache containing the sorted set.
+    /// * `sorted_set_name` - The name of the sorted set to add elements to.
+    /// * `items` - The items to add one element for.
+    /// * `score_fn` - Computes the score of an item's element.
+    /// * `value_fn` - Computes the value of an item's element.
+    ///
+    /// # Examples
+    /// Assumes that a CacheClient named `cache_client` has been created and is available.
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use momento_test_util::create_doctest_cache_client;
+    /// # tokio_test::block_on(async {
+    /// # let (cache_client, cache_name) = create_doctest_cache_client();
+    /// struct Player {
+    ///     name: String,
+    ///     points: u32,
+    /// }
+    ///
+    /// let players = vec![
+    ///     Player { name: "alice".to_string(), points: 42 },
+    ///     Player { name: "bob".to_string(), points: 7 },
+    /// ];
+    ///
+    /// cache_client.sorted_set_put_elements_by(
+    ///     &cache_name,
+    ///     "leaderboard",
+    ///     players,
+    ///     |player| player.points as f64,
+    ///     |player| player.name.clone().into_bytes(),
+    /// ).await?;
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    pub async fn sorted_set_put_elements_by<T>(
+        &self,
+        cache_name: impl Into<String>,
+        sorted_set_name: impl IntoBytes,
+        items: impl IntoIterator<Item = T>,
+        score_fn: impl Fn(&T) -> f64,
+        value_fn: impl Fn(&T) -> Vec<u8>,
+    ) -> MomentoResult<SortedSetPutElementsResponse> {
+        let elements: Vec<(Vec<u8>, f64)> = items
+            .into_iter()
+            .map(|item| (value_fn(&item), score_fn(&item)))
+            .collect();
+        self.sorted_set_put_elements(cache_name, sorted_set_name, elements)
+            .await
     }
 
     /// Fetch the elements in the given sorted set by their rank.
@@ -1151,6 +1697,7 @@ impl CacheClient {
     /// * `cache_name` - The name of the cache containing the sorted set.
     /// * `sorted_set_name` - The name of the sorted set to add an element to.
     /// * `order` - The order to sort the elements by. [SortedSetOrder::Ascending] or [SortedSetOrder::Descending].
+    ///   If `None`, the client's default sorted set order is used.
     /// * `start_rank` - The rank of the first element to fetch. Defaults to 0. This rank is
     ///   inclusive, i.e. the element at this rank will be fetched.
     /// * `end_rank` - The rank of the last element to fetch. This rank is exclusive, i.e. the
@@ -1200,7 +1747,7 @@ impl CacheClient {
         &self,
         cache_name: impl Into<String>,
         sorted_set_name: impl IntoBytes,
-        order: SortedSetOrder,
+        order: impl Into<Option<SortedSetOrder>>,
         start_rank: Option<i32>,
         end_rank: Option<i32>,
     ) -> MomentoResult<SortedSetFetchResponse> {
@@ -1213,7 +1760,148 @@ impl CacheClient {
         if let Some(end) = end_rank {
             request = request.end_rank(end);
         }
-        request.send(self).await
+        self.send_request(request).await
+    }
+
+    /// Copies a sorted set to a new name, replacing anything stored under the destination name, and
+    /// returns the number of elements copied.
+    ///
+    /// The sorted set is fetched and written back through the client, so the copy is not atomic. See
+    /// [SortedSetCopyRequest] for what that means for concurrent changes.
+    ///
+    /// # Arguments
+    ///
+    /// * `cache_name` - The name of the cache containing the sorted set.
+    /// * `source_name` - The name of the sorted set to copy.
+    /// * `destination_name` - The name to copy the sorted set to.
+    /// * `ttl` - The time-to-live of the copy. If `None`, the client's default time-to-live is used.
+    ///
+    /// # Examples
+    /// Assumes that a CacheClient named `cache_client` has been created and is available.
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use momento_test_util::create_doctest_cache_client;
+    /// # tokio_test::block_on(async {
+    /// use momento::cache::SortedSetCopyResponse;
+    /// # let (cache_client, cache_name) = create_doctest_cache_client();
+    /// # cache_client.sorted_set_put_elements(&cache_name, "scores", vec![("alice", 1.0), ("bob", 2.0)]).await?;
+    ///
+    /// let response = cache_client.sorted_set_copy(&cache_name, "scores", "scores-snapshot", None).await?;
+    ///
+    /// if let SortedSetCopyResponse::Copied { count } = response {
+    ///     println!("Copied {} elements", count);
+    /// }
+    /// # assert_eq!(response, SortedSetCopyResponse::Copied { count: 2 });
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    /// You can also use the [send_request](CacheClient::send_request) method to copy a sorted set using a [SortedSetCopyRequest].
+    pub async fn sorted_set_copy(
+        &self,
+        cache_name: impl Into<String>,
+        source_name: impl IntoBytes,
+        destination_name: impl IntoBytes,
+        ttl: impl Into<Option<Duration>>,
+    ) -> MomentoResult<SortedSetCopyResponse> {
+        let request = SortedSetCopyRequest::new(cache_name, source_name, destination_name).ttl(ttl);
+        self.send_request(request).await
+    }
+
+    /// Fetch the highest and lowest scored elements of the given sorted set together.
+    ///
+    /// The two ends are fetched concurrently, so this takes about as long as a single
+    /// [sorted_set_fetch_by_rank](CacheClient::sorted_set_fetch_by_rank).
+    ///
+    /// # Arguments
+    ///
+    /// * `cache_name` - The name of the cache containing the sorted set.
+    /// * `sorted_set_name` - The name of the sorted set to fetch from.
+    /// * `top_n` - The number of highest scored elements to fetch. Must be greater than zero.
+    /// * `bottom_n` - The number of lowest scored elements to fetch. Must be greater than zero.
+    ///
+    /// # Examples
+    /// Assumes that a CacheClient named `cache_client` has been created and is available.
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use momento_test_util::create_doctest_cache_client;
+    /// # tokio_test::block_on(async {
+    /// use momento::cache::SortedSetFetchExtremesResponse;
+    /// # let (cache_client, cache_name) = create_doctest_cache_client();
+    ///
+    /// match cache_client.sorted_set_fetch_extremes(&cache_name, "leaderboard", 10, 10).await? {
+    ///     SortedSetFetchExtremesResponse::Hit { top, bottom } => {
+    ///         println!("Leaders: {:?}", top.into_strings()?);
+    ///         println!("Laggards: {:?}", bottom.into_strings()?);
+    ///     }
+    ///     SortedSetFetchExtremesResponse::Miss => println!("Leaderboard does not exist"),
+    /// }
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    /// You can also use the [send_request](CacheClient::send_request) method to fetch both ends using a [SortedSetFetchExtremesRequest].
+    pub async fn sorted_set_fetch_extremes(
+        &self,
+        cache_name: impl Into<String>,
+        sorted_set_name: impl IntoBytes,
+        top_n: u32,
+        bottom_n: u32,
+    ) -> MomentoResult<SortedSetFetchExtremesResponse> {
+        let request =
+            SortedSetFetchExtremesRequest::new(cache_name, sorted_set_name, top_n, bottom_n);
+        self.send_request(request).await
+    }
+
+    /// Computes the union of several sorted sets and returns the merged elements without
+    /// storing them.
+    ///
+    /// The score of an element in the union is the sum of its scores in each source sorted set
+    /// multiplied by that set's weight. Sources that do not exist are skipped; if none of them
+    /// exist, the response is a [SortedSetFetchResponse::Miss].
+    ///
+    /// # Arguments
+    ///
+    /// * `cache_name` - The name of the cache containing the sorted sets.
+    /// * `sources` - The names of the sorted sets to merge, each paired with a weight.
+    /// * `order` - The order to sort the merged elements by. [SortedSetOrder::Ascending] or [SortedSetOrder::Descending].
+    ///   If `None`, the client's default sorted set order is used.
+    /// * `limit` - The maximum number of merged elements to return. Returns all elements if `None`.
+    ///
+    /// # Examples
+    /// Assumes that a CacheClient named `cache_client` has been created and is available.
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use momento_test_util::create_doctest_cache_client;
+    /// # tokio_test::block_on(async {
+    /// use std::convert::TryInto;
+    /// use momento::cache::{SortedSetElement, SortedSetOrder};
+    /// # let (cache_client, cache_name) = create_doctest_cache_client();
+    ///
+    /// let merged: Vec<SortedSetElement<String>> = cache_client.sorted_set_union(
+    ///     &cache_name,
+    ///     vec![("scores_day_1", 1.0), ("scores_day_2", 0.5)],
+    ///     SortedSetOrder::Descending,
+    ///     Some(10)
+    /// ).await?.try_into().unwrap_or_default();
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    /// You can also use the [send_request](CacheClient::send_request) method to compute a union using a [SortedSetUnionRequest].
+    ///
+    /// For more examples of handling the response, see [SortedSetFetchResponse].
+    pub async fn sorted_set_union<S: IntoBytes>(
+        &self,
+        cache_name: impl Into<String>,
+        sources: impl IntoIterator<Item = (S, f64)>,
+        order: impl Into<Option<SortedSetOrder>>,
+        limit: Option<u32>,
+    ) -> MomentoResult<SortedSetFetchResponse> {
+        let request = SortedSetUnionRequest::new(cache_name, sources)
+            .order(order)
+            .limit(limit);
+        self.send_request(request).await
     }
 
     /// Fetch the elements in the given sorted set by their score.
@@ -1223,6 +1911,7 @@ impl CacheClient {
     /// * `cache_name` - The name of the cache containing the sorted set.
     /// * `sorted_set_name` - The name of the sorted set to add an element to.
     /// * `order` - The order to sort the elements by. [SortedSetOrder::Ascending] or [SortedSetOrder::Descending].
+    ///   If `None`, the client's default sorted set order is used.
     ///
     /// # Optional Arguments
     /// If you use [send_request](CacheClient::send_request) to fetch elements using a
@@ -1279,10 +1968,10 @@ impl CacheClient {
         &self,
         cache_name: impl Into<String>,
         sorted_set_name: impl IntoBytes,
-        order: SortedSetOrder,
+        order: impl Into<Option<SortedSetOrder>>,
     ) -> MomentoResult<SortedSetFetchResponse> {
         let request = SortedSetFetchByScoreRequest::new(cache_name, sorted_set_name).order(order);
-        request.send(self).await
+        self.send_request(request).await
     }
 
     /// Remove multiple elements from the sorted set.
@@ -1324,7 +2013,7 @@ impl CacheClient {
         values: V,
     ) -> MomentoResult<SortedSetRemoveElementsResponse> {
         let request = SortedSetRemoveElementsRequest::new(cache_name, sorted_set_name, values);
-        request.send(self).await
+        self.send_request(request).await
     }
 
     /// GetResponse the number of entries in a sorted set collection.
@@ -1361,7 +2050,55 @@ impl CacheClient {
         sorted_set_name: impl IntoBytes,
     ) -> MomentoResult<SortedSetLengthResponse> {
         let request = SortedSetLengthRequest::new(cache_name, sorted_set_name);
-        request.send(self).await
+        self.send_request(request).await
+    }
+
+    /// Get the number of elements in a sorted set collection whose scores are within a range.
+    ///
+    /// # Arguments
+    /// * `cache_name` - name of cache
+    /// * `sorted_set_name` - name of the sorted set
+    /// * `min_score` - the lower bound on the scores of the elements to count
+    /// * `max_score` - the upper bound on the scores of the elements to count
+    ///
+    /// # Examples
+    /// Assumes that a CacheClient named `cache_client` has been created and is available.
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use momento_test_util::create_doctest_cache_client;
+    /// # tokio_test::block_on(async {
+    /// use std::convert::TryInto;
+    /// use momento::cache::ScoreBound;
+    /// # let (cache_client, cache_name) = create_doctest_cache_client();
+    /// let sorted_set_name = "sorted_set";
+    ///
+    /// # cache_client.sorted_set_put_elements(&cache_name, sorted_set_name.to_string(), vec![("value1", 1.0), ("value2", 2.0)]).await?;
+    ///
+    /// let length: u32 = cache_client.sorted_set_length_by_score_range(
+    ///     cache_name,
+    ///     sorted_set_name,
+    ///     ScoreBound::Inclusive(1.0),
+    ///     ScoreBound::Exclusive(2.0)
+    /// ).await?.try_into().expect("Expected a sorted set length!");
+    /// # assert_eq!(length, 1);
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    /// You can also use the [send_request](CacheClient::send_request) method to count elements by score using a [SortedSetLengthByScoreRequest].
+    ///
+    /// For more examples of handling the response, see [SortedSetLengthByScoreResponse].
+    pub async fn sorted_set_length_by_score_range(
+        &self,
+        cache_name: impl Into<String>,
+        sorted_set_name: impl IntoBytes,
+        min_score: ScoreBound,
+        max_score: ScoreBound,
+    ) -> MomentoResult<SortedSetLengthByScoreResponse> {
+        let request = SortedSetLengthByScoreRequest::new(cache_name, sorted_set_name)
+            .min_score(min_score)
+            .max_score(max_score);
+        self.send_request(request).await
     }
 
     /// GetResponse the rank (position) of a specific element in a sorted set.
@@ -1401,7 +2138,7 @@ impl CacheClient {
         value: impl IntoBytes,
     ) -> MomentoResult<SortedSetGetRankResponse> {
         let request = SortedSetGetRankRequest::new(cache_name, sorted_set_name, value);
-        request.send(self).await
+        self.send_request(request).await
     }
 
     /// GetResponse the score of a specific element in a sorted set.
@@ -1441,7 +2178,7 @@ impl CacheClient {
         value: impl IntoBytes,
     ) -> MomentoResult<SortedSetGetScoreResponse> {
         let request = SortedSetGetScoreRequest::new(cache_name, sorted_set_name, value);
-        request.send(self).await
+        self.send_request(request).await
     }
 
     /// Gets the scores of specific elements in a sorted set.
@@ -1487,7 +2224,7 @@ impl CacheClient {
         values: F,
     ) -> MomentoResult<SortedSetGetScoresResponse<F>> {
         let request = SortedSetGetScoresRequest::new(cache_name, sorted_set_name, values);
-        request.send(self).await
+        self.send_request(request).await
     }
 
     /// Increment the score of an element in the sorted set. Incrementing a score that was not set
@@ -1536,7 +2273,119 @@ impl CacheClient {
     ) -> MomentoResult<SortedSetIncrementScoreResponse> {
         let request =
             SortedSetIncrementScoreRequest::new(cache_name, sorted_set_name, value, score);
-        request.send(self).await
+        self.send_request(request).await
+    }
+
+    /// Increments the score of an element in a sorted set and returns its new score and rank.
+    /// Adds the element with the increment amount as its score if it does not exist.
+    ///
+    /// This sends an increment and then a get rank request. They are not atomic, so the rank
+    /// also reflects changes other clients make in between; see
+    /// [SortedSetIncrementScoreAndGetRankRequest].
+    ///
+    /// # Arguments
+    ///
+    /// * `cache_name` - The name of the cache containing the sorted set.
+    /// * `sorted_set_name` - The name of the sorted set.
+    /// * `value` - The value of the element whose score to increment.
+    /// * `amount` - The amount to add to the score.
+    /// * `order` - The order the rank is counted in.
+    ///
+    /// # Examples
+    /// Assumes that a CacheClient named `cache_client` has been created and is available.
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use momento_test_util::create_doctest_cache_client;
+    /// # tokio_test::block_on(async {
+    /// use momento::cache::SortedSetOrder;
+    /// # let (cache_client, cache_name) = create_doctest_cache_client();
+    ///
+    /// let response = cache_client
+    ///     .sorted_set_increment_score_and_get_rank(
+    ///         &cache_name,
+    ///         "leaderboard",
+    ///         "alice",
+    ///         15.0,
+    ///         SortedSetOrder::Descending,
+    ///     )
+    ///     .await?;
+    /// println!("alice now has {} points, rank {:?}", response.score, response.rank);
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    /// You can also use the [send_request](CacheClient::send_request) method to increment a score and get its rank using a [SortedSetIncrementScoreAndGetRankRequest]
+    /// which will allow you to set [optional arguments](SortedSetIncrementScoreAndGetRankRequest#optional-arguments) as well.
+    pub async fn sorted_set_increment_score_and_get_rank(
+        &self,
+        cache_name: impl Into<String>,
+        sorted_set_name: impl IntoBytes,
+        value: impl IntoBytes,
+        amount: f64,
+        order: SortedSetOrder,
+    ) -> MomentoResult<SortedSetIncrementScoreAndGetRankResponse> {
+        let request = SortedSetIncrementScoreAndGetRankRequest::new(
+            cache_name,
+            sorted_set_name,
+            value,
+            amount,
+            order,
+        );
+        self.send_request(request).await
+    }
+
+    /// Increments the scores of several elements in a sorted set. Elements that do not exist are
+    /// added with the increment amount as their score.
+    ///
+    /// One increment is sent per element, with at most
+    /// [DEFAULT_INCREMENT_SCORES_CONCURRENCY](crate::cache::DEFAULT_INCREMENT_SCORES_CONCURRENCY)
+    /// in flight at once. Each increment succeeds or fails on its own; see
+    /// [SortedSetIncrementScoresResponse] for how to tell which ones failed.
+    ///
+    /// # Arguments
+    ///
+    /// * `cache_name` - The name of the cache containing the sorted set.
+    /// * `sorted_set_name` - The name of the sorted set to increment scores in.
+    /// * `elements` - The values to increment, each paired with the amount to increment it by.
+    ///
+    /// # Optional Arguments
+    /// If you use [send_request](CacheClient::send_request) to increment scores using a
+    /// [SortedSetIncrementScoresRequest], you can also provide the following optional arguments:
+    ///
+    /// * `collection_ttl` - The time-to-live for the collection. If not provided, the client's default time-to-live is used.
+    /// * `max_concurrency` - The maximum number of increments in flight at once.
+    ///
+    /// # Examples
+    /// Assumes that a CacheClient named `cache_client` has been created and is available.
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use momento_test_util::create_doctest_cache_client;
+    /// # tokio_test::block_on(async {
+    /// # let (cache_client, cache_name) = create_doctest_cache_client();
+    /// let response = cache_client.sorted_set_increment_scores(
+    ///     &cache_name,
+    ///     "user_counters",
+    ///     vec![("user_1", 1.0), ("user_2", 5.0)]
+    /// ).await?;
+    ///
+    /// for result in response.results {
+    ///     match result {
+    ///         Ok(score) => println!("New score: {}", score),
+    ///         Err(e) => eprintln!("Increment failed: {}", e),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    pub async fn sorted_set_increment_scores<V: IntoBytes>(
+        &self,
+        cache_name: impl Into<String>,
+        sorted_set_name: impl IntoBytes,
+        elements: impl IntoSortedSetElements<V>,
+    ) -> MomentoResult<SortedSetIncrementScoresResponse> {
+        let request = SortedSetIncrementScoresRequest::new(cache_name, sorted_set_name, elements);
+        self.send_request(request).await
     }
 
     /// Check if the provided key exists in the cache
@@ -1571,7 +2420,7 @@ impl CacheClient {
         key: impl IntoBytes,
     ) -> MomentoResult<KeyExistsResponse> {
         let request = KeyExistsRequest::new(cache_name, key);
-        request.send(self).await
+        self.send_request(request).await
     }
 
     /// Check if the provided keys exist in the cache.
@@ -1609,7 +2458,7 @@ impl CacheClient {
         keys: impl IntoBytesIterable,
     ) -> MomentoResult<KeysExistResponse> {
         let request = KeysExistRequest::new(cache_name, keys);
-        request.send(self).await
+        self.send_request(request).await
     }
 
     /// Adds an integer quantity to a cache item.
@@ -1658,7 +2507,7 @@ impl CacheClient {
         amount: i64,
     ) -> MomentoResult<IncrementResponse> {
         let request = IncrementRequest::new(cache_name, key, amount);
-        request.send(self).await
+        self.send_request(request).await
     }
 
     /// Return the type of an item in the cache.
@@ -1696,14 +2545,14 @@ impl CacheClient {
         key: impl IntoBytes,
     ) -> MomentoResult<ItemGetTypeResponse> {
         let request = ItemGetTypeRequest::new(cache_name, key);
-        request.send(self).await
+        self.send_request(request).await
     }
 
-    /// Return the remaining ttl of an item in the cache
+    /// Return the types of a batch of items in the cache. The lookups are sent concurrently.
     ///
     /// # Arguments
     /// * `cache_name` - name of cache
-    /// * `key` - the key of the item for which the remaining ttl is requested
+    /// * `keys` - the keys of the items to get the types of
     ///
     /// # Examples
     /// Assumes that a CacheClient named `cache_client` has been created and is available.
@@ -1712,35 +2561,45 @@ impl CacheClient {
     /// # use momento_test_util::create_doctest_cache_client;
     /// # tokio_test::block_on(async {
     /// # let (cache_client, cache_name) = create_doctest_cache_client();
+    /// use std::collections::HashMap;
     /// use std::convert::TryInto;
-    /// use momento::cache::ItemGetTtlResponse;
-    /// use std::time::Duration;
+    /// use momento::cache::ItemGetTypeResponse;
     /// # cache_client.set(&cache_name, "key1", "value").await?;
     ///
-    /// let remaining_ttl: Duration = cache_client.item_get_ttl(cache_name, "key1").await?.try_into().expect("Expected an item ttl!");
-    /// # assert!(remaining_ttl <= Duration::from_secs(5));
+    /// let response = cache_client.item_get_type_batch(&cache_name, vec!["key1", "key2"]).await?;
+    /// let item_types: HashMap<String, ItemGetTypeResponse> = response.try_into().expect("stored string keys");
+    /// for (key, item_type) in item_types {
+    ///     match item_type {
+    ///         ItemGetTypeResponse::Hit { key_type } => println!("{} is a {}", key, key_type),
+    ///         ItemGetTypeResponse::Miss => println!("{} was not found", key),
+    ///     }
+    /// }
     /// # Ok(())
     /// # })
     /// # }
     /// ```
-    /// You can also use the [send_request](CacheClient::send_request) method to get an item's ttl using a [ItemGetTtlRequest].
+    /// You can also use the [send_request](CacheClient::send_request) method to get the item types using an [ItemGetTypeBatchRequest].
     ///
-    /// For more examples of handling the response, see [ItemGetTtlResponse].
-    pub async fn item_get_ttl(
+    /// For more examples of handling the response, see [ItemGetTypeBatchResponse].
+    pub async fn item_get_type_batch(
         &self,
         cache_name: impl Into<String>,
-        key: impl IntoBytes,
-    ) -> MomentoResult<ItemGetTtlResponse> {
-        let request = ItemGetTtlRequest::new(cache_name, key);
-        request.send(self).await
+        keys: impl IntoBytesIterable,
+    ) -> MomentoResult<ItemGetTypeBatchResponse> {
+        let request = ItemGetTypeBatchRequest::new(cache_name, keys);
+        self.send_request(request).await
     }
 
-    /// Update the ttl of an item in the cache.
+    /// Deletes an item of any type from the cache and returns the type it had before it was
+    /// deleted, e.g. for an audit log.
+    ///
+    /// The type is read with [item_get_type](CacheClient::item_get_type) before the item is
+    /// deleted. The two calls are not atomic: if the item is replaced in between, the reported
+    /// type is the one it had when it was read.
     ///
     /// # Arguments
     /// * `cache_name` - name of cache
-    /// * `key` - the key of the item for which ttl is requested
-    /// * `ttl` - The time-to-live that should overwrite the current ttl.
+    /// * `key` - the key of the item to delete
     ///
     /// # Examples
     /// Assumes that a CacheClient named `cache_client` has been created and is available.
@@ -1749,11 +2608,99 @@ impl CacheClient {
     /// # use momento_test_util::create_doctest_cache_client;
     /// # tokio_test::block_on(async {
     /// # let (cache_client, cache_name) = create_doctest_cache_client();
-    /// use std::time::Duration;
-    /// use momento::cache::UpdateTtlResponse;
+    /// use momento::cache::{ItemGetTypeResponse, ItemType};
     /// # cache_client.set(&cache_name, "key1", "value").await?;
     ///
-    /// match(cache_client.update_ttl(&cache_name, "key1", Duration::from_secs(10)).await?) {
+    /// match cache_client.delete_and_report_type(&cache_name, "key1").await? {
+    ///     ItemGetTypeResponse::Hit { key_type } => println!("Deleted an item of type {}", key_type),
+    ///     ItemGetTypeResponse::Miss => println!("There was no item to delete"),
+    /// }
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    pub async fn delete_and_report_type(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+    ) -> MomentoResult<ItemGetTypeResponse> {
+        let cache_name = cache_name.into();
+        let key = key.into_bytes();
+        let item_type = self.item_get_type(&cache_name, key.clone()).await?;
+        // Delete even on a miss in case the item was created after its type was read
+        self.delete(cache_name, key).await?;
+        Ok(item_type)
+    }
+
+    /// Return the remaining ttl of an item in the cache
+    ///
+    /// Items of every type have a ttl, so this also returns the remaining ttl of a collection
+    /// when given its name as the key. Use it to check the ttl applied by a collection write's
+    /// [CollectionTtl](crate::cache::CollectionTtl).
+    ///
+    /// # Arguments
+    /// * `cache_name` - name of cache
+    /// * `key` - the key of the item, or the name of the collection, for which the remaining ttl is requested
+    ///
+    /// # Examples
+    /// Assumes that a CacheClient named `cache_client` has been created and is available.
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use momento_test_util::create_doctest_cache_client;
+    /// # tokio_test::block_on(async {
+    /// # let (cache_client, cache_name) = create_doctest_cache_client();
+    /// use std::convert::TryInto;
+    /// use momento::cache::ItemGetTtlResponse;
+    /// use std::time::Duration;
+    /// # cache_client.set(&cache_name, "key1", "value").await?;
+    ///
+    /// let remaining_ttl: Duration = cache_client.item_get_ttl(&cache_name, "key1").await?.try_into().expect("Expected an item ttl!");
+    /// # assert!(remaining_ttl <= Duration::from_secs(5));
+    ///
+    /// // Collections are looked up by name
+    /// # cache_client.set_add_elements(&cache_name, "set1", vec!["a"]).await?;
+    /// let collection_ttl: Duration = cache_client.item_get_ttl(&cache_name, "set1").await?.try_into().expect("Expected a collection ttl!");
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    /// You can also use the [send_request](CacheClient::send_request) method to get an item's ttl using a [ItemGetTtlRequest].
+    ///
+    /// For more examples of handling the response, see [ItemGetTtlResponse].
+    pub async fn item_get_ttl(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+    ) -> MomentoResult<ItemGetTtlResponse> {
+        let request = ItemGetTtlRequest::new(cache_name, key);
+        self.send_request(request).await
+    }
+
+    /// Update the ttl of an item in the cache.
+    ///
+    /// Like [item_get_ttl](CacheClient::item_get_ttl), this also works on a collection when given
+    /// its name as the key. It only changes the ttl of an item that exists, and returns
+    /// [UpdateTtlResponse::Miss] otherwise, so a keep-alive job can use it to extend a collection
+    /// without resurrecting one that has expired. Momento removes a collection when its last
+    /// element is removed, so a collection that exists is never empty.
+    ///
+    /// # Arguments
+    /// * `cache_name` - name of cache
+    /// * `key` - the key of the item, or the name of the collection, for which ttl is updated
+    /// * `ttl` - The time-to-live that should overwrite the current ttl.
+    ///
+    /// # Examples
+    /// Assumes that a CacheClient named `cache_client` has been created and is available.
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use momento_test_util::create_doctest_cache_client;
+    /// # tokio_test::block_on(async {
+    /// # let (cache_client, cache_name) = create_doctest_cache_client();
+    /// use std::time::Duration;
+    /// use momento::cache::UpdateTtlResponse;
+    /// # cache_client.set(&cache_name, "key1", "value").await?;
+    ///
+    /// match(cache_client.update_ttl(&cache_name, "key1", Duration::from_secs(10)).await?) {
     ///     UpdateTtlResponse::Set => println!("TTL updated"),
     ///     UpdateTtlResponse::Miss => return Err(anyhow::Error::msg("cache miss"))
     /// };
@@ -1769,7 +2716,58 @@ impl CacheClient {
         ttl: Duration,
     ) -> MomentoResult<UpdateTtlResponse> {
         let request = UpdateTtlRequest::new(cache_name, key, ttl);
-        request.send(self).await
+        self.send_request(request).await
+    }
+
+    /// Refresh the ttl of an item in the cache, but only if its remaining ttl has dropped below
+    /// a threshold.
+    ///
+    /// Useful for sliding expiration, such as keeping a session alive while it is in use. An item
+    /// that does not need refreshing costs one round trip and one that does costs two. The
+    /// refresh only ever lengthens the ttl, so a concurrent refresh by another client is never
+    /// cut short.
+    ///
+    /// # Arguments
+    /// * `cache_name` - name of cache
+    /// * `key` - the key of the item to refresh
+    /// * `threshold` - refresh the item only if its remaining ttl is below this
+    /// * `new_ttl` - the ttl to give the item when it is refreshed. Should be greater than `threshold`.
+    ///
+    /// # Examples
+    /// Assumes that a CacheClient named `cache_client` has been created and is available.
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use momento_test_util::create_doctest_cache_client;
+    /// # tokio_test::block_on(async {
+    /// # let (cache_client, cache_name) = create_doctest_cache_client();
+    /// use std::time::Duration;
+    /// use momento::cache::TouchTtlIfBelowResponse;
+    /// # cache_client.set(&cache_name, "session", "data").await?;
+    ///
+    /// let response = cache_client.touch_ttl_if_below(
+    ///     &cache_name,
+    ///     "session",
+    ///     Duration::from_secs(300),
+    ///     Duration::from_secs(1800),
+    /// ).await?;
+    ///
+    /// if response == TouchTtlIfBelowResponse::Miss {
+    ///     println!("Session expired");
+    /// }
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    /// You can also use the [send_request](CacheClient::send_request) method to refresh an item's ttl using a [TouchTtlIfBelowRequest].
+    pub async fn touch_ttl_if_below(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+        threshold: Duration,
+        new_ttl: Duration,
+    ) -> MomentoResult<TouchTtlIfBelowResponse> {
+        let request = TouchTtlIfBelowRequest::new(cache_name, key, threshold, new_ttl);
+        self.send_request(request).await
     }
 
     /// Increase the ttl of an item in the cache.
@@ -1807,7 +2805,7 @@ impl CacheClient {
         ttl: Duration,
     ) -> MomentoResult<IncreaseTtlResponse> {
         let request = IncreaseTtlRequest::new(cache_name, key, ttl);
-        request.send(self).await
+        self.send_request(request).await
     }
 
     /// Decrease the ttl of an item in the cache.
@@ -1845,7 +2843,7 @@ impl CacheClient {
         ttl: Duration,
     ) -> MomentoResult<DecreaseTtlResponse> {
         let request = DecreaseTtlRequest::new(cache_name, key, ttl);
-        request.send(self).await
+        self.send_request(request).await
     }
 
     /// Associate the given key with the given value if key is not already present in the cache.
@@ -1896,7 +2894,51 @@ impl CacheClient {
         value: impl IntoBytes,
     ) -> MomentoResult<SetIfAbsentResponse> {
         let request = SetIfAbsentRequest::new(cache_name, key, value);
-        request.send(self).await
+        self.send_request(request).await
+    }
+
+    /// Associate each of the given keys with its value, but only for keys that are not already
+    /// present in the cache.
+    ///
+    /// One conditional set is sent per item and they run concurrently, so the batch takes about
+    /// one round trip. Each item is stored or not on its own; see [SetIfAbsentBatchRequest] for
+    /// what happens when part of the batch fails.
+    ///
+    /// # Arguments
+    ///
+    /// * `cache_name` - name of cache
+    /// * `items` - the keys and values to set
+    /// * `ttl` - The time-to-live for the items. If `None`, the client's default time-to-live is used.
+    ///
+    /// # Examples
+    /// Assumes that a CacheClient named `cache_client` has been created and is available.
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use momento_test_util::create_doctest_cache_client;
+    /// # tokio_test::block_on(async {
+    /// # let (cache_client, cache_name) = create_doctest_cache_client();
+    /// use std::time::Duration;
+    ///
+    /// let response = cache_client.set_if_absent_batch(
+    ///     &cache_name,
+    ///     vec![("lock:resource-1", "worker-1"), ("lock:resource-2", "worker-1")],
+    ///     Duration::from_secs(30),
+    /// ).await?;
+    ///
+    /// println!("Acquired {} locks", response.stored_keys().len());
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    /// You can also use the [send_request](CacheClient::send_request) method to conditionally set items using a [SetIfAbsentBatchRequest].
+    pub async fn set_if_absent_batch<K: IntoBytes, V: IntoBytes>(
+        &self,
+        cache_name: impl Into<String>,
+        items: impl IntoIterator<Item = (K, V)>,
+        ttl: impl Into<Option<Duration>>,
+    ) -> MomentoResult<SetIfAbsentBatchResponse> {
+        let request = SetIfAbsentBatchRequest::new(cache_name, items).ttl(ttl);
+        self.send_request(request).await
     }
 
     /// Associate the given key with the given value if key is present in the cache.
@@ -1947,7 +2989,7 @@ impl CacheClient {
         value: impl IntoBytes,
     ) -> MomentoResult<SetIfPresentResponse> {
         let request = SetIfPresentRequest::new(cache_name, key, value);
-        request.send(self).await
+        self.send_request(request).await
     }
 
     /// Associates the given key with the given value if the key is present
@@ -2001,7 +3043,7 @@ impl CacheClient {
         equal: impl IntoBytes,
     ) -> MomentoResult<SetIfEqualResponse> {
         let request = SetIfEqualRequest::new(cache_name, key, value, equal);
-        request.send(self).await
+        self.send_request(request).await
     }
 
     /// Associates the given key with the given value if the key does not already exist in the
@@ -2055,7 +3097,7 @@ impl CacheClient {
         not_equal: impl IntoBytes,
     ) -> MomentoResult<SetIfNotEqualResponse> {
         let request = SetIfNotEqualRequest::new(cache_name, key, value, not_equal);
-        request.send(self).await
+        self.send_request(request).await
     }
 
     /// Associates the given key with the given value if the key exists in the cache
@@ -2109,7 +3151,7 @@ impl CacheClient {
         not_equal: impl IntoBytes,
     ) -> MomentoResult<SetIfPresentAndNotEqualResponse> {
         let request = SetIfPresentAndNotEqualRequest::new(cache_name, key, value, not_equal);
-        request.send(self).await
+        self.send_request(request).await
     }
 
     /// Associate the given key with the given value if the key does not already
@@ -2163,7 +3205,7 @@ impl CacheClient {
         equal: impl IntoBytes,
     ) -> MomentoResult<SetIfAbsentOrEqualResponse> {
         let request = SetIfAbsentOrEqualRequest::new(cache_name, key, value, equal);
-        request.send(self).await
+        self.send_request(request).await
     }
 
     /// Gets the number of elements in the given list.
@@ -2199,7 +3241,7 @@ impl CacheClient {
         list_name: impl IntoBytes,
     ) -> MomentoResult<ListLengthResponse> {
         let request = ListLengthRequest::new(cache_name, list_name);
-        request.send(self).await
+        self.send_request(request).await
     }
 
     /// Adds multiple elements to the front of the given list. Creates the list if it does not already exist.
@@ -2249,7 +3291,7 @@ impl CacheClient {
         values: impl IntoBytesIterable,
     ) -> MomentoResult<ListConcatenateFrontResponse> {
         let request = ListConcatenateFrontRequest::new(cache_name, list_name, values);
-        request.send(self).await
+        self.send_request(request).await
     }
 
     /// Adds multiple elements to the back of the given list. Creates the list if it does not already exist.
@@ -2299,7 +3341,7 @@ impl CacheClient {
         values: impl IntoBytesIterable,
     ) -> MomentoResult<ListConcatenateBackResponse> {
         let request = ListConcatenateBackRequest::new(cache_name, list_name, values);
-        request.send(self).await
+        self.send_request(request).await
     }
 
     /// Gets a list item from a cache with optional slices.
@@ -2343,7 +3385,131 @@ impl CacheClient {
         list_name: impl IntoBytes,
     ) -> MomentoResult<ListFetchResponse> {
         let request = ListFetchRequest::new(cache_name, list_name);
-        request.send(self).await
+        self.send_request(request).await
+    }
+
+    /// Copies a list to a new name, replacing anything stored under the destination name, and
+    /// returns the number of elements copied.
+    ///
+    /// The list is fetched and written back through the client, so the copy is not atomic. See
+    /// [ListCopyRequest] for what that means for concurrent changes.
+    ///
+    /// # Arguments
+    ///
+    /// * `cache_name` - The name of the cache containing the list.
+    /// * `source_name` - The name of the list to copy.
+    /// * `destination_name` - The name to copy the list to.
+    /// * `ttl` - The time-to-live of the copy. If `None`, the client's default time-to-live is used.
+    ///
+    /// # Examples
+    /// Assumes that a CacheClient named `cache_client` has been created and is available.
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use momento_test_util::create_doctest_cache_client;
+    /// # tokio_test::block_on(async {
+    /// use momento::cache::ListCopyResponse;
+    /// # let (cache_client, cache_name) = create_doctest_cache_client();
+    /// # cache_client.list_concatenate_back(&cache_name, "history", vec!["a", "b"]).await?;
+    ///
+    /// let response = cache_client.list_copy(&cache_name, "history", "history-snapshot", None).await?;
+    ///
+    /// if let ListCopyResponse::Copied { count } = response {
+    ///     println!("Copied {} elements", count);
+    /// }
+    /// # assert_eq!(response, ListCopyResponse::Copied { count: 2 });
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    /// You can also use the [send_request](CacheClient::send_request) method to copy a list using a [ListCopyRequest].
+    pub async fn list_copy(
+        &self,
+        cache_name: impl Into<String>,
+        source_name: impl IntoBytes,
+        destination_name: impl IntoBytes,
+        ttl: impl Into<Option<Duration>>,
+    ) -> MomentoResult<ListCopyResponse> {
+        let request = ListCopyRequest::new(cache_name, source_name, destination_name).ttl(ttl);
+        self.send_request(request).await
+    }
+
+    /// Gets a list item from a cache as UTF-8 strings.
+    ///
+    /// Returns `None` if the list does not exist, and an error if any element is not valid UTF-8.
+    ///
+    /// # Arguments
+    /// * `cache_name` - name of cache
+    /// * `list_name` - name of the list
+    ///
+    /// # Examples
+    /// Assumes that a CacheClient named `cache_client` has been created and is available.
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use momento_test_util::create_doctest_cache_client;
+    /// # tokio_test::block_on(async {
+    /// # let (cache_client, cache_name) = create_doctest_cache_client();
+    /// let list_name = "list-name";
+    /// # cache_client.list_concatenate_front(&cache_name, list_name, vec!["value1", "value2"]).await?;
+    ///
+    /// match cache_client.list_fetch_strings(cache_name, list_name).await? {
+    ///     Some(values) => println!("Values: {:?}", values),
+    ///     None => println!("List not found"),
+    /// }
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    pub async fn list_fetch_strings(
+        &self,
+        cache_name: impl Into<String>,
+        list_name: impl IntoBytes,
+    ) -> MomentoResult<Option<Vec<String>>> {
+        match self.list_fetch(cache_name, list_name).await? {
+            ListFetchResponse::Hit { values } => values.try_into().map(Some),
+            ListFetchResponse::Miss => Ok(None),
+        }
+    }
+
+    /// Gets a slice of a list item from a cache using a Rust range.
+    ///
+    /// Negative bounds count back from the end of the list, so `-1` is the last element:
+    /// * `-10..` fetches the last 10 elements, or the whole list if it has fewer than 10.
+    /// * `..-1` fetches every element except the last.
+    /// * `2..5` fetches the elements at indices 2, 3 and 4.
+    ///
+    /// # Arguments
+    /// * `cache_name` - name of cache
+    /// * `list_name` - name of the list
+    /// * `range` - the range of elements to fetch
+    ///
+    /// # Examples
+    /// Assumes that a CacheClient named `cache_client` has been created and is available.
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use momento_test_util::create_doctest_cache_client;
+    /// # tokio_test::block_on(async {
+    /// use std::convert::TryInto;
+    /// # let (cache_client, cache_name) = create_doctest_cache_client();
+    /// let list_name = "list-name";
+    /// # cache_client.list_concatenate_back(&cache_name, list_name, vec!["value1", "value2", "value3"]).await?;
+    ///
+    /// let last_two: Vec<String> = cache_client.list_fetch_range(cache_name, list_name, -2..).await?.try_into().expect("Expected a list fetch!");
+    /// # assert_eq!(last_two, vec!["value2", "value3"]);
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    /// You can also use the [send_request](CacheClient::send_request) method to fetch a range of a list using a [ListFetchRequest].
+    ///
+    /// For more examples of handling the response, see [ListFetchResponse].
+    pub async fn list_fetch_range(
+        &self,
+        cache_name: impl Into<String>,
+        list_name: impl IntoBytes,
+        range: impl RangeBounds<i32>,
+    ) -> MomentoResult<ListFetchResponse> {
+        let request = ListFetchRequest::new(cache_name, list_name).range(range);
+        self.send_request(request).await
     }
 
     /// Remove and return the last element from a list item.
@@ -2379,7 +3545,7 @@ impl CacheClient {
         list_name: impl IntoBytes,
     ) -> MomentoResult<ListPopBackResponse> {
         let request = ListPopBackRequest::new(cache_name, list_name);
-        request.send(self).await
+        self.send_request(request).await
     }
 
     /// Remove and return the first element from a list item.
@@ -2415,7 +3581,7 @@ impl CacheClient {
         list_name: impl IntoBytes,
     ) -> MomentoResult<ListPopFrontResponse> {
         let request = ListPopFrontRequest::new(cache_name, list_name);
-        request.send(self).await
+        self.send_request(request).await
     }
 
     /// Remove all elements in a list item equal to a particular value.
@@ -2453,7 +3619,53 @@ impl CacheClient {
         value: impl IntoBytes,
     ) -> MomentoResult<ListRemoveValueResponse> {
         let request = ListRemoveValueRequest::new(cache_name, list_name, value);
-        request.send(self).await
+        self.send_request(request).await
+    }
+
+    /// Trims a list in place, keeping only the elements from `start_index` (inclusive) to
+    /// `end_index` (exclusive). Negative indices count back from the end of the list, and
+    /// `None` leaves that side of the range unbounded, so passing `None` for both keeps every
+    /// element.
+    ///
+    /// # Arguments
+    /// * `cache_name` - name of cache
+    /// * `list_name` - name of the list
+    /// * `start_index` - the first element to keep, or `None` to keep from the start of the list
+    /// * `end_index` - the element after the last one to keep, or `None` to keep to the end of the list
+    ///
+    /// # Examples
+    /// Assumes that a CacheClient named `cache_client` has been created and is available.
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use momento_test_util::create_doctest_cache_client;
+    /// # tokio_test::block_on(async {
+    /// use momento::cache::ListRetainResponse;
+    /// # let (cache_client, cache_name) = create_doctest_cache_client();
+    /// let list_name = "list-name";
+    /// # cache_client.list_concatenate_back(&cache_name, list_name, vec!["value1", "value2", "value3"]).await?;
+    ///
+    /// // Keep the first two elements
+    /// match cache_client.list_retain(&cache_name, list_name, None, 2).await? {
+    ///     ListRetainResponse::Hit => println!("List trimmed"),
+    ///     ListRetainResponse::Miss => println!("List does not exist"),
+    /// }
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    /// You can also use the [send_request](CacheClient::send_request) method to trim a list using a [ListRetainRequest],
+    /// which also accepts a Rust range and a collection TTL.
+    pub async fn list_retain(
+        &self,
+        cache_name: impl Into<String>,
+        list_name: impl IntoBytes,
+        start_index: impl Into<Option<i32>>,
+        end_index: impl Into<Option<i32>>,
+    ) -> MomentoResult<ListRetainResponse> {
+        let request = ListRetainRequest::new(cache_name, list_name)
+            .start_index(start_index)
+            .end_index(end_index);
+        self.send_request(request).await
     }
 
     /// Adds an element to the back of the given list. Creates the list if it does not already exist.
@@ -2497,7 +3709,7 @@ impl CacheClient {
         value: impl IntoBytes,
     ) -> MomentoResult<ListPushBackResponse> {
         let request = ListPushBackRequest::new(cache_name, list_name, value);
-        request.send(self).await
+        self.send_request(request).await
     }
 
     /// Adds an element to the front of the given list. Creates the list if it does not already exist.
@@ -2541,7 +3753,7 @@ impl CacheClient {
         value: impl IntoBytes,
     ) -> MomentoResult<ListPushFrontResponse> {
         let request = ListPushFrontRequest::new(cache_name, list_name, value);
-        request.send(self).await
+        self.send_request(request).await
     }
 
     /// Lower-level API to send any type of MomentoRequest to the server. This is used for cases when
@@ -2549,22 +3761,176 @@ impl CacheClient {
     /// that request type.
     ///
     /// See [SortedSetFetchByScoreRequest] for an example of creating a request with optional fields.
+    ///
+    /// If the client was built with a maximum number of concurrent requests, the request counts
+    /// against that limit until it completes. Requests sent as several RPCs, such as batches,
+    /// count each RPC against the limit instead.
+    ///
+    /// Errors returned from this method carry the operation and cache name in
+    /// [MomentoError::context](crate::MomentoError::context).
     pub async fn send_request<R: MomentoRequest>(&self, request: R) -> MomentoResult<R::Response> {
-        request.send(self).await
+        let context = request.error_context();
+        let _permit = self
+            .acquire_permit(&request)
+            .await
+            .map_err(|e| e.with_context(context.clone()))?;
+        let started_at = Instant::now();
+        let send = async {
+            if self.middlewares.is_empty() {
+                request.send(self).await
+            } else {
+                self.send_with_middleware(request, context.as_ref()).await
+            }
+        };
+        #[cfg(feature = "tracing")]
+        let span = request_span::request_span(context.as_ref());
+        #[cfg(feature = "tracing")]
+        let send = tracing::Instrument::instrument(send, span.clone());
+        let result = send.await;
+        #[cfg(feature = "tracing")]
+        request_span::record_outcome(&span, &result, result.as_ref().ok().and_then(R::is_hit));
+        self.warn_if_slow(context.as_ref(), started_at.elapsed());
+        let response = match result {
+            Ok(response) => response,
+            Err(e) => {
+                if self.treat_errors_as_miss && MISS_ON_ERROR_CODES.contains(&e.error_code) {
+                    if let Some(miss) = R::miss() {
+                        log::debug!(
+                            "returning a miss for {} after a transient error: {}",
+                            context
+                                .as_ref()
+                                .map_or("request".to_string(), |c| c.to_string()),
+                            e
+                        );
+                        return Ok(miss);
+                    }
+                }
+                return Err(e.with_context(context));
+            }
+        };
+        if let Some(hit_rate_tracker) = &self.hit_rate_tracker {
+            let cache_name = context.as_ref().and_then(|c| c.cache_name.as_deref());
+            if let (Some(cache_name), Some(hit)) = (cache_name, R::is_hit(&response)) {
+                hit_rate_tracker.record(cache_name, hit);
+            }
+        }
+        Ok(response)
+    }
+
+    /// Like [send_request](CacheClient::send_request), but also returns the underlying
+    /// `momento_protos` response the typed response was converted from. This is an escape hatch
+    /// for advanced users who need a field the typed response does not expose yet.
+    ///
+    /// The request counts against the client's concurrency limit and its errors carry
+    /// [MomentoError::context](crate::MomentoError::context), but it skips middleware, hit rate
+    /// tracking and `treat_errors_as_miss`. Like [MomentoRequest::send], this is not an
+    /// explicitly supported API: the raw response type is tied to the SDK's protobuf dependency
+    /// and may change between releases without notice.
+    ///
+    /// # Example
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use momento_test_util::create_doctest_cache_client;
+    /// # tokio_test::block_on(async {
+    /// use momento::cache::GetRequest;
+    /// # let (cache_client, cache_name) = create_doctest_cache_client();
+    /// # cache_client.set(&cache_name, "key", "value").await?;
+    /// let (response, raw_response) = cache_client
+    ///     .send_request_raw(GetRequest::new(&cache_name, "key"))
+    ///     .await?;
+    /// println!("{response}, raw message: {:?}", raw_response.message);
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    pub async fn send_request_raw<R: MomentoRawRequest>(
+        &self,
+        request: R,
+    ) -> MomentoResult<(R::Response, R::RawResponse)> {
+        let context = request.error_context();
+        let _permit = self
+            .acquire_permit(&request)
+            .await
+            .map_err(|e| e.with_context(context.clone()))?;
+        request
+            .send_raw(self)
+            .await
+            .map_err(|e| e.with_context(context))
+    }
+
+    /// Returns the configuration the client was built with, including any overrides applied
+    /// on the builder such as `with_num_connections`.
+    ///
+    /// # Example
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use momento_test_util::create_doctest_cache_client;
+    /// # tokio_test::block_on(async {
+    /// # let (cache_client, _cache_name) = create_doctest_cache_client();
+    /// let configuration = cache_client.configuration();
+    /// println!(
+    ///     "deadline: {:?}, connections: {}",
+    ///     configuration.deadline_millis(),
+    ///     configuration.num_connections()
+    /// );
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    pub fn configuration(&self) -> &Configuration {
+        &self.configuration
+    }
+
+    /// Returns one of the underlying gRPC clients for cache data operations.
+    ///
+    /// This is an escape hatch for advanced users who need a field of a `momento_protos`
+    /// response that the typed response types do not expose yet. Requests sent with this
+    /// client bypass the SDK's validation, timeouts and concurrency limits, and its type is
+    /// tied to the SDK's gRPC and protobuf dependencies. It is not an explicitly supported API
+    /// and its signature may change between releases without notice. To read a field of a
+    /// response, prefer [send_request_raw](CacheClient::send_request_raw).
+    #[doc(hidden)]
+    pub fn raw_data_client(&self) -> ScsClient<InterceptedService<Channel, HeaderInterceptor>> {
+        self.next_data_client()
+    }
+
+    /// Returns the underlying gRPC client for cache control operations.
+    ///
+    /// Like [raw_data_client](CacheClient::raw_data_client), this is an unstable escape hatch
+    /// whose signature may change between releases without notice.
+    #[doc(hidden)]
+    pub fn raw_control_client(
+        &self,
+    ) -> ScsControlClient<InterceptedService<Channel, HeaderInterceptor>> {
+        self.control_client()
     }
 
     /* helper fns */
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn new(
         data_clients: Vec<ScsClient<InterceptedService<Channel, HeaderInterceptor>>>,
         control_client: ScsControlClient<InterceptedService<Channel, HeaderInterceptor>>,
         configuration: Configuration,
         item_default_ttl: Duration,
+        request_limiter: Option<RequestLimiter>,
+        default_sorted_set_order: SortedSetOrder,
+        hit_rate_tracker: Option<HitRateTracker>,
+        connection_pinning: bool,
+        middlewares: Vec<Arc<dyn Middleware>>,
+        treat_errors_as_miss: bool,
     ) -> Self {
         Self {
             data_clients,
             control_client,
             configuration,
             item_default_ttl,
+            request_limiter,
+            default_sorted_set_order,
+            hit_rate_tracker,
+            connection_pinning,
+            next_data_client_index: Arc::new(AtomicUsize::new(0)),
+            middlewares,
+            treat_errors_as_miss,
         }
     }
 
@@ -2579,17 +3945,897 @@ impl CacheClient {
         self.configuration.deadline_millis()
     }
 
+    fn warn_if_slow(&self, context: Option<&ErrorContext>, elapsed: Duration) {
+        let threshold = match self.configuration.slow_request_threshold() {
+            Some(threshold) if elapsed > threshold => threshold,
+            _ => return,
+        };
+        let operation = context.map_or("unknown", |c| c.operation.as_str());
+        let cache_name = context
+            .and_then(|c| c.cache_name.as_deref())
+            .unwrap_or("none");
+        #[cfg(feature = "tracing")]
+        tracing::warn!(
+            operation,
+            cache_name,
+            elapsed_ms = elapsed.as_millis() as u64,
+            threshold_ms = threshold.as_millis() as u64,
+            "slow momento request"
+        );
+        #[cfg(not(feature = "tracing"))]
+        log::warn!(
+            "slow request: operation={} cache_name={} elapsed_ms={} threshold_ms={}",
+            operation,
+            cache_name,
+            elapsed.as_millis(),
+            threshold.as_millis()
+        );
+    }
+
+    /// Sends one of the sub-requests a composite request, such as a batch, is made of. Like a
+    /// request passed to [send_request](CacheClient::send_request), it takes its own permit from
+    /// the client's concurrency limit, so a batch never has more requests in flight than the
+    /// limit allows, and its RPCs are retried as the configured retry strategy decides.
+    pub(crate) async fn send_sub_request<R: MomentoRequest>(
+        &self,
+        request: R,
+    ) -> MomentoResult<R::Response> {
+        let _permit = self.acquire_permit(&request).await?;
+        request.send(self).await
+    }
+
+    /// Acquires a permit from the client's concurrency limit for the request, if the client has
+    /// a limit and the request is not composite.
+    async fn acquire_permit<R: MomentoRequest>(
+        &self,
+        request: &R,
+    ) -> MomentoResult<Option<OwnedSemaphorePermit>> {
+        match &self.request_limiter {
+            Some(request_limiter) if !request.is_composite() => {
+                request_limiter.acquire().await.map(Some)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    pub(crate) fn default_sorted_set_order(&self) -> SortedSetOrder {
+        self.default_sorted_set_order
+    }
+
     pub(crate) fn control_client(
         &self,
     ) -> ScsControlClient<InterceptedService<Channel, HeaderInterceptor>> {
         self.control_client.clone()
     }
 
+    async fn send_with_middleware<R: MomentoRequest>(
+        &self,
+        request: R,
+        context: Option<&ErrorContext>,
+    ) -> MomentoResult<R::Response> {
+        let metadata = RequestMetadata {
+            operation: context.map_or_else(|| "Unknown".to_string(), |c| c.operation.clone()),
+            cache_name: context.and_then(|c| c.cache_name.clone()),
+        };
+
+        let mut entered = 0;
+        let mut short_circuit = None;
+        for middleware in &self.middlewares {
+            if let Err(e) = middleware.on_request(&metadata).await {
+                short_circuit = Some(e);
+                break;
+            }
+            entered += 1;
+        }
+
+        let started_at = Instant::now();
+        let (result, duration) = match short_circuit {
+            Some(e) => (Err(e), Duration::ZERO),
+            None => {
+                let result = request.send(self).await;
+                (result, started_at.elapsed())
+            }
+        };
+
+        let response = ResponseMetadata {
+            error: result.as_ref().err(),
+            hit: result.as_ref().ok().and_then(R::is_hit),
+            duration,
+        };
+        for middleware in self.middlewares[..entered].iter().rev() {
+            middleware.on_response(&metadata, &response).await;
+        }
+        result
+    }
+
     pub(crate) fn next_data_client(
         &self,
     ) -> ScsClient<InterceptedService<Channel, HeaderInterceptor>> {
-        let next_index =
-            NEXT_DATA_CLIENT_INDEX.fetch_add(1, Ordering::Relaxed) % self.data_clients.len();
-        self.data_clients[next_index].clone()
+        self.data_clients[self.next_data_client_index()].clone()
+    }
+
+    /// Sends a gRPC request on the next data client with `call`, retrying it on another data
+    /// client as the configured retry strategy decides. Each attempt gets a copy of the request,
+    /// including its metadata and deadline. Requests that are not `idempotent` are sent once
+    /// unless the configuration allows retrying them.
+    pub(crate) async fn send_with_retries<Req, Resp, F, Fut>(
+        &self,
+        request: tonic::Request<Req>,
+        idempotent: bool,
+        call: F,
+    ) -> MomentoResult<tonic::Response<Resp>>
+    where
+        Req: Clone + prost::Message,
+        F: Fn(
+            ScsClient<InterceptedService<Channel, HeaderInterceptor>>,
+            tonic::Request<Req>,
+        ) -> Fut,
+        Fut: Future<Output = Result<tonic::Response<Resp>, tonic::Status>>,
+    {
+        let retry_strategy = match &self.configuration.retry_strategy {
+            Some(retry_strategy)
+                if idempotent || self.configuration.retry_non_idempotent_requests =>
+            {
+                retry_strategy
+            }
+            _ => return Ok(self.send_attempt(&call, request, 1).await?),
+        };
+
+        let mut attempt = 1;
+        loop {
+            let mut attempt_request = tonic::Request::new(request.get_ref().clone());
+            *attempt_request.metadata_mut() = request.metadata().clone();
+            let error = match self.send_attempt(&call, attempt_request, attempt).await {
+                Ok(response) => return Ok(response),
+                Err(status) => MomentoError::from(status),
+            };
+            match retry_strategy.determine_when_to_retry(&error, attempt) {
+                Some(delay) => {
+                    log::debug!(
+                        "retrying request after attempt {} failed with {:?}",
+                        attempt,
+                        error.error_code
+                    );
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                None => return Err(error),
+            }
+        }
+    }
+
+    /// Sends one attempt of a gRPC request on the next data client, logging its size and timing
+    /// if the configuration enables debug logging.
+    async fn send_attempt<Req, Resp, F, Fut>(
+        &self,
+        call: &F,
+        request: tonic::Request<Req>,
+        attempt: u32,
+    ) -> Result<tonic::Response<Resp>, tonic::Status>
+    where
+        Req: prost::Message,
+        F: Fn(
+            ScsClient<InterceptedService<Channel, HeaderInterceptor>>,
+            tonic::Request<Req>,
+        ) -> Fut,
+        Fut: Future<Output = Result<tonic::Response<Resp>, tonic::Status>>,
+    {
+        if !self.configuration.debug_logging {
+            return call(self.next_data_client(), request).await;
+        }
+
+        let rpc = std::any::type_name::<Req>()
+            .rsplit("::")
+            .next()
+            .unwrap_or("unknown");
+        let cache_name = request
+            .metadata()
+            .get("cache")
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or("none")
+            .to_string();
+        let request_bytes = request.get_ref().encoded_len();
+        let started_at = Instant::now();
+        let result = call(self.next_data_client(), request).await;
+        let status = match &result {
+            Ok(_) => tonic::Code::Ok,
+            Err(status) => status.code(),
+        };
+        let elapsed_ms = started_at.elapsed().as_millis() as u64;
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            rpc,
+            cache_name = cache_name.as_str(),
+            attempt,
+            request_bytes,
+            elapsed_ms,
+            status = ?status,
+            "momento request attempt"
+        );
+        #[cfg(not(feature = "tracing"))]
+        log::debug!(
+            "request attempt: rpc={} cache_name={} attempt={} request_bytes={} elapsed_ms={} status={:?}",
+            rpc,
+            cache_name,
+            attempt,
+            request_bytes,
+            elapsed_ms,
+            status
+        );
+        result
+    }
+
+    // Clones of a client share its rotation, but separately built clients rotate independently.
+    fn next_data_client_index(&self) -> usize {
+        if self.connection_pinning {
+            return 0;
+        }
+        self.next_data_client_index.fetch_add(1, Ordering::Relaxed) % self.data_clients.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use std::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    use futures::future::BoxFuture;
+    use futures::StreamExt;
+    use tokio::net::TcpListener;
+
+    use crate::cache::messages::data::scalar::get::Value;
+    use crate::cache::{
+        configurations, Configuration, GetRequest, GetResponse, IncrementRequest,
+        MaxConcurrentRequestsBehavior, MetricsMiddleware, Middleware, MomentoRequest,
+        RequestMetadata, RequestMetric, ResponseMetadata, SetIfAbsentOrEqualRequest,
+        SetIfAbsentRequest, SetIfEqualRequest, SetIfNotEqualRequest,
+        SetIfPresentAndNotEqualRequest, SetIfPresentRequest,
+    };
+    use crate::config::retry_strategy::{
+        ExponentialBackoffRetryStrategy, FixedCountRetryStrategy, RetryStrategy,
+    };
+    use crate::utils::bounded_fan_out;
+    use crate::{
+        CacheClient, CredentialProvider, ErrorContext, MomentoError, MomentoErrorCode,
+        MomentoResult,
+    };
+
+    const V1_TOKEN: &str = "eyJlbmRwb2ludCI6Im1vbWVudG9fZW5kcG9pbnQiLCJhcGlfa2V5IjoiZXlKaGJHY2lPaUpJVXpJMU5pSjkuZXlKemRXSWlPaUowWlhOMElITjFZbXBsWTNRaUxDSjJaWElpT2pFc0luQWlPaUlpZlEuaGcyd01iV2Utd2VzUVZ0QTd3dUpjUlVMalJwaFhMUXdRVFZZZlFMM0w3YyJ9Cg==";
+
+    fn test_client(connection_pinning: bool) -> MomentoResult<CacheClient> {
+        CacheClient::builder()
+            .default_ttl(Duration::from_secs(60))
+            .configuration(configurations::Laptop::latest())
+            .credential_provider(CredentialProvider::from_string(V1_TOKEN)?)
+            .with_num_connections(3)
+            .connection_pinning(connection_pinning)
+            .build()
+    }
+
+    #[tokio::test]
+    async fn clients_rotate_independently() -> MomentoResult<()> {
+        let first = test_client(false)?;
+        let second = test_client(false)?;
+
+        assert_eq!(first.next_data_client_index(), 0);
+        assert_eq!(first.next_data_client_index(), 1);
+        assert_eq!(second.next_data_client_index(), 0);
+        assert_eq!(first.next_data_client_index(), 2);
+        assert_eq!(first.next_data_client_index(), 0);
+        assert_eq!(second.next_data_client_index(), 1);
+
+        // Clones share the rotation of the client they were cloned from
+        let first_clone = first.clone();
+        assert_eq!(first_clone.next_data_client_index(), 1);
+        assert_eq!(first.next_data_client_index(), 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn endpoint_overrides_are_used_by_channels() -> MomentoResult<()> {
+        let cache_listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("should be able to bind a listener");
+        let control_listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("should be able to bind a listener");
+        let cache_endpoint = format!(
+            "http://{}",
+            cache_listener
+                .local_addr()
+                .expect("listener has an address")
+        );
+        let control_endpoint = format!(
+            "http://{}",
+            control_listener
+                .local_addr()
+                .expect("listener has an address")
+        );
+
+        let client = CacheClient::builder()
+            .default_ttl(Duration::from_secs(60))
+            .configuration(configurations::Laptop::latest())
+            .credential_provider(
+                CredentialProvider::from_string(V1_TOKEN)?
+                    .with_cache_endpoint(&cache_endpoint)?
+                    .with_control_endpoint(&control_endpoint)?,
+            )
+            .build()?;
+
+        // The channels connect lazily, so send a request on each to make it connect
+        let data_client = client.clone();
+        tokio::spawn(async move { data_client.get("cache", "key").await });
+        let control_client = client.clone();
+        tokio::spawn(async move { control_client.list_caches().await });
+
+        tokio::time::timeout(Duration::from_secs(5), cache_listener.accept())
+            .await
+            .expect("data channel should connect to the cache endpoint override")
+            .expect("should be able to accept a connection");
+        tokio::time::timeout(Duration::from_secs(5), control_listener.accept())
+            .await
+            .expect("control channel should connect to the control endpoint override")
+            .expect("should be able to accept a connection");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn pinned_client_uses_one_connection() -> MomentoResult<()> {
+        let client = test_client(true)?;
+        for _ in 0..5 {
+            assert_eq!(client.next_data_client_index(), 0);
+        }
+        Ok(())
+    }
+
+    fn retrying_client(retry_strategy: impl RetryStrategy + 'static) -> MomentoResult<CacheClient> {
+        CacheClient::builder()
+            .default_ttl(Duration::from_secs(60))
+            .configuration(configurations::Laptop::latest())
+            .credential_provider(CredentialProvider::from_string(V1_TOKEN)?)
+            .retry_strategy(retry_strategy)
+            .build()
+    }
+
+    #[tokio::test]
+    async fn timed_out_get_is_retried_but_increment_is_not() -> MomentoResult<()> {
+        let client = retrying_client(FixedCountRetryStrategy::new(3))?;
+        let timeout = || tonic::Status::deadline_exceeded("timed out after sending");
+
+        let (result, attempts) =
+            send_flaky(&client, GetRequest::<String>::is_idempotent(), 1, timeout).await;
+        assert!(result.is_ok());
+        assert_eq!(attempts, 2);
+
+        let (result, attempts) = send_flaky(
+            &client,
+            IncrementRequest::<String>::is_idempotent(),
+            1,
+            timeout,
+        )
+        .await;
+        assert_eq!(
+            result.unwrap_err().error_code,
+            MomentoErrorCode::TimeoutError
+        );
+        assert_eq!(attempts, 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn timed_out_conditional_set_is_not_retried() -> MomentoResult<()> {
+        // A conditional set that timed out may have been applied, and retrying it would then
+        // report the condition as failed
+        assert!(!SetIfAbsentRequest::<String, String>::is_idempotent());
+        assert!(!SetIfPresentRequest::<String, String>::is_idempotent());
+        assert!(!SetIfEqualRequest::<String, String, String>::is_idempotent());
+        assert!(!SetIfNotEqualRequest::<String, String, String>::is_idempotent());
+        assert!(!SetIfPresentAndNotEqualRequest::<String, String, String>::is_idempotent());
+        assert!(!SetIfAbsentOrEqualRequest::<String, String, String>::is_idempotent());
+
+        let client = retrying_client(FixedCountRetryStrategy::new(3))?;
+        let (result, attempts) = send_flaky(
+            &client,
+            SetIfAbsentRequest::<String, String>::is_idempotent(),
+            1,
+            || tonic::Status::deadline_exceeded("timed out after sending"),
+        )
+        .await;
+        assert_eq!(
+            result.unwrap_err().error_code,
+            MomentoErrorCode::TimeoutError
+        );
+        assert_eq!(attempts, 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn non_idempotent_requests_are_retried_when_allowed() -> MomentoResult<()> {
+        let client = CacheClient::builder()
+            .default_ttl(Duration::from_secs(60))
+            .configuration(configurations::Laptop::latest())
+            .credential_provider(CredentialProvider::from_string(V1_TOKEN)?)
+            .retry_strategy(FixedCountRetryStrategy::new(3))
+            .retry_non_idempotent_requests(true)
+            .build()?;
+        let (result, attempts) = send_flaky(
+            &client,
+            IncrementRequest::<String>::is_idempotent(),
+            1,
+            || tonic::Status::deadline_exceeded("timed out after sending"),
+        )
+        .await;
+        assert!(result.is_ok());
+        assert_eq!(attempts, 2);
+        Ok(())
+    }
+
+    /// Sends a request through `send_with_retries` that fails with `status` on its first
+    /// `failures` attempts, returning the result and the number of attempts made.
+    async fn send_flaky(
+        client: &CacheClient,
+        idempotent: bool,
+        failures: u32,
+        status: fn() -> tonic::Status,
+    ) -> (MomentoResult<tonic::Response<()>>, u32) {
+        let attempts = AtomicU32::new(0);
+        let result = client
+            .send_with_retries(tonic::Request::new(()), idempotent, |_client, _request| {
+                let attempt = attempts.fetch_add(1, Ordering::SeqCst) + 1;
+                async move {
+                    if attempt <= failures {
+                        Err(status())
+                    } else {
+                        Ok(tonic::Response::new(()))
+                    }
+                }
+            })
+            .await;
+        (result, attempts.load(Ordering::SeqCst))
+    }
+
+    #[tokio::test]
+    async fn flaky_request_is_retried_until_it_succeeds() -> MomentoResult<()> {
+        let client = retrying_client(FixedCountRetryStrategy::new(3))?;
+        let (result, attempts) =
+            send_flaky(&client, true, 2, || tonic::Status::unavailable("flaky")).await;
+        assert!(result.is_ok());
+        assert_eq!(attempts, 3);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn flaky_request_stops_at_max_attempts() -> MomentoResult<()> {
+        let client = retrying_client(ExponentialBackoffRetryStrategy::new(
+            3,
+            Duration::from_millis(1),
+            Duration::from_millis(10),
+        ))?;
+        let (result, attempts) = send_flaky(&client, true, 5, || {
+            tonic::Status::deadline_exceeded("slow")
+        })
+        .await;
+        assert_eq!(
+            result.unwrap_err().error_code,
+            MomentoErrorCode::TimeoutError
+        );
+        assert_eq!(attempts, 3);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn non_retryable_errors_are_not_retried() -> MomentoResult<()> {
+        let client = retrying_client(FixedCountRetryStrategy::new(3))?;
+        let (result, attempts) =
+            send_flaky(&client, true, 1, || tonic::Status::invalid_argument("bad")).await;
+        assert_eq!(
+            result.unwrap_err().error_code,
+            MomentoErrorCode::InvalidArgumentError
+        );
+        assert_eq!(attempts, 1);
+
+        let (result, attempts) =
+            send_flaky(&client, true, 1, || tonic::Status::not_found("gone")).await;
+        assert!(result.is_err());
+        assert_eq!(attempts, 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn debug_logging_does_not_change_how_requests_are_sent() -> MomentoResult<()> {
+        let configuration: Configuration = configurations::Laptop::latest().into();
+        let configuration = configuration
+            .with_retry_strategy(FixedCountRetryStrategy::new(3))
+            .with_debug_logging(true);
+        let client = CacheClient::builder()
+            .default_ttl(Duration::from_secs(60))
+            .configuration(configuration)
+            .credential_provider(CredentialProvider::from_string(V1_TOKEN)?)
+            .build()?;
+        let (result, attempts) =
+            send_flaky(&client, true, 2, || tonic::Status::unavailable("flaky")).await;
+        assert!(result.is_ok());
+        assert_eq!(attempts, 3);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn requests_are_not_retried_without_a_retry_strategy() -> MomentoResult<()> {
+        let client = test_client(false)?;
+        let (result, attempts) =
+            send_flaky(&client, true, 1, || tonic::Status::unavailable("flaky")).await;
+        assert_eq!(
+            result.unwrap_err().error_code,
+            MomentoErrorCode::ServerUnavailable
+        );
+        assert_eq!(attempts, 1);
+        Ok(())
+    }
+
+    /// A get that responds with `response` without going over the network.
+    struct CannedGet(GetResponse);
+
+    impl MomentoRequest for CannedGet {
+        type Response = GetResponse;
+
+        fn error_context(&self) -> Option<ErrorContext> {
+            Some(ErrorContext::new("Get", "cache".to_string()))
+        }
+
+        fn is_hit(response: &GetResponse) -> Option<bool> {
+            GetRequest::<String>::is_hit(response)
+        }
+
+        async fn send(self, _cache_client: &CacheClient) -> MomentoResult<GetResponse> {
+            Ok(self.0)
+        }
+    }
+
+    /// A get that counts how many copies of itself are in flight, keeping the peak.
+    struct TrackedGet {
+        in_flight: Arc<AtomicUsize>,
+        peak: Arc<AtomicUsize>,
+    }
+
+    impl MomentoRequest for TrackedGet {
+        type Response = GetResponse;
+
+        async fn send(self, _cache_client: &CacheClient) -> MomentoResult<GetResponse> {
+            let now = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.peak.fetch_max(now, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(5)).await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+            Ok(GetResponse::Miss)
+        }
+    }
+
+    /// A batch that sends `count` tracked gets at once as sub-requests.
+    struct TrackedBatch {
+        count: usize,
+        in_flight: Arc<AtomicUsize>,
+        peak: Arc<AtomicUsize>,
+    }
+
+    impl MomentoRequest for TrackedBatch {
+        type Response = usize;
+
+        fn is_composite(&self) -> bool {
+            true
+        }
+
+        async fn send(self, cache_client: &CacheClient) -> MomentoResult<usize> {
+            let gets = (0..self.count).map(|_| {
+                cache_client.send_sub_request(TrackedGet {
+                    in_flight: self.in_flight.clone(),
+                    peak: self.peak.clone(),
+                })
+            });
+            let responses: Vec<_> = bounded_fan_out(gets, self.count).collect().await;
+            responses.into_iter().collect::<MomentoResult<Vec<_>>>()?;
+            Ok(self.count)
+        }
+    }
+
+    fn limited_client(
+        max_concurrent_requests: usize,
+        behavior: MaxConcurrentRequestsBehavior,
+    ) -> MomentoResult<CacheClient> {
+        CacheClient::builder()
+            .default_ttl(Duration::from_secs(60))
+            .configuration(configurations::Laptop::latest())
+            .credential_provider(CredentialProvider::from_string(V1_TOKEN)?)
+            .with_max_concurrent_requests(max_concurrent_requests, behavior)
+            .build()
+    }
+
+    #[tokio::test]
+    async fn batch_sub_requests_stay_within_the_concurrency_limit() -> MomentoResult<()> {
+        let client = limited_client(2, MaxConcurrentRequestsBehavior::Wait)?;
+        let peak = Arc::new(AtomicUsize::new(0));
+        let batch = TrackedBatch {
+            count: 20,
+            in_flight: Arc::new(AtomicUsize::new(0)),
+            peak: peak.clone(),
+        };
+
+        // The batch itself holds no permit, so its sub-requests can take them all
+        let sent = tokio::time::timeout(Duration::from_secs(5), client.send_request(batch))
+            .await
+            .expect("batch should not deadlock on the limiter")?;
+        assert_eq!(sent, 20);
+        assert_eq!(peak.load(Ordering::SeqCst), 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn batch_sub_requests_fail_fast_past_the_concurrency_limit() -> MomentoResult<()> {
+        let client = limited_client(2, MaxConcurrentRequestsBehavior::FailFast)?;
+        let batch = TrackedBatch {
+            count: 20,
+            in_flight: Arc::new(AtomicUsize::new(0)),
+            peak: Arc::new(AtomicUsize::new(0)),
+        };
+
+        let error = client.send_request(batch).await.unwrap_err();
+        assert_eq!(error.error_code, MomentoErrorCode::ClientResourceExhausted);
+        Ok(())
+    }
+
+    /// Records the hooks it sees as `<name> <hook> <outcome>`, optionally rejecting requests.
+    #[derive(Debug)]
+    struct Recorder {
+        name: &'static str,
+        reject: bool,
+        events: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl Middleware for Recorder {
+        fn on_request<'a>(
+            &'a self,
+            request: &'a RequestMetadata,
+        ) -> BoxFuture<'a, MomentoResult<()>> {
+            Box::pin(async move {
+                self.events
+                    .lock()
+                    .unwrap()
+                    .push(format!("{} request {}", self.name, request.operation));
+                if self.reject {
+                    return Err(MomentoError {
+                        message: "rejected by middleware".to_string(),
+                        error_code: MomentoErrorCode::LimitExceededError,
+                        inner_error: None,
+                        details: None,
+                        context: None,
+                    });
+                }
+                Ok(())
+            })
+        }
+
+        fn on_response<'a>(
+            &'a self,
+            _request: &'a RequestMetadata,
+            response: &'a ResponseMetadata<'a>,
+        ) -> BoxFuture<'a, ()> {
+            Box::pin(async move {
+                let outcome = match (response.error, response.hit) {
+                    (Some(error), _) => format!("{:?}", error.error_code),
+                    (None, Some(true)) => "hit".to_string(),
+                    (None, Some(false)) => "miss".to_string(),
+                    (None, None) => "ok".to_string(),
+                };
+                self.events
+                    .lock()
+                    .unwrap()
+                    .push(format!("{} response {}", self.name, outcome));
+            })
+        }
+    }
+
+    fn recorder(name: &'static str, reject: bool, events: &Arc<Mutex<Vec<String>>>) -> Recorder {
+        Recorder {
+            name,
+            reject,
+            events: events.clone(),
+        }
+    }
+
+    #[tokio::test]
+    async fn middleware_observes_hits_and_misses_in_order() -> MomentoResult<()> {
+        let events = Arc::new(Mutex::new(vec![]));
+        let client = CacheClient::builder()
+            .default_ttl(Duration::from_secs(60))
+            .configuration(configurations::Laptop::latest())
+            .credential_provider(CredentialProvider::from_string(V1_TOKEN)?)
+            .add_middleware(recorder("outer", false, &events))
+            .add_middleware(recorder("inner", false, &events))
+            .build()?;
+
+        let hit = GetResponse::Hit {
+            value: Value::new(b"value".to_vec()),
+        };
+        client.send_request(CannedGet(hit)).await?;
+        client.send_request(CannedGet(GetResponse::Miss)).await?;
+
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec![
+                "outer request Get",
+                "inner request Get",
+                "inner response hit",
+                "outer response hit",
+                "outer request Get",
+                "inner request Get",
+                "inner response miss",
+                "outer response miss",
+            ]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn middleware_can_short_circuit_a_request() -> MomentoResult<()> {
+        let events = Arc::new(Mutex::new(vec![]));
+        let client = CacheClient::builder()
+            .default_ttl(Duration::from_secs(60))
+            .configuration(configurations::Laptop::latest())
+            .credential_provider(CredentialProvider::from_string(V1_TOKEN)?)
+            .add_middleware(recorder("outer", false, &events))
+            .add_middleware(recorder("gate", true, &events))
+            .add_middleware(recorder("inner", false, &events))
+            .build()?;
+
+        let error = client
+            .send_request(CannedGet(GetResponse::Miss))
+            .await
+            .unwrap_err();
+        assert_eq!(error.error_code, MomentoErrorCode::LimitExceededError);
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec![
+                "outer request Get",
+                "gate request Get",
+                "outer response LimitExceededError",
+            ]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn metrics_middleware_can_leave_out_the_cache_name() -> MomentoResult<()> {
+        let metrics = Arc::new(Mutex::new(vec![]));
+        let record = |label: &'static str| {
+            let metrics = metrics.clone();
+            move |metric: &RequestMetric| {
+                metrics
+                    .lock()
+                    .unwrap()
+                    .push((label, metric.cache_name.clone()))
+            }
+        };
+        let client = CacheClient::builder()
+            .default_ttl(Duration::from_secs(60))
+            .configuration(configurations::Laptop::latest())
+            .credential_provider(CredentialProvider::from_string(V1_TOKEN)?)
+            .add_middleware(MetricsMiddleware::new(record("default")))
+            .add_middleware(MetricsMiddleware::new(record("without")).with_cache_name(false))
+            .build()?;
+
+        client.send_request(CannedGet(GetResponse::Miss)).await?;
+        assert_eq!(
+            *metrics.lock().unwrap(),
+            vec![("without", None), ("default", Some("cache".to_string()))]
+        );
+        Ok(())
+    }
+
+    /// Builds a client whose cache endpoint refuses connections.
+    async fn unreachable_client(treat_errors_as_miss: bool) -> MomentoResult<CacheClient> {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("should be able to bind a listener");
+        let endpoint = format!(
+            "http://{}",
+            listener.local_addr().expect("listener has an address")
+        );
+        drop(listener);
+
+        CacheClient::builder()
+            .default_ttl(Duration::from_secs(60))
+            .configuration(configurations::Laptop::latest())
+            .credential_provider(
+                CredentialProvider::from_string(V1_TOKEN)?.with_cache_endpoint(&endpoint)?,
+            )
+            .treat_errors_as_miss(treat_errors_as_miss)
+            .build()
+    }
+
+    #[tokio::test]
+    async fn unavailable_reads_are_misses_when_errors_are_treated_as_miss() -> MomentoResult<()> {
+        let client = unreachable_client(true).await?;
+        assert_eq!(client.get("cache", "key").await?, GetResponse::Miss);
+
+        let error = client.set("cache", "key", "value").await.unwrap_err();
+        assert_eq!(error.error_code, MomentoErrorCode::ServerUnavailable);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn unavailable_reads_error_by_default() -> MomentoResult<()> {
+        let client = unreachable_client(false).await?;
+        let error = client.get("cache", "key").await.unwrap_err();
+        assert_eq!(error.error_code, MomentoErrorCode::ServerUnavailable);
+        Ok(())
+    }
+
+    #[cfg(feature = "tracing")]
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn get_runs_in_a_request_span() -> MomentoResult<()> {
+        let client = unreachable_client(false).await?;
+        client.get("cache", "key").await.unwrap_err();
+
+        assert!(logs_contain("momento_request{"));
+        assert!(logs_contain("operation=\"Get\""));
+        assert!(logs_contain("cache_name=\"cache\""));
+        assert!(logs_contain("key_length=3"));
+        assert!(logs_contain("result=\"error\""));
+        assert!(logs_contain("grpc_status=Unavailable"));
+        assert!(logs_contain("error_code=ServerUnavailable"));
+        Ok(())
+    }
+
+    #[cfg(feature = "tracing")]
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn slow_requests_emit_a_warning_with_fields() -> MomentoResult<()> {
+        let configuration: Configuration = configurations::Laptop::latest().into();
+        let client = CacheClient::builder()
+            .default_ttl(Duration::from_secs(60))
+            .configuration(configuration.with_slow_request_threshold(Duration::from_millis(100)))
+            .credential_provider(CredentialProvider::from_string(V1_TOKEN)?)
+            .build()?;
+        let context = ErrorContext::new("Get", "cache".to_string());
+
+        client.warn_if_slow(Some(&context), Duration::from_millis(50));
+        assert!(!logs_contain("slow momento request"));
+
+        client.warn_if_slow(Some(&context), Duration::from_millis(250));
+        assert!(logs_contain("WARN"));
+        assert!(logs_contain("slow momento request"));
+        assert!(logs_contain("operation=\"Get\""));
+        assert!(logs_contain("cache_name=\"cache\""));
+        assert!(logs_contain("elapsed_ms=250"));
+        assert!(logs_contain("threshold_ms=100"));
+        Ok(())
+    }
+
+    #[cfg(feature = "tracing")]
+    #[tokio::test]
+    #[tracing_test::traced_test]
+    async fn debug_logging_emits_an_event_per_attempt() -> MomentoResult<()> {
+        let configuration: Configuration = configurations::Laptop::latest().into();
+        let configuration = configuration
+            .with_retry_strategy(FixedCountRetryStrategy::new(3))
+            .with_debug_logging(true);
+        let client = CacheClient::builder()
+            .default_ttl(Duration::from_secs(60))
+            .configuration(configuration)
+            .credential_provider(CredentialProvider::from_string(V1_TOKEN)?)
+            .build()?;
+        let (result, _attempts) =
+            send_flaky(&client, true, 1, || tonic::Status::unavailable("flaky")).await;
+        assert!(result.is_ok());
+
+        assert!(logs_contain("momento request attempt"));
+        assert!(logs_contain("cache_name=\"none\""));
+        assert!(logs_contain("request_bytes=0"));
+        assert!(logs_contain("attempt=1"));
+        assert!(logs_contain("status=Unavailable"));
+        assert!(logs_contain("attempt=2"));
+        assert!(logs_contain("status=Ok"));
+        Ok(())
     }
 }
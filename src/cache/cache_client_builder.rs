@@ -1,10 +1,15 @@
-use crate::cache::Configuration;
+use crate::cache::request_limiter::RequestLimiter;
+use crate::cache::{
+    Configuration, HitRateTracker, MaxConcurrentRequestsBehavior, Middleware, SortedSetOrder,
+};
 use crate::grpc::header_interceptor::HeaderInterceptor;
 use crate::{utils, CacheClient, CredentialProvider, MomentoResult};
+use std::sync::Arc;
 use std::time::Duration;
 use tonic::codegen::InterceptedService;
 
 use crate::config::grpc_configuration::GrpcConfiguration;
+use crate::config::retry_strategy::RetryStrategy;
 use crate::config::transport_strategy::TransportStrategy;
 use crate::utils::ChannelConnectError;
 use momento_protos::cache_client::scs_client::ScsClient;
@@ -28,6 +33,13 @@ pub struct ReadyToBuild {
     default_ttl: Duration,
     configuration: Configuration,
     credential_provider: CredentialProvider,
+    max_concurrent_requests: Option<(usize, MaxConcurrentRequestsBehavior)>,
+    user_agent: Option<String>,
+    default_sorted_set_order: SortedSetOrder,
+    hit_rate_tracker: Option<HitRateTracker>,
+    connection_pinning: bool,
+    middlewares: Vec<Arc<dyn Middleware>>,
+    treat_errors_as_miss: bool,
 }
 
 impl CacheClientBuilder<NeedsDefaultTtl> {
@@ -57,6 +69,13 @@ impl CacheClientBuilder<NeedsCredentialProvider> {
             default_ttl: self.0.default_ttl,
             configuration: self.0.configuration,
             credential_provider,
+            user_agent: Some(utils::user_agent("cache")),
+            max_concurrent_requests: None,
+            default_sorted_set_order: SortedSetOrder::Ascending,
+            hit_rate_tracker: None,
+            connection_pinning: false,
+            middlewares: vec![],
+            treat_errors_as_miss: false,
         })
     }
 }
@@ -72,13 +91,233 @@ impl CacheClientBuilder<ReadyToBuild> {
         };
 
         CacheClientBuilder(ReadyToBuild {
-            configuration: Configuration { transport_strategy },
+            configuration: Configuration {
+                transport_strategy,
+                ..self.0.configuration
+            },
+            ..self.0
+        })
+    }
+
+    /// Sets how long each attempt at a request may take before it fails with a timeout,
+    /// replacing the [deadline](crate::config::grpc_configuration::GrpcConfigurationBuilder::deadline)
+    /// in the configuration. Independent of [with_connect_timeout](Self::with_connect_timeout).
+    pub fn with_operation_timeout(
+        self,
+        operation_timeout: Duration,
+    ) -> CacheClientBuilder<ReadyToBuild> {
+        let grpc_configuration = self.0.configuration.transport_strategy.grpc_configuration;
+        let transport_strategy = TransportStrategy {
+            grpc_configuration: GrpcConfiguration {
+                deadline: operation_timeout,
+                ..grpc_configuration
+            },
+        };
+
+        CacheClientBuilder(ReadyToBuild {
+            configuration: Configuration {
+                transport_strategy,
+                ..self.0.configuration
+            },
+            ..self.0
+        })
+    }
+
+    /// Sets how long the client waits for a connection to be established, replacing any
+    /// [connect timeout](crate::config::grpc_configuration::GrpcConfigurationBuilder::connect_timeout)
+    /// in the configuration. Use a connect timeout longer than the operation timeout to let a
+    /// cold start finish connecting while requests keep a short timeout.
+    pub fn with_connect_timeout(
+        self,
+        connect_timeout: Duration,
+    ) -> CacheClientBuilder<ReadyToBuild> {
+        let grpc_configuration = self.0.configuration.transport_strategy.grpc_configuration;
+        let transport_strategy = TransportStrategy {
+            grpc_configuration: GrpcConfiguration {
+                connect_timeout: Some(connect_timeout),
+                ..grpc_configuration
+            },
+        };
+
+        CacheClientBuilder(ReadyToBuild {
+            configuration: Configuration {
+                transport_strategy,
+                ..self.0.configuration
+            },
+            ..self.0
+        })
+    }
+
+    /// Retries failed requests as the given [RetryStrategy] decides, replacing any retry
+    /// strategy in the configuration.
+    pub fn retry_strategy(
+        self,
+        retry_strategy: impl RetryStrategy + 'static,
+    ) -> CacheClientBuilder<ReadyToBuild> {
+        CacheClientBuilder(ReadyToBuild {
+            configuration: self.0.configuration.with_retry_strategy(retry_strategy),
+            ..self.0
+        })
+    }
+
+    /// Allows the retry strategy to retry requests that could be applied twice, such as
+    /// increments, accepting at-least-once semantics for them. Defaults to `false`. See
+    /// [Configuration::with_retry_non_idempotent_requests].
+    pub fn retry_non_idempotent_requests(self, retry: bool) -> CacheClientBuilder<ReadyToBuild> {
+        CacheClientBuilder(ReadyToBuild {
+            configuration: self
+                .0
+                .configuration
+                .with_retry_non_idempotent_requests(retry),
             ..self.0
         })
     }
 
+    pub fn with_max_concurrent_requests(
+        self,
+        max_concurrent_requests: usize,
+        behavior: MaxConcurrentRequestsBehavior,
+    ) -> CacheClientBuilder<ReadyToBuild> {
+        CacheClientBuilder(ReadyToBuild {
+            max_concurrent_requests: Some((max_concurrent_requests, behavior)),
+            ..self.0
+        })
+    }
+
+    /// Overrides the value of the `agent` header the client sends to identify the SDK.
+    pub fn with_user_agent(
+        self,
+        user_agent: impl Into<String>,
+    ) -> CacheClientBuilder<ReadyToBuild> {
+        CacheClientBuilder(ReadyToBuild {
+            user_agent: Some(user_agent.into()),
+            ..self.0
+        })
+    }
+
+    /// Stops the client from sending the `agent` and `runtime-version` headers that identify
+    /// the SDK and its version.
+    pub fn without_user_agent(self) -> CacheClientBuilder<ReadyToBuild> {
+        CacheClientBuilder(ReadyToBuild {
+            user_agent: None,
+            ..self.0
+        })
+    }
+
+    /// Sets the order used by sorted set fetches that do not specify one. Defaults to
+    /// [SortedSetOrder::Ascending].
+    pub fn with_default_sorted_set_order(
+        self,
+        order: SortedSetOrder,
+    ) -> CacheClientBuilder<ReadyToBuild> {
+        CacheClientBuilder(ReadyToBuild {
+            default_sorted_set_order: order,
+            ..self.0
+        })
+    }
+
+    /// Records the hits and misses of the client's reads in the given [HitRateTracker]. Keep a
+    /// clone of the tracker to read the hit rate per cache.
+    pub fn with_hit_rate_tracker(
+        self,
+        hit_rate_tracker: HitRateTracker,
+    ) -> CacheClientBuilder<ReadyToBuild> {
+        CacheClientBuilder(ReadyToBuild {
+            hit_rate_tracker: Some(hit_rate_tracker),
+            ..self.0
+        })
+    }
+
+    /// Sends every request from the client over a single connection instead of rotating through
+    /// all of them. Defaults to `false`.
+    ///
+    /// Pinning gives a client request affinity, so requests made one after another, for example
+    /// to the same key, travel over the same connection in the order they were made. The cost is
+    /// throughput: a single connection caps the number of concurrent requests it can carry, so the
+    /// extra connections from [with_num_connections](Self::with_num_connections) go unused. To
+    /// pin only some traffic, build a separate pinned client for it.
+    pub fn connection_pinning(self, connection_pinning: bool) -> CacheClientBuilder<ReadyToBuild> {
+        CacheClientBuilder(ReadyToBuild {
+            connection_pinning,
+            ..self.0
+        })
+    }
+
+    /// Adds a [Middleware] that runs around every request sent by the client.
+    ///
+    /// Middleware runs in the order it was added: the `on_request` hook of the first middleware
+    /// added runs first, and its `on_response` hook runs last.
+    pub fn add_middleware(
+        mut self,
+        middleware: impl Middleware + 'static,
+    ) -> CacheClientBuilder<ReadyToBuild> {
+        self.0.middlewares.push(Arc::new(middleware));
+        self
+    }
+
+    /// Makes reads return a miss instead of an error when Momento is unavailable or a request
+    /// times out. Defaults to `false`.
+    ///
+    /// This suits a best-effort cache in front of an origin: a read that cannot reach Momento
+    /// falls back to the origin as if the item were not cached. The miss is returned once the
+    /// client's [retry strategy](crate::cache::Configuration::with_retry_strategy) has given up.
+    /// Only reads that can miss, such as gets and collection fetches, are affected. Writes and
+    /// all other errors, such as a missing cache, still surface.
+    pub fn treat_errors_as_miss(
+        self,
+        treat_errors_as_miss: bool,
+    ) -> CacheClientBuilder<ReadyToBuild> {
+        CacheClientBuilder(ReadyToBuild {
+            treat_errors_as_miss,
+            ..self.0
+        })
+    }
+
+    /// Builds a [blocking CacheClient](crate::blocking::CacheClient) that runs its requests on
+    /// a current-thread runtime of its own.
+    ///
+    /// Must not be called from within an async context.
+    #[cfg(feature = "blocking")]
+    pub fn build_blocking(self) -> MomentoResult<crate::blocking::CacheClient> {
+        self.build_blocking_on(None)
+    }
+
+    /// Builds a [blocking CacheClient](crate::blocking::CacheClient) that runs its requests on
+    /// an existing runtime, so several clients can share one.
+    ///
+    /// The runtime should be multi-threaded: a current-thread runtime only makes progress while
+    /// its own `block_on` is running, so requests from the blocking client would stall.
+    #[cfg(feature = "blocking")]
+    pub fn build_blocking_with_runtime(
+        self,
+        handle: tokio::runtime::Handle,
+    ) -> MomentoResult<crate::blocking::CacheClient> {
+        self.build_blocking_on(Some(handle))
+    }
+
+    #[cfg(feature = "blocking")]
+    fn build_blocking_on(
+        self,
+        handle: Option<tokio::runtime::Handle>,
+    ) -> MomentoResult<crate::blocking::CacheClient> {
+        let runtime = crate::blocking::BlockingRuntime::new(handle)?;
+        // The client's connections are created lazily on whichever runtime is current
+        let client = {
+            let _guard = runtime.handle().enter();
+            self.build()?
+        };
+        Ok(crate::blocking::CacheClient::new(client, runtime))
+    }
+
     pub fn build(self) -> MomentoResult<CacheClient> {
-        let agent_value = &utils::user_agent("cache");
+        let agent_value = self.0.user_agent.as_deref();
+
+        let request_limiter = match self.0.max_concurrent_requests {
+            Some((max_concurrent_requests, behavior)) => {
+                Some(RequestLimiter::new(max_concurrent_requests, behavior)?)
+            }
+            None => None,
+        };
 
         let data_channels_result: Result<Vec<Channel>, ChannelConnectError> = (0..self
             .0
@@ -132,6 +371,12 @@ impl CacheClientBuilder<ReadyToBuild> {
             control_client,
             self.0.configuration,
             self.0.default_ttl,
+            request_limiter,
+            self.0.default_sorted_set_order,
+            self.0.hit_rate_tracker,
+            self.0.connection_pinning,
+            self.0.middlewares,
+            self.0.treat_errors_as_miss,
         ))
     }
 }
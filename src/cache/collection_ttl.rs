@@ -11,6 +11,10 @@ use std::time::Duration;
 ///
 /// The default behaviour is to refresh the TTL (to prolong the life of the collection)
 /// each time it is written using the client's default item TTL.
+///
+/// Collection write responses do not report the TTL that was applied. To check it, read the
+/// collection's remaining TTL with [CacheClient::item_get_ttl](crate::CacheClient::item_get_ttl),
+/// which accepts a collection name as the key.
 #[derive(Copy, Clone, Debug)]
 pub struct CollectionTtl {
     ttl: Option<Duration>,
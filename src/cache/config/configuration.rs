@@ -1,5 +1,7 @@
+use std::sync::Arc;
 use std::time::Duration;
 
+use crate::config::retry_strategy::RetryStrategy;
 use crate::config::transport_strategy::TransportStrategy;
 
 /// Configuration for a Momento cache client.
@@ -34,6 +36,14 @@ use crate::config::transport_strategy::TransportStrategy;
 pub struct Configuration {
     /// Low-level options for network interactions with Momento.
     pub(crate) transport_strategy: TransportStrategy,
+    /// Requests that take longer than this are logged as warnings.
+    pub(crate) slow_request_threshold: Option<Duration>,
+    /// Decides whether failed requests are retried. Requests are sent once if not set.
+    pub(crate) retry_strategy: Option<Arc<dyn RetryStrategy>>,
+    /// Whether requests that could be applied twice, such as increments, are retried.
+    pub(crate) retry_non_idempotent_requests: bool,
+    /// Whether every request attempt is logged at debug level with its size and timing.
+    pub(crate) debug_logging: bool,
 }
 
 impl Configuration {
@@ -46,6 +56,102 @@ impl Configuration {
     pub fn deadline_millis(&self) -> Duration {
         self.transport_strategy.grpc_configuration.deadline
     }
+
+    /// Returns the number of gRPC channels (TCP connections) the client opens for data operations.
+    pub fn num_connections(&self) -> usize {
+        self.transport_strategy.grpc_configuration.num_channels
+    }
+
+    /// Returns the low-level network settings of the configuration.
+    pub fn transport_strategy(&self) -> &TransportStrategy {
+        &self.transport_strategy
+    }
+
+    /// Returns the latency above which requests are logged as warnings, if set.
+    pub fn slow_request_threshold(&self) -> Option<Duration> {
+        self.slow_request_threshold
+    }
+
+    /// Logs a warning with the operation and cache name for every request that takes longer
+    /// than `threshold`, including requests that fail. With the `tracing` feature enabled, the
+    /// warning is a `tracing` event with `operation`, `cache_name`, `elapsed_ms` and
+    /// `threshold_ms` fields; otherwise it goes through the `log` crate. Use this to start from a
+    /// prebuilt configuration:
+    /// ```
+    /// use std::time::Duration;
+    /// use momento::cache::{configurations, Configuration};
+    ///
+    /// let config: Configuration = configurations::InRegion::latest().into();
+    /// let config = config.with_slow_request_threshold(Duration::from_millis(100));
+    /// ```
+    pub fn with_slow_request_threshold(mut self, threshold: Duration) -> Self {
+        self.slow_request_threshold = Some(threshold);
+        self
+    }
+
+    /// Returns the strategy that decides whether failed requests are retried, if set.
+    pub fn retry_strategy(&self) -> Option<&dyn RetryStrategy> {
+        self.retry_strategy.as_deref()
+    }
+
+    /// Retries failed requests as the given [RetryStrategy] decides. Without a retry strategy,
+    /// each request is sent once. Use this to start from a prebuilt configuration:
+    /// ```
+    /// use std::time::Duration;
+    /// use momento::cache::{configurations, Configuration};
+    /// use momento::config::retry_strategy::ExponentialBackoffRetryStrategy;
+    ///
+    /// let config: Configuration = configurations::InRegion::latest().into();
+    /// let config = config.with_retry_strategy(ExponentialBackoffRetryStrategy::new(
+    ///     3,
+    ///     Duration::from_millis(50),
+    ///     Duration::from_millis(500),
+    /// ));
+    /// ```
+    pub fn with_retry_strategy(mut self, retry_strategy: impl RetryStrategy + 'static) -> Self {
+        self.retry_strategy = Some(Arc::new(retry_strategy));
+        self
+    }
+
+    /// Returns whether requests that could be applied twice, such as increments, are retried.
+    pub fn retry_non_idempotent_requests(&self) -> bool {
+        self.retry_non_idempotent_requests
+    }
+
+    /// Sets whether the retry strategy also retries requests that could be applied twice, such
+    /// as increments, list pushes and list pops. Defaults to `false`.
+    ///
+    /// A request that failed may still have been applied by the server, for example when it
+    /// timed out after being sent. Retrying reads and sets is harmless, but retrying an increment
+    /// could increment twice. Enable this only if at-least-once semantics are acceptable.
+    pub fn with_retry_non_idempotent_requests(mut self, retry: bool) -> Self {
+        self.retry_non_idempotent_requests = retry;
+        self
+    }
+
+    /// Returns whether every request attempt is logged at debug level with its size and timing.
+    pub fn debug_logging(&self) -> bool {
+        self.debug_logging
+    }
+
+    /// Sets whether every request attempt is logged at debug level. Defaults to `false`.
+    ///
+    /// Each attempt, including retries, is logged with its gRPC method, cache name, serialized
+    /// request size in bytes, round-trip time and gRPC status, for example:
+    ///
+    /// ```text
+    /// request attempt: rpc=SetRequest cache_name=my-cache attempt=1 request_bytes=1048600 elapsed_ms=48 status=Ok
+    /// ```
+    ///
+    /// The round-trip time covers both the network and the server, which does not report its
+    /// own processing time. With the `tracing` feature enabled, each attempt is a debug-level
+    /// `tracing` event with `rpc`, `cache_name`, `attempt`, `request_bytes`, `elapsed_ms` and
+    /// `status` fields. Otherwise logging goes through the `log` crate. Either way, debug level
+    /// must be enabled for the `momento` target to see it.
+    pub fn with_debug_logging(mut self, debug_logging: bool) -> Self {
+        self.debug_logging = debug_logging;
+        self
+    }
 }
 
 /// The initial state of the ConfigurationBuilder.
@@ -57,6 +163,10 @@ pub struct NeedsTransportStrategy(());
 /// The state of the ConfigurationBuilder when it is ready to build a Configuration.
 pub struct ReadyToBuild {
     transport_strategy: TransportStrategy,
+    slow_request_threshold: Option<Duration>,
+    retry_strategy: Option<Arc<dyn RetryStrategy>>,
+    retry_non_idempotent_requests: bool,
+    debug_logging: bool,
 }
 
 impl ConfigurationBuilder<NeedsTransportStrategy> {
@@ -68,15 +178,50 @@ impl ConfigurationBuilder<NeedsTransportStrategy> {
     ) -> ConfigurationBuilder<ReadyToBuild> {
         ConfigurationBuilder(ReadyToBuild {
             transport_strategy: transport_strategy.into(),
+            slow_request_threshold: None,
+            retry_strategy: None,
+            retry_non_idempotent_requests: false,
+            debug_logging: false,
         })
     }
 }
 
 impl ConfigurationBuilder<ReadyToBuild> {
+    /// Logs a warning with the operation and cache name for every request that takes longer
+    /// than `threshold`, including requests that fail.
+    pub fn slow_request_threshold(mut self, threshold: Duration) -> Self {
+        self.0.slow_request_threshold = Some(threshold);
+        self
+    }
+
+    /// Retries failed requests as the given [RetryStrategy] decides.
+    pub fn retry_strategy(mut self, retry_strategy: impl RetryStrategy + 'static) -> Self {
+        self.0.retry_strategy = Some(Arc::new(retry_strategy));
+        self
+    }
+
+    /// Sets whether the retry strategy also retries requests that could be applied twice, such
+    /// as increments. Defaults to `false`. See [Configuration::with_retry_non_idempotent_requests].
+    pub fn retry_non_idempotent_requests(mut self, retry: bool) -> Self {
+        self.0.retry_non_idempotent_requests = retry;
+        self
+    }
+
+    /// Logs every request attempt at debug level with its size and timing. Defaults to `false`.
+    /// See [Configuration::with_debug_logging].
+    pub fn debug_logging(mut self, debug_logging: bool) -> Self {
+        self.0.debug_logging = debug_logging;
+        self
+    }
+
     /// Constructs the Configuration with the given transport strategy.
     pub fn build(self) -> Configuration {
         Configuration {
             transport_strategy: self.0.transport_strategy,
+            slow_request_threshold: self.0.slow_request_threshold,
+            retry_strategy: self.0.retry_strategy,
+            retry_non_idempotent_requests: self.0.retry_non_idempotent_requests,
+            debug_logging: self.0.debug_logging,
         }
     }
 }
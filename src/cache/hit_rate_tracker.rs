@@ -0,0 +1,201 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Hit and miss counts for a cache over a [HitRateTracker]'s window.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct HitRateStats {
+    /// The number of reads that were hits.
+    pub hits: u64,
+    /// The number of reads that were misses.
+    pub misses: u64,
+}
+
+impl HitRateStats {
+    /// The fraction of reads that were hits, or `None` if there were no reads.
+    pub fn hit_rate(&self) -> Option<f64> {
+        let total = self.hits + self.misses;
+        if total == 0 {
+            None
+        } else {
+            Some(self.hits as f64 / total as f64)
+        }
+    }
+}
+
+/// Counts the hits and misses of a [CacheClient](crate::CacheClient)'s reads per cache over a
+/// rolling window.
+///
+/// Pass a tracker to the client builder's `with_hit_rate_tracker` and keep a clone of it to
+/// read the stats. Clones share the same counts.
+///
+/// Reads are counted in one-second buckets, so the window is accurate to about a second. Only
+/// reads whose response is a hit or a miss are counted: `get`, `dictionary_get_field`,
+/// `dictionary_fetch`, `list_fetch`, `set_fetch` and the sorted set fetches.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use momento::cache::HitRateTracker;
+///
+/// let tracker = HitRateTracker::new(Duration::from_secs(60));
+/// // let cache_client = CacheClient::builder()
+/// //     ...
+/// //     .with_hit_rate_tracker(tracker.clone())
+/// //     .build()?;
+///
+/// let stats = tracker.stats("my-cache");
+/// if let Some(hit_rate) = stats.hit_rate() {
+///     println!("Hit rate over the last minute: {:.2}", hit_rate);
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct HitRateTracker {
+    window: Duration,
+    started_at: Instant,
+    buckets: Arc<Mutex<HashMap<String, VecDeque<Bucket>>>>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    second: u64,
+    stats: HitRateStats,
+}
+
+impl HitRateTracker {
+    /// Constructs a new HitRateTracker that reports the reads made in the last `window`.
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            started_at: Instant::now(),
+            buckets: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Returns the hits and misses for the given cache within the window.
+    pub fn stats(&self, cache_name: &str) -> HitRateStats {
+        let now = self.current_second();
+        let mut buckets = self.lock();
+        match buckets.get_mut(cache_name) {
+            Some(cache_buckets) => {
+                self.expire(cache_buckets, now);
+                sum(cache_buckets)
+            }
+            None => HitRateStats::default(),
+        }
+    }
+
+    /// Returns the hits and misses for every cache with reads within the window.
+    pub fn all_stats(&self) -> HashMap<String, HitRateStats> {
+        let now = self.current_second();
+        let mut buckets = self.lock();
+        buckets.retain(|_, cache_buckets| {
+            self.expire(cache_buckets, now);
+            !cache_buckets.is_empty()
+        });
+        buckets
+            .iter()
+            .map(|(cache_name, cache_buckets)| (cache_name.clone(), sum(cache_buckets)))
+            .collect()
+    }
+
+    pub(crate) fn record(&self, cache_name: &str, hit: bool) {
+        let now = self.current_second();
+        let mut buckets = self.lock();
+        let cache_buckets = buckets.entry(cache_name.to_string()).or_default();
+        self.expire(cache_buckets, now);
+        if cache_buckets.back().map(|bucket| bucket.second) != Some(now) {
+            cache_buckets.push_back(Bucket {
+                second: now,
+                stats: HitRateStats::default(),
+            });
+        }
+        if let Some(bucket) = cache_buckets.back_mut() {
+            if hit {
+                bucket.stats.hits += 1;
+            } else {
+                bucket.stats.misses += 1;
+            }
+        }
+    }
+
+    fn current_second(&self) -> u64 {
+        self.started_at.elapsed().as_secs()
+    }
+
+    /// Drops the buckets that have fallen out of the window.
+    fn expire(&self, cache_buckets: &mut VecDeque<Bucket>, now: u64) {
+        let window_secs = self.window.as_secs().max(1);
+        while let Some(bucket) = cache_buckets.front() {
+            if now - bucket.second < window_secs {
+                break;
+            }
+            cache_buckets.pop_front();
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, VecDeque<Bucket>>> {
+        // The counts are still usable if another thread panicked while holding the lock.
+        self.buckets
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+fn sum(cache_buckets: &VecDeque<Bucket>) -> HitRateStats {
+    cache_buckets
+        .iter()
+        .fold(HitRateStats::default(), |total, bucket| HitRateStats {
+            hits: total.hits + bucket.stats.hits,
+            misses: total.misses + bucket.stats.misses,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hit_rate_per_cache() {
+        let tracker = HitRateTracker::new(Duration::from_secs(60));
+        tracker.record("cache-a", true);
+        tracker.record("cache-a", true);
+        tracker.record("cache-a", false);
+        tracker.record("cache-b", false);
+
+        let stats = tracker.stats("cache-a");
+        assert_eq!(stats, HitRateStats { hits: 2, misses: 1 });
+        assert_eq!(stats.hit_rate(), Some(2.0 / 3.0));
+
+        assert_eq!(tracker.stats("cache-b").hit_rate(), Some(0.0));
+        assert_eq!(tracker.stats("cache-c").hit_rate(), None);
+        assert_eq!(tracker.all_stats().len(), 2);
+    }
+
+    #[test]
+    fn test_clones_share_counts() {
+        let tracker = HitRateTracker::new(Duration::from_secs(60));
+        tracker.clone().record("cache", true);
+        assert_eq!(tracker.stats("cache"), HitRateStats { hits: 1, misses: 0 });
+    }
+
+    #[test]
+    fn test_expire_drops_buckets_outside_window() {
+        let tracker = HitRateTracker::new(Duration::from_secs(10));
+        let mut cache_buckets: VecDeque<Bucket> = (0..20)
+            .map(|second| Bucket {
+                second,
+                stats: HitRateStats { hits: 1, misses: 0 },
+            })
+            .collect();
+
+        tracker.expire(&mut cache_buckets, 19);
+        assert_eq!(
+            sum(&cache_buckets),
+            HitRateStats {
+                hits: 10,
+                misses: 0
+            }
+        );
+    }
+}
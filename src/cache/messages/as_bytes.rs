@@ -0,0 +1,23 @@
+/// Borrows the raw bytes of a response that carries at most a single value.
+///
+/// This is implemented by responses such as [GetResponse](crate::cache::GetResponse),
+/// [DictionaryGetFieldResponse](crate::cache::DictionaryGetFieldResponse),
+/// [ListPopFrontResponse](crate::cache::ListPopFrontResponse) and
+/// [ListPopBackResponse](crate::cache::ListPopBackResponse), so generic code can read the value
+/// of any of them without matching on each response type.
+///
+/// # Example
+/// ```
+/// use momento::cache::{AsBytes, GetResponse};
+///
+/// fn value_len(response: &impl AsBytes) -> usize {
+///     response.as_bytes().map(|bytes| bytes.len()).unwrap_or(0)
+/// }
+///
+/// assert_eq!(value_len(&GetResponse::from("hello")), 5);
+/// assert_eq!(value_len(&GetResponse::Miss), 0);
+/// ```
+pub trait AsBytes {
+    /// Returns the bytes of the value on a hit, or `None` on a miss.
+    fn as_bytes(&self) -> Option<&[u8]>;
+}
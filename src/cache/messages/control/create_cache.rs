@@ -3,6 +3,7 @@ use tonic::Request;
 
 use crate::cache::messages::MomentoRequest;
 use crate::status_to_error;
+use crate::ErrorContext;
 use crate::{utils, CacheClient, MomentoResult};
 
 /// Request to create a cache.
@@ -47,6 +48,10 @@ impl CreateCacheRequest {
 impl MomentoRequest for CreateCacheRequest {
     type Response = CreateCacheResponse;
 
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new("CreateCache", self.cache_name.clone()))
+    }
+
     async fn send(self, cache_client: &CacheClient) -> MomentoResult<CreateCacheResponse> {
         utils::is_cache_name_valid(&self.cache_name)?;
         let request = Request::new(control_client::CreateCacheRequest {
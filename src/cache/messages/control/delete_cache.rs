@@ -2,6 +2,7 @@ use momento_protos::control_client;
 use tonic::Request;
 
 use crate::cache::messages::MomentoRequest;
+use crate::ErrorContext;
 use crate::{utils, CacheClient, MomentoResult};
 
 /// Request to delete a cache
@@ -51,6 +52,10 @@ impl DeleteCacheRequest {
 impl MomentoRequest for DeleteCacheRequest {
     type Response = DeleteCacheResponse;
 
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new("DeleteCache", self.cache_name.clone()))
+    }
+
     async fn send(self, cache_client: &CacheClient) -> MomentoResult<DeleteCacheResponse> {
         let cache_name = &self.cache_name;
 
@@ -2,6 +2,7 @@ use momento_protos::control_client;
 use tonic::Request;
 
 use crate::cache::messages::MomentoRequest;
+use crate::ErrorContext;
 use crate::{utils, CacheClient, MomentoResult};
 
 /// Request to flush a cache of its data
@@ -53,6 +54,10 @@ impl FlushCacheRequest {
 impl MomentoRequest for FlushCacheRequest {
     type Response = FlushCacheResponse;
 
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new("FlushCache", self.cache_name.clone()))
+    }
+
     async fn send(self, cache_client: &CacheClient) -> MomentoResult<FlushCacheResponse> {
         let cache_name = &self.cache_name;
 
@@ -2,6 +2,7 @@ use momento_protos::control_client;
 use tonic::Request;
 
 use crate::cache::messages::MomentoRequest;
+use crate::ErrorContext;
 use crate::{CacheClient, MomentoResult};
 
 /// Request to list all caches in your account.
@@ -30,9 +31,38 @@ pub struct ListCachesRequest {}
 impl MomentoRequest for ListCachesRequest {
     type Response = ListCachesResponse;
 
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new("ListCaches", None))
+    }
+
     async fn send(self, cache_client: &CacheClient) -> MomentoResult<ListCachesResponse> {
+        let (response, _) = ListCachesPageRequest::new(None).send(cache_client).await?;
+        Ok(response)
+    }
+}
+
+/// Request for a single page of caches, used to list caches lazily.
+pub(crate) struct ListCachesPageRequest {
+    next_token: Option<String>,
+}
+
+impl ListCachesPageRequest {
+    pub(crate) fn new(next_token: Option<String>) -> Self {
+        Self { next_token }
+    }
+}
+
+impl MomentoRequest for ListCachesPageRequest {
+    /// The caches in this page, along with the token for the next page if there is one.
+    type Response = (ListCachesResponse, Option<String>);
+
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new("ListCaches", None))
+    }
+
+    async fn send(self, cache_client: &CacheClient) -> MomentoResult<Self::Response> {
         let request = Request::new(control_client::ListCachesRequest {
-            next_token: "".to_string(),
+            next_token: self.next_token.unwrap_or_default(),
         });
 
         let response = cache_client
@@ -41,7 +71,8 @@ impl MomentoRequest for ListCachesRequest {
             .await?
             .into_inner();
 
-        Ok(ListCachesResponse::from_response(response))
+        let next_token = Some(response.next_token.clone()).filter(|token| !token.is_empty());
+        Ok((ListCachesResponse::from_response(response), next_token))
     }
 }
 
@@ -0,0 +1,131 @@
+use std::time::Duration;
+
+use crate::cache::messages::data::dictionary::dictionary_fetch::{
+    DictionaryFetchRequest, DictionaryFetchResponse,
+};
+use crate::cache::messages::data::dictionary::dictionary_set_fields::DictionarySetFieldsRequest;
+use crate::cache::messages::data::scalar::delete::DeleteRequest;
+use crate::cache::{CollectionTtl, MomentoRequest};
+use crate::utils::is_cache_name_valid;
+use crate::{CacheClient, ErrorContext, IntoBytes, MomentoResult};
+
+/// Copy a dictionary to a new name, replacing anything stored under the destination name.
+///
+/// The service has no copy operation, so the dictionary is fetched and then written to the
+/// destination, passing through the client. The copy is not atomic: changes made to the source
+/// while it is being copied may or may not be included, and the destination is deleted before it
+/// is written, so readers can briefly find it missing. If the write fails after the delete, the
+/// destination is left missing.
+///
+/// # Arguments
+///
+/// * `cache_name` - The name of the cache containing the dictionary.
+/// * `source_name` - The name of the dictionary to copy.
+/// * `destination_name` - The name to copy the dictionary to.
+///
+/// # Optional Arguments
+///
+/// * `ttl` - The time-to-live of the copy. If not provided, the client's default time-to-live is
+///   used. The source's remaining time-to-live is not copied.
+///
+/// # Examples
+/// Assumes that a CacheClient named `cache_client` has been created and is available.
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// # use momento_test_util::create_doctest_cache_client;
+/// # tokio_test::block_on(async {
+/// use std::time::Duration;
+/// use momento::cache::{DictionaryCopyRequest, DictionaryCopyResponse};
+/// # let (cache_client, cache_name) = create_doctest_cache_client();
+/// # cache_client.dictionary_set_fields(&cache_name, "config", vec![("region", "us-west-2")]).await?;
+///
+/// let request = DictionaryCopyRequest::new(&cache_name, "config", "config-v1")
+///     .ttl(Duration::from_secs(3600));
+///
+/// match cache_client.send_request(request).await? {
+///     DictionaryCopyResponse::Copied { count } => println!("Copied {} fields", count),
+///     DictionaryCopyResponse::Miss => println!("Nothing to copy"),
+/// };
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+pub struct DictionaryCopyRequest<S: IntoBytes, D: IntoBytes> {
+    cache_name: String,
+    source_name: S,
+    destination_name: D,
+    ttl: Option<Duration>,
+}
+
+impl<S: IntoBytes, D: IntoBytes> DictionaryCopyRequest<S, D> {
+    /// Constructs a new DictionaryCopyRequest.
+    pub fn new(cache_name: impl Into<String>, source_name: S, destination_name: D) -> Self {
+        Self {
+            cache_name: cache_name.into(),
+            source_name,
+            destination_name,
+            ttl: None,
+        }
+    }
+
+    /// Set the time-to-live of the copy.
+    pub fn ttl(mut self, ttl: impl Into<Option<Duration>>) -> Self {
+        self.ttl = ttl.into();
+        self
+    }
+}
+
+impl<S: IntoBytes, D: IntoBytes> MomentoRequest for DictionaryCopyRequest<S, D> {
+    type Response = DictionaryCopyResponse;
+
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new("DictionaryCopy", self.cache_name.clone()))
+    }
+
+    fn is_composite(&self) -> bool {
+        true
+    }
+
+    async fn send(self, cache_client: &CacheClient) -> MomentoResult<DictionaryCopyResponse> {
+        is_cache_name_valid(&self.cache_name)?;
+
+        let fields: Vec<(Vec<u8>, Vec<u8>)> = match cache_client
+            .send_sub_request(DictionaryFetchRequest::new(
+                &self.cache_name,
+                self.source_name,
+            ))
+            .await?
+        {
+            DictionaryFetchResponse::Hit { value } => value.raw_item.into_iter().collect(),
+            DictionaryFetchResponse::Miss => return Ok(DictionaryCopyResponse::Miss),
+        };
+        let count = fields.len();
+
+        let destination_name = self.destination_name.into_bytes();
+        cache_client
+            .send_sub_request(DeleteRequest::new(
+                &self.cache_name,
+                destination_name.clone(),
+            ))
+            .await?;
+        cache_client
+            .send_sub_request(
+                DictionarySetFieldsRequest::new(&self.cache_name, destination_name, fields)
+                    .ttl(CollectionTtl::refresh_on_update(self.ttl)),
+            )
+            .await?;
+        Ok(DictionaryCopyResponse::Copied { count })
+    }
+}
+
+/// Response for a dictionary copy operation.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum DictionaryCopyResponse {
+    /// The dictionary was copied.
+    Copied {
+        /// The number of fields copied.
+        count: usize,
+    },
+    /// The source dictionary was not found. The destination was left unchanged.
+    Miss,
+}
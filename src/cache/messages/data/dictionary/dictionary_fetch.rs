@@ -1,6 +1,7 @@
 use crate::cache::messages::MomentoRequest;
 use crate::utils::fmt::{AsDebuggableValue, DebuggableValue};
 use crate::utils::{parse_string, prep_request_with_timeout};
+use crate::ErrorContext;
 use crate::{CacheClient, IntoBytes, MomentoError, MomentoResult};
 use derive_more::Display;
 use momento_protos::cache_client::{
@@ -66,19 +67,40 @@ impl<D: IntoBytes> DictionaryFetchRequest<D> {
 impl<D: IntoBytes> MomentoRequest for DictionaryFetchRequest<D> {
     type Response = DictionaryFetchResponse;
 
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new(
+            "DictionaryFetch",
+            self.cache_name.clone(),
+        ))
+    }
+
+    fn is_hit(response: &DictionaryFetchResponse) -> Option<bool> {
+        Some(matches!(response, DictionaryFetchResponse::Hit { .. }))
+    }
+
+    fn miss() -> Option<DictionaryFetchResponse> {
+        Some(DictionaryFetchResponse::Miss)
+    }
+
     async fn send(self, cache_client: &CacheClient) -> MomentoResult<Self::Response> {
+        let error_context = self.error_context();
+        let dictionary_name = self.dictionary_name.into_bytes();
+        let error_context =
+            error_context.map(|context| context.with_collection_name(&dictionary_name));
         let request = prep_request_with_timeout(
             &self.cache_name,
             cache_client.deadline_millis(),
-            DictionaryFetchRequestProto {
-                dictionary_name: self.dictionary_name.into_bytes(),
-            },
+            DictionaryFetchRequestProto { dictionary_name },
         )?;
 
         let response = cache_client
-            .next_data_client()
-            .dictionary_fetch(request)
-            .await?
+            .send_with_retries(
+                request,
+                Self::is_idempotent(),
+                |mut client, request| async move { client.dictionary_fetch(request).await },
+            )
+            .await
+            .map_err(|e| e.with_context(error_context))?
             .into_inner();
 
         match response.dictionary {
@@ -1,5 +1,6 @@
-use crate::cache::messages::MomentoRequest;
+use crate::cache::messages::{AsBytes, MomentoRequest};
 use crate::utils::{parse_string, prep_request_with_timeout};
+use crate::ErrorContext;
 use crate::{CacheClient, IntoBytes, MomentoError, MomentoResult};
 use momento_protos::cache_client::dictionary_get_response::DictionaryGetResponsePart;
 use momento_protos::cache_client::{
@@ -71,20 +72,43 @@ impl<D: IntoBytes, F: IntoBytes> DictionaryGetFieldRequest<D, F> {
 impl<D: IntoBytes, F: IntoBytes> MomentoRequest for DictionaryGetFieldRequest<D, F> {
     type Response = DictionaryGetFieldResponse;
 
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new(
+            "DictionaryGetField",
+            self.cache_name.clone(),
+        ))
+    }
+
+    fn is_hit(response: &DictionaryGetFieldResponse) -> Option<bool> {
+        Some(matches!(response, DictionaryGetFieldResponse::Hit { .. }))
+    }
+
+    fn miss() -> Option<DictionaryGetFieldResponse> {
+        Some(DictionaryGetFieldResponse::Miss)
+    }
+
     async fn send(self, cache_client: &CacheClient) -> MomentoResult<Self::Response> {
+        let error_context = self.error_context();
+        let dictionary_name = self.dictionary_name.into_bytes();
+        let error_context =
+            error_context.map(|context| context.with_collection_name(&dictionary_name));
         let request = prep_request_with_timeout(
             &self.cache_name,
             cache_client.deadline_millis(),
             DictionaryGetRequestProto {
-                dictionary_name: self.dictionary_name.into_bytes(),
+                dictionary_name,
                 fields: vec![self.field.into_bytes()],
             },
         )?;
 
         let response = cache_client
-            .next_data_client()
-            .dictionary_get(request)
-            .await?
+            .send_with_retries(
+                request,
+                Self::is_idempotent(),
+                |mut client, request| async move { client.dictionary_get(request).await },
+            )
+            .await
+            .map_err(|e| e.with_context(error_context))?
             .into_inner();
 
         match response.dictionary {
@@ -215,6 +239,15 @@ impl Value {
     }
 }
 
+impl AsBytes for DictionaryGetFieldResponse {
+    fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            DictionaryGetFieldResponse::Hit { value } => Some(&value.raw_item),
+            DictionaryGetFieldResponse::Miss => None,
+        }
+    }
+}
+
 impl TryFrom<Value> for String {
     type Error = MomentoError;
 
@@ -0,0 +1,173 @@
+use std::convert::TryFrom;
+
+use futures::TryStreamExt;
+
+use crate::cache::messages::data::dictionary::dictionary_get_field::{
+    DictionaryGetFieldRequest, DictionaryGetFieldResponse,
+};
+use crate::cache::messages::data::DEFAULT_BATCH_CONCURRENCY;
+use crate::cache::messages::MomentoRequest;
+use crate::utils::{bounded_fan_out, is_cache_name_valid, parse_string};
+use crate::{CacheClient, ErrorContext, IntoBytes, MomentoError, MomentoResult};
+
+/// Request to get one field from each of several dictionaries.
+///
+/// The fields are fetched concurrently, one request per (dictionary, field) pair, with at most
+/// `max_concurrent_requests` of them in flight at once. The batch counts as a single request
+/// against the client's concurrency limit and is seen once by its middleware.
+///
+/// # Arguments
+///
+/// * `cache_name` - The name of the cache containing the dictionaries.
+/// * `items` - The (dictionary name, field) pairs to get.
+///
+/// # Optional Arguments
+///
+/// * `max_concurrent_requests` - The maximum number of requests in flight at once. Defaults to
+///   [DEFAULT_BATCH_CONCURRENCY](crate::cache::DEFAULT_BATCH_CONCURRENCY).
+///
+/// # Examples
+/// Assumes that a CacheClient named `cache_client` has been created and is available.
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// # use momento_test_util::create_doctest_cache_client;
+/// # tokio_test::block_on(async {
+/// use std::convert::TryInto;
+/// use momento::cache::DictionaryGetFieldBatchRequest;
+/// # let (cache_client, cache_name) = create_doctest_cache_client();
+/// # cache_client.dictionary_set_field(&cache_name, "user_1", "name", "alice").await?;
+///
+/// let get_field_batch_request = DictionaryGetFieldBatchRequest::new(
+///     &cache_name,
+///     vec![("user_1", "name"), ("user_2", "name")]
+/// ).max_concurrent_requests(2);
+///
+/// let names: Vec<Option<String>> = cache_client
+///     .send_request(get_field_batch_request)
+///     .await?
+///     .try_into()?;
+/// # assert_eq!(names, vec![Some("alice".to_string()), None]);
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+pub struct DictionaryGetFieldBatchRequest<D: IntoBytes, F: IntoBytes> {
+    cache_name: String,
+    items: Vec<(D, F)>,
+    max_concurrent_requests: usize,
+}
+
+impl<D: IntoBytes, F: IntoBytes> DictionaryGetFieldBatchRequest<D, F> {
+    /// Constructs a new DictionaryGetFieldBatchRequest.
+    pub fn new(cache_name: impl Into<String>, items: impl IntoIterator<Item = (D, F)>) -> Self {
+        Self {
+            cache_name: cache_name.into(),
+            items: items.into_iter().collect(),
+            max_concurrent_requests: DEFAULT_BATCH_CONCURRENCY,
+        }
+    }
+
+    /// Set the maximum number of requests in flight at once. Values below 1 are treated as 1.
+    pub fn max_concurrent_requests(mut self, max_concurrent_requests: usize) -> Self {
+        self.max_concurrent_requests = max_concurrent_requests.max(1);
+        self
+    }
+}
+
+impl<D: IntoBytes, F: IntoBytes> MomentoRequest for DictionaryGetFieldBatchRequest<D, F> {
+    type Response = DictionaryGetFieldBatchResponse;
+
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new(
+            "DictionaryGetFieldBatch",
+            self.cache_name.clone(),
+        ))
+    }
+
+    fn is_composite(&self) -> bool {
+        true
+    }
+
+    async fn send(self, cache_client: &CacheClient) -> MomentoResult<Self::Response> {
+        is_cache_name_valid(&self.cache_name)?;
+
+        let cache_name = &self.cache_name;
+        let gets = self
+            .items
+            .into_iter()
+            .enumerate()
+            .map(|(index, (dictionary_name, field))| {
+                let request = DictionaryGetFieldRequest::new(cache_name, dictionary_name, field);
+                async move {
+                    cache_client
+                        .send_sub_request(request)
+                        .await
+                        .map(|response| (index, response))
+                }
+            });
+        let mut results = bounded_fan_out(gets, self.max_concurrent_requests);
+
+        // Results arrive in the order they complete; put them back in the order requested
+        let mut indexed_responses = Vec::new();
+        while let Some(indexed_response) = results.try_next().await? {
+            indexed_responses.push(indexed_response);
+        }
+        indexed_responses.sort_unstable_by_key(|(index, _)| *index);
+        let responses = indexed_responses
+            .into_iter()
+            .map(|(_, response)| response)
+            .collect();
+
+        Ok(DictionaryGetFieldBatchResponse { responses })
+    }
+}
+
+/// Response for a [DictionaryGetFieldBatchRequest].
+///
+/// Holds one [DictionaryGetFieldResponse] per requested (dictionary, field) pair, in the order
+/// they were requested. A pair is a miss if either its dictionary or its field does not exist.
+///
+/// You can use `into()` to convert it into a `Vec<DictionaryGetFieldResponse>` or a
+/// `Vec<Option<Vec<u8>>>`, and `try_into()` to convert it into a `Vec<Option<String>>`. In the
+/// `Option` conversions, misses are `None`.
+/// ```
+/// # use momento::cache::DictionaryGetFieldBatchResponse;
+/// # use momento::MomentoResult;
+/// # let response = DictionaryGetFieldBatchResponse::default();
+/// use std::convert::TryInto;
+/// let values: MomentoResult<Vec<Option<String>>> = response.try_into();
+/// ```
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct DictionaryGetFieldBatchResponse {
+    responses: Vec<DictionaryGetFieldResponse>,
+}
+
+impl From<DictionaryGetFieldBatchResponse> for Vec<DictionaryGetFieldResponse> {
+    fn from(response: DictionaryGetFieldBatchResponse) -> Self {
+        response.responses
+    }
+}
+
+impl From<DictionaryGetFieldBatchResponse> for Vec<Option<Vec<u8>>> {
+    fn from(response: DictionaryGetFieldBatchResponse) -> Self {
+        response
+            .responses
+            .into_iter()
+            .map(|response| match response {
+                DictionaryGetFieldResponse::Hit { value } => Some(value.into()),
+                DictionaryGetFieldResponse::Miss => None,
+            })
+            .collect()
+    }
+}
+
+impl TryFrom<DictionaryGetFieldBatchResponse> for Vec<Option<String>> {
+    type Error = MomentoError;
+
+    fn try_from(response: DictionaryGetFieldBatchResponse) -> Result<Self, Self::Error> {
+        Vec::<Option<Vec<u8>>>::from(response)
+            .into_iter()
+            .map(|value| value.map(parse_string).transpose())
+            .collect()
+    }
+}
@@ -2,7 +2,8 @@ use super::dictionary_get_field::{DictionaryGetFieldResponse, Value};
 use crate::cache::messages::MomentoRequest;
 use crate::utils::{parse_string, prep_request_with_timeout};
 use crate::{
-    CacheClient, IntoBytes, IntoBytesIterable, MomentoError, MomentoErrorCode, MomentoResult,
+    CacheClient, ErrorContext, IntoBytes, IntoBytesIterable, MomentoError, MomentoErrorCode,
+    MomentoResult,
 };
 use momento_protos::cache_client::{
     dictionary_get_response::Dictionary as DictionaryProto,
@@ -76,20 +77,35 @@ impl<D: IntoBytes, F: IntoBytesIterable + Clone> MomentoRequest
 {
     type Response = DictionaryGetFieldsResponse<F>;
 
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new(
+            "DictionaryGetFields",
+            self.cache_name.clone(),
+        ))
+    }
+
     async fn send(self, cache_client: &CacheClient) -> MomentoResult<Self::Response> {
+        let error_context = self.error_context();
+        let dictionary_name = self.dictionary_name.into_bytes();
+        let error_context =
+            error_context.map(|context| context.with_collection_name(&dictionary_name));
         let request = prep_request_with_timeout(
             &self.cache_name,
             cache_client.deadline_millis(),
             DictionaryGetRequestProto {
-                dictionary_name: self.dictionary_name.into_bytes(),
+                dictionary_name,
                 fields: self.fields.clone().into_bytes(),
             },
         )?;
 
         let response = cache_client
-            .next_data_client()
-            .dictionary_get(request)
-            .await?
+            .send_with_retries(
+                request,
+                Self::is_idempotent(),
+                |mut client, request| async move { client.dictionary_get(request).await },
+            )
+            .await
+            .map_err(|e| e.with_context(error_context))?
             .into_inner();
 
         match response.dictionary {
@@ -256,6 +272,7 @@ impl<F: IntoBytesIterable> TryFrom<DictionaryGetFieldsResponse<F>> for HashMap<V
                 error_code: MomentoErrorCode::Miss,
                 inner_error: None,
                 details: None,
+                context: None,
             }),
         }
     }
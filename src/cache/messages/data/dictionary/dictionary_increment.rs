@@ -1,7 +1,7 @@
 use crate::{
     cache::{CollectionTtl, MomentoRequest},
     utils::prep_request_with_timeout,
-    CacheClient, IntoBytes, MomentoResult,
+    CacheClient, ErrorContext, IntoBytes, MomentoResult,
 };
 
 /// Adds an integer quantity to a field value.
@@ -72,18 +72,35 @@ impl<D: IntoBytes, F: IntoBytes> DictionaryIncrementRequest<D, F> {
         self.collection_ttl = collection_ttl.into();
         self
     }
+
+    collection_ttl_shortcuts!("dictionary");
 }
 
 impl<D: IntoBytes, F: IntoBytes> MomentoRequest for DictionaryIncrementRequest<D, F> {
     type Response = DictionaryIncrementResponse;
 
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new(
+            "DictionaryIncrement",
+            self.cache_name.clone(),
+        ))
+    }
+
+    fn is_idempotent() -> bool {
+        false
+    }
+
     async fn send(self, cache_client: &CacheClient) -> MomentoResult<DictionaryIncrementResponse> {
+        let error_context = self.error_context();
+        let dictionary_name = self.dictionary_name.into_bytes();
+        let error_context =
+            error_context.map(|context| context.with_collection_name(&dictionary_name));
         let collection_ttl = self.collection_ttl.unwrap_or_default();
         let request = prep_request_with_timeout(
             &self.cache_name,
             cache_client.deadline_millis(),
             momento_protos::cache_client::DictionaryIncrementRequest {
-                dictionary_name: self.dictionary_name.into_bytes(),
+                dictionary_name,
                 field: self.field.into_bytes(),
                 amount: self.amount,
                 ttl_milliseconds: cache_client.expand_ttl_ms(collection_ttl.ttl())?,
@@ -92,9 +109,13 @@ impl<D: IntoBytes, F: IntoBytes> MomentoRequest for DictionaryIncrementRequest<D
         )?;
 
         let response = cache_client
-            .next_data_client()
-            .dictionary_increment(request)
-            .await?
+            .send_with_retries(
+                request,
+                Self::is_idempotent(),
+                |mut client, request| async move { client.dictionary_increment(request).await },
+            )
+            .await
+            .map_err(|e| e.with_context(error_context))?
             .into_inner();
         Ok(DictionaryIncrementResponse {
             value: response.value,
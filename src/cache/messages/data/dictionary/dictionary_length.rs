@@ -5,8 +5,8 @@ use momento_protos::cache_client::{
 };
 
 use crate::{
-    cache::MomentoRequest, utils::prep_request_with_timeout, CacheClient, IntoBytes, MomentoError,
-    MomentoResult,
+    cache::MomentoRequest, utils::prep_request_with_timeout, CacheClient, ErrorContext, IntoBytes,
+    MomentoError, MomentoResult,
 };
 
 /// Gets the number of elements in the given dictionary.
@@ -52,19 +52,32 @@ impl<D: IntoBytes> DictionaryLengthRequest<D> {
 impl<D: IntoBytes> MomentoRequest for DictionaryLengthRequest<D> {
     type Response = DictionaryLengthResponse;
 
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new(
+            "DictionaryLength",
+            self.cache_name.clone(),
+        ))
+    }
+
     async fn send(self, cache_client: &CacheClient) -> MomentoResult<DictionaryLengthResponse> {
+        let error_context = self.error_context();
+        let dictionary_name = self.dictionary_name.into_bytes();
+        let error_context =
+            error_context.map(|context| context.with_collection_name(&dictionary_name));
         let request = prep_request_with_timeout(
             &self.cache_name,
             cache_client.deadline_millis(),
-            DictionaryLengthRequestProto {
-                dictionary_name: self.dictionary_name.into_bytes(),
-            },
+            DictionaryLengthRequestProto { dictionary_name },
         )?;
 
         let response = cache_client
-            .next_data_client()
-            .dictionary_length(request)
-            .await?
+            .send_with_retries(
+                request,
+                Self::is_idempotent(),
+                |mut client, request| async move { client.dictionary_length(request).await },
+            )
+            .await
+            .map_err(|e| e.with_context(error_context))?
             .into_inner();
 
         match response.dictionary {
@@ -1,5 +1,6 @@
 use crate::{
-    cache::MomentoRequest, utils::prep_request_with_timeout, CacheClient, IntoBytes, MomentoError,
+    cache::MomentoRequest, utils::prep_request_with_timeout, CacheClient, ErrorContext, IntoBytes,
+    MomentoError,
 };
 use momento_protos::cache_client::{
     dictionary_delete_request as DictionaryFieldSelector,
@@ -58,7 +59,18 @@ impl<D: IntoBytes, F: IntoBytes> DictionaryRemoveFieldRequest<D, F> {
 impl<D: IntoBytes, F: IntoBytes> MomentoRequest for DictionaryRemoveFieldRequest<D, F> {
     type Response = DictionaryRemoveFieldResponse;
 
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new(
+            "DictionaryRemoveField",
+            self.cache_name.clone(),
+        ))
+    }
+
     async fn send(self, cache_client: &CacheClient) -> Result<Self::Response, MomentoError> {
+        let error_context = self.error_context();
+        let dictionary_name = self.dictionary_name.into_bytes();
+        let error_context =
+            error_context.map(|context| context.with_collection_name(&dictionary_name));
         let fields_to_delete = DictionaryFieldSelector::Some {
             fields: vec![self.field.into_bytes()],
         };
@@ -66,15 +78,19 @@ impl<D: IntoBytes, F: IntoBytes> MomentoRequest for DictionaryRemoveFieldRequest
             &self.cache_name,
             cache_client.deadline_millis(),
             DictionaryRemoveFieldsRequestProto {
-                dictionary_name: self.dictionary_name.into_bytes(),
+                dictionary_name,
                 delete: Some(DictionaryFieldSelector::Delete::Some(fields_to_delete)),
             },
         )?;
 
         cache_client
-            .next_data_client()
-            .dictionary_delete(request)
-            .await?
+            .send_with_retries(
+                request,
+                Self::is_idempotent(),
+                |mut client, request| async move { client.dictionary_delete(request).await },
+            )
+            .await
+            .map_err(|e| e.with_context(error_context))?
             .into_inner();
 
         Ok(DictionaryRemoveFieldResponse {})
@@ -1,5 +1,5 @@
 use crate::{
-    cache::MomentoRequest, utils::prep_request_with_timeout, CacheClient, IntoBytes,
+    cache::MomentoRequest, utils::prep_request_with_timeout, CacheClient, ErrorContext, IntoBytes,
     IntoBytesIterable, MomentoError,
 };
 use momento_protos::cache_client::{
@@ -59,7 +59,18 @@ impl<D: IntoBytes, F: IntoBytesIterable> DictionaryRemoveFieldsRequest<D, F> {
 impl<D: IntoBytes, F: IntoBytesIterable> MomentoRequest for DictionaryRemoveFieldsRequest<D, F> {
     type Response = DictionaryRemoveFieldsResponse;
 
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new(
+            "DictionaryRemoveFields",
+            self.cache_name.clone(),
+        ))
+    }
+
     async fn send(self, cache_client: &CacheClient) -> Result<Self::Response, MomentoError> {
+        let error_context = self.error_context();
+        let dictionary_name = self.dictionary_name.into_bytes();
+        let error_context =
+            error_context.map(|context| context.with_collection_name(&dictionary_name));
         let fields_to_delete = DictionaryFieldSelector::Some {
             fields: self.fields.into_bytes(),
         };
@@ -67,15 +78,19 @@ impl<D: IntoBytes, F: IntoBytesIterable> MomentoRequest for DictionaryRemoveFiel
             &self.cache_name,
             cache_client.deadline_millis(),
             DictionaryRemoveFieldsRequestProto {
-                dictionary_name: self.dictionary_name.into_bytes(),
+                dictionary_name,
                 delete: Some(DictionaryFieldSelector::Delete::Some(fields_to_delete)),
             },
         )?;
 
         cache_client
-            .next_data_client()
-            .dictionary_delete(request)
-            .await?
+            .send_with_retries(
+                request,
+                Self::is_idempotent(),
+                |mut client, request| async move { client.dictionary_delete(request).await },
+            )
+            .await
+            .map_err(|e| e.with_context(error_context))?
             .into_inner();
 
         Ok(DictionaryRemoveFieldsResponse {})
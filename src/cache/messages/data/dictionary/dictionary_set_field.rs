@@ -1,13 +1,13 @@
 use crate::cache::messages::MomentoRequest;
 use crate::cache::CollectionTtl;
 use crate::utils::prep_request_with_timeout;
+use crate::ErrorContext;
 use crate::IntoBytes;
 use crate::{CacheClient, MomentoResult};
 use momento_protos::cache_client::{
     DictionaryFieldValuePair as DictionaryFieldValuePairProto,
     DictionarySetRequest as DictionarySetFieldRequestProto,
 };
-
 /// Request to set a field in a dictionary.
 ///
 /// # Arguments
@@ -81,6 +81,8 @@ where
         self.collection_ttl = collection_ttl.into();
         self
     }
+
+    collection_ttl_shortcuts!("dictionary");
 }
 
 impl<D, F, V> MomentoRequest for DictionarySetFieldRequest<D, F, V>
@@ -91,13 +93,24 @@ where
 {
     type Response = DictionarySetFieldResponse;
 
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new(
+            "DictionarySetField",
+            self.cache_name.clone(),
+        ))
+    }
+
     async fn send(self, cache_client: &CacheClient) -> MomentoResult<Self::Response> {
+        let error_context = self.error_context();
+        let dictionary_name = self.dictionary_name.into_bytes();
+        let error_context =
+            error_context.map(|context| context.with_collection_name(&dictionary_name));
         let collection_ttl = self.collection_ttl.unwrap_or_default();
         let request = prep_request_with_timeout(
             &self.cache_name,
             cache_client.deadline_millis(),
             DictionarySetFieldRequestProto {
-                dictionary_name: self.dictionary_name.into_bytes(),
+                dictionary_name,
                 items: vec![DictionaryFieldValuePairProto {
                     field: self.field.into_bytes(),
                     value: self.value.into_bytes(),
@@ -108,9 +121,13 @@ where
         )?;
 
         cache_client
-            .next_data_client()
-            .dictionary_set(request)
-            .await?;
+            .send_with_retries(
+                request,
+                Self::is_idempotent(),
+                |mut client, request| async move { client.dictionary_set(request).await },
+            )
+            .await
+            .map_err(|e| e.with_context(error_context))?;
 
         Ok(DictionarySetFieldResponse {})
     }
@@ -119,3 +136,44 @@ where
 /// The response type for a successful dictionary set field request.
 #[derive(Debug, PartialEq, Eq)]
 pub struct DictionarySetFieldResponse {}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn ttl_settings(collection_ttl: Option<CollectionTtl>) -> (Option<Duration>, bool) {
+        let collection_ttl = collection_ttl.unwrap_or_default();
+        (collection_ttl.ttl(), collection_ttl.refresh())
+    }
+
+    #[test]
+    fn ttl_shortcuts_set_the_collection_ttl() {
+        let ttl = Duration::from_secs(60);
+        assert_eq!(
+            ttl_settings(
+                DictionarySetFieldRequest::new("cache", "dictionary", "field", "value")
+                    .ttl_with_refresh(ttl)
+                    .collection_ttl
+            ),
+            (Some(ttl), true)
+        );
+        assert_eq!(
+            ttl_settings(
+                DictionarySetFieldRequest::new("cache", "dictionary", "field", "value")
+                    .ttl_no_refresh(ttl)
+                    .collection_ttl
+            ),
+            (Some(ttl), false)
+        );
+        assert_eq!(
+            ttl_settings(
+                DictionarySetFieldRequest::new("cache", "dictionary", "field", "value")
+                    .keep_ttl()
+                    .collection_ttl
+            ),
+            (None, false)
+        );
+    }
+}
@@ -1,15 +1,15 @@
-use std::collections::HashMap;
-use std::marker::PhantomData;
-
 use crate::cache::messages::MomentoRequest;
 use crate::cache::CollectionTtl;
 use crate::utils::prep_request_with_timeout;
+use crate::ErrorContext;
 use crate::IntoBytes;
 use crate::{CacheClient, MomentoResult};
 use momento_protos::cache_client::{
     DictionaryFieldValuePair as DictionaryFieldValuePairProto,
     DictionarySetRequest as DictionarySetFieldRequestProto,
 };
+use std::collections::HashMap;
+use std::marker::PhantomData;
 
 /// This trait defines an interface for converting a type into a vector of [DictionaryFieldValuePair].
 pub trait IntoDictionaryFieldValuePairs<F: IntoBytes, V: IntoBytes>: Send {
@@ -129,6 +129,8 @@ where
         self.collection_ttl = collection_ttl.into();
         self
     }
+
+    collection_ttl_shortcuts!("dictionary");
 }
 
 impl<D, F, V, E> MomentoRequest for DictionarySetFieldsRequest<D, F, V, E>
@@ -140,13 +142,24 @@ where
 {
     type Response = DictionarySetFieldsResponse;
 
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new(
+            "DictionarySetFields",
+            self.cache_name.clone(),
+        ))
+    }
+
     async fn send(self, cache_client: &CacheClient) -> MomentoResult<Self::Response> {
+        let error_context = self.error_context();
+        let dictionary_name = self.dictionary_name.into_bytes();
+        let error_context =
+            error_context.map(|context| context.with_collection_name(&dictionary_name));
         let collection_ttl = self.collection_ttl.unwrap_or_default();
         let request = prep_request_with_timeout(
             &self.cache_name,
             cache_client.deadline_millis(),
             DictionarySetFieldRequestProto {
-                dictionary_name: self.dictionary_name.into_bytes(),
+                dictionary_name,
                 items: self
                     .elements
                     .into_dictionary_field_value_pairs()
@@ -162,9 +175,13 @@ where
         )?;
 
         cache_client
-            .next_data_client()
-            .dictionary_set(request)
-            .await?;
+            .send_with_retries(
+                request,
+                Self::is_idempotent(),
+                |mut client, request| async move { client.dictionary_set(request).await },
+            )
+            .await
+            .map_err(|e| e.with_context(error_context))?;
 
         Ok(DictionarySetFieldsResponse {})
     }
@@ -0,0 +1,227 @@
+use crate::cache::messages::data::dictionary::dictionary_get_field::DictionaryGetFieldResponse;
+use crate::cache::messages::data::dictionary::dictionary_get_fields::{
+    DictionaryGetFieldsRequest, DictionaryGetFieldsResponse,
+};
+use crate::cache::messages::data::dictionary::dictionary_set_fields::{
+    DictionarySetFieldsRequest, IntoDictionaryFieldValuePairs,
+};
+use crate::cache::messages::MomentoRequest;
+use crate::cache::CollectionTtl;
+use crate::utils::{is_cache_name_valid, parse_string};
+use crate::{CacheClient, ErrorContext, IntoBytes, MomentoError, MomentoResult};
+use std::convert::TryFrom;
+use std::marker::PhantomData;
+
+/// Request to set fields in a dictionary, but only those fields that are not already present.
+/// Fields that already have a value keep it. If the dictionary does not exist, it will be created
+/// with all of the fields.
+///
+/// The service has no conditional dictionary write, so this reads the fields with a
+/// DictionaryGetFields request and then writes the missing ones with a DictionarySetFields
+/// request. The two requests are not atomic: if another client sets one of the missing fields
+/// between them, this request overwrites it. Use it where that race is acceptable, such as
+/// populating defaults that are only written once.
+///
+/// # Arguments
+///
+/// - `cache_name`: The name of the cache where the dictionary is stored.
+/// - `dictionary_name`: The name of the dictionary to set fields in.
+/// - `elements`: The fields and values to set in the dictionary if the fields are absent.
+///
+/// # Optional Arguments
+///
+/// - `collection_ttl`: The time-to-live for the collection. If not provided, the client's default time-to-live is used.
+///   It is only applied if at least one field is written.
+///
+/// # Examples
+/// Assumes that a CacheClient named `cache_client` has been created and is available.
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// # use momento_test_util::create_doctest_cache_client;
+/// # tokio_test::block_on(async {
+/// use std::convert::TryInto;
+/// use momento::cache::{CollectionTtl, DictionarySetFieldsIfNotExistsRequest};
+/// # let (cache_client, cache_name) = create_doctest_cache_client();
+/// let dictionary_name = "config";
+/// # cache_client.dictionary_set_field(&cache_name, dictionary_name, "region", "eu-west-1").await?;
+///
+/// let defaults_request = DictionarySetFieldsIfNotExistsRequest::new(
+///     cache_name,
+///     dictionary_name,
+///     vec![("region", "us-west-2"), ("retries", "3")]
+/// ).ttl(CollectionTtl::default());
+///
+/// let written: Vec<String> = cache_client.send_request(defaults_request).await?.try_into()?;
+/// # assert_eq!(written, vec!["retries".to_string()]);
+/// println!("Filled in defaults for {:?}", written);
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+pub struct DictionarySetFieldsIfNotExistsRequest<D, F, V, E>
+where
+    D: IntoBytes,
+    F: IntoBytes,
+    V: IntoBytes,
+    E: IntoDictionaryFieldValuePairs<F, V>,
+{
+    cache_name: String,
+    dictionary_name: D,
+    elements: E,
+    collection_ttl: Option<CollectionTtl>,
+    // F and V are only used for the [IntoDictionaryFieldValuePairs] generic type parameter.
+    _field_marker: PhantomData<F>,
+    _value_marker: PhantomData<V>,
+}
+
+impl<D, F, V, E> DictionarySetFieldsIfNotExistsRequest<D, F, V, E>
+where
+    D: IntoBytes,
+    F: IntoBytes,
+    V: IntoBytes,
+    E: IntoDictionaryFieldValuePairs<F, V>,
+{
+    /// Constructs a new DictionarySetFieldsIfNotExistsRequest.
+    pub fn new(cache_name: impl Into<String>, dictionary_name: D, elements: E) -> Self {
+        let collection_ttl = CollectionTtl::default();
+        Self {
+            cache_name: cache_name.into(),
+            dictionary_name,
+            elements,
+            collection_ttl: Some(collection_ttl),
+            _field_marker: PhantomData,
+            _value_marker: PhantomData,
+        }
+    }
+
+    /// Set the time-to-live for the collection.
+    pub fn ttl(mut self, collection_ttl: impl Into<Option<CollectionTtl>>) -> Self {
+        self.collection_ttl = collection_ttl.into();
+        self
+    }
+
+    collection_ttl_shortcuts!("dictionary");
+}
+
+impl<D, F, V, E> MomentoRequest for DictionarySetFieldsIfNotExistsRequest<D, F, V, E>
+where
+    D: IntoBytes,
+    F: IntoBytes,
+    V: IntoBytes,
+    E: IntoDictionaryFieldValuePairs<F, V>,
+{
+    type Response = DictionarySetFieldsIfNotExistsResponse;
+
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new(
+            "DictionarySetFieldsIfNotExists",
+            self.cache_name.clone(),
+        ))
+    }
+
+    fn is_composite(&self) -> bool {
+        true
+    }
+
+    async fn send(self, cache_client: &CacheClient) -> MomentoResult<Self::Response> {
+        is_cache_name_valid(&self.cache_name)?;
+
+        let dictionary_name = self.dictionary_name.into_bytes();
+        let elements: Vec<(Vec<u8>, Vec<u8>)> = self
+            .elements
+            .into_dictionary_field_value_pairs()
+            .into_iter()
+            .map(|pair| (pair.field.into_bytes(), pair.value.into_bytes()))
+            .collect();
+        if elements.is_empty() {
+            return Ok(DictionarySetFieldsIfNotExistsResponse {
+                written_fields: vec![],
+                existing_fields: vec![],
+            });
+        }
+
+        let fields: Vec<Vec<u8>> = elements.iter().map(|(field, _)| field.clone()).collect();
+        let existing = cache_client
+            .send_sub_request(DictionaryGetFieldsRequest::new(
+                &self.cache_name,
+                dictionary_name.clone(),
+                fields,
+            ))
+            .await?;
+        let present: Vec<bool> = match existing {
+            DictionaryGetFieldsResponse::Hit { responses, .. } => responses
+                .iter()
+                .map(|response| matches!(response, DictionaryGetFieldResponse::Hit { .. }))
+                .collect(),
+            DictionaryGetFieldsResponse::Miss => vec![false; elements.len()],
+        };
+
+        let mut missing = vec![];
+        let mut existing_fields = vec![];
+        for ((field, value), present) in elements.into_iter().zip(present) {
+            if present {
+                existing_fields.push(field);
+            } else {
+                missing.push((field, value));
+            }
+        }
+        let written_fields = missing.iter().map(|(field, _)| field.clone()).collect();
+
+        if !missing.is_empty() {
+            cache_client
+                .send_sub_request(
+                    DictionarySetFieldsRequest::new(&self.cache_name, dictionary_name, missing)
+                        .ttl(self.collection_ttl),
+                )
+                .await?;
+        }
+
+        Ok(DictionarySetFieldsIfNotExistsResponse {
+            written_fields,
+            existing_fields,
+        })
+    }
+}
+
+/// Response for a dictionary set fields if not exists operation.
+///
+/// Lists the fields that were written because they were absent and the fields that were left
+/// alone because they were already present. You can use `try_into()` to convert it into a
+/// `Vec<String>` of the written fields.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct DictionarySetFieldsIfNotExistsResponse {
+    written_fields: Vec<Vec<u8>>,
+    existing_fields: Vec<Vec<u8>>,
+}
+
+impl DictionarySetFieldsIfNotExistsResponse {
+    /// Returns the fields that were written because they were absent, in the order they were
+    /// given.
+    pub fn written_fields(&self) -> &[Vec<u8>] {
+        &self.written_fields
+    }
+
+    /// Returns the fields that were not written because they were already present, in the order
+    /// they were given.
+    pub fn existing_fields(&self) -> &[Vec<u8>] {
+        &self.existing_fields
+    }
+}
+
+impl From<DictionarySetFieldsIfNotExistsResponse> for Vec<Vec<u8>> {
+    fn from(response: DictionarySetFieldsIfNotExistsResponse) -> Self {
+        response.written_fields
+    }
+}
+
+impl TryFrom<DictionarySetFieldsIfNotExistsResponse> for Vec<String> {
+    type Error = MomentoError;
+
+    fn try_from(response: DictionarySetFieldsIfNotExistsResponse) -> Result<Self, Self::Error> {
+        response
+            .written_fields
+            .into_iter()
+            .map(parse_string)
+            .collect()
+    }
+}
@@ -1,7 +1,11 @@
+/// Contains the request and response types for copying a dictionary to a new name.
+pub mod dictionary_copy;
 /// Contains the request and response types for fetching a dictionary.
 pub mod dictionary_fetch;
 /// Contains the request and response types for getting a field from a dictionary.
 pub mod dictionary_get_field;
+/// Contains the request and response types for getting a field from each of several dictionaries.
+pub mod dictionary_get_field_batch;
 /// Contains the request and response types for getting multiple fields from a dictionary.
 pub mod dictionary_get_fields;
 /// Contains the request and response types for incrementing a field in a dictionary.
@@ -16,3 +20,5 @@ pub mod dictionary_remove_fields;
 pub mod dictionary_set_field;
 /// Contains the request and response types for setting fields in a dictionary.
 pub mod dictionary_set_fields;
+/// Contains the request and response types for setting only the absent fields in a dictionary.
+pub mod dictionary_set_fields_if_not_exists;
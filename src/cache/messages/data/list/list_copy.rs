@@ -0,0 +1,126 @@
+use std::time::Duration;
+
+use crate::cache::messages::data::list::list_concatenate_back::ListConcatenateBackRequest;
+use crate::cache::messages::data::list::list_fetch::{ListFetchRequest, ListFetchResponse};
+use crate::cache::messages::data::scalar::delete::DeleteRequest;
+use crate::cache::{CollectionTtl, MomentoRequest};
+use crate::utils::is_cache_name_valid;
+use crate::{CacheClient, ErrorContext, IntoBytes, MomentoResult};
+
+/// Copy a list to a new name, replacing anything stored under the destination name.
+///
+/// The service has no copy operation, so the list is fetched and then written to the
+/// destination, passing through the client. The copy is not atomic: changes made to the source
+/// while it is being copied may or may not be included, and the destination is deleted before it
+/// is written, so readers can briefly find it missing. If the write fails after the delete, the
+/// destination is left missing.
+///
+/// # Arguments
+///
+/// * `cache_name` - The name of the cache containing the list.
+/// * `source_name` - The name of the list to copy.
+/// * `destination_name` - The name to copy the list to.
+///
+/// # Optional Arguments
+///
+/// * `ttl` - The time-to-live of the copy. If not provided, the client's default time-to-live is
+///   used. The source's remaining time-to-live is not copied.
+///
+/// # Examples
+/// Assumes that a CacheClient named `cache_client` has been created and is available.
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// # use momento_test_util::create_doctest_cache_client;
+/// # tokio_test::block_on(async {
+/// use std::time::Duration;
+/// use momento::cache::{ListCopyRequest, ListCopyResponse};
+/// # let (cache_client, cache_name) = create_doctest_cache_client();
+/// # cache_client.list_concatenate_back(&cache_name, "history", vec!["a", "b"]).await?;
+///
+/// let request = ListCopyRequest::new(&cache_name, "history", "history-snapshot")
+///     .ttl(Duration::from_secs(3600));
+///
+/// match cache_client.send_request(request).await? {
+///     ListCopyResponse::Copied { count } => println!("Copied {} elements", count),
+///     ListCopyResponse::Miss => println!("Nothing to copy"),
+/// };
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+pub struct ListCopyRequest<S: IntoBytes, D: IntoBytes> {
+    cache_name: String,
+    source_name: S,
+    destination_name: D,
+    ttl: Option<Duration>,
+}
+
+impl<S: IntoBytes, D: IntoBytes> ListCopyRequest<S, D> {
+    /// Constructs a new ListCopyRequest.
+    pub fn new(cache_name: impl Into<String>, source_name: S, destination_name: D) -> Self {
+        Self {
+            cache_name: cache_name.into(),
+            source_name,
+            destination_name,
+            ttl: None,
+        }
+    }
+
+    /// Set the time-to-live of the copy.
+    pub fn ttl(mut self, ttl: impl Into<Option<Duration>>) -> Self {
+        self.ttl = ttl.into();
+        self
+    }
+}
+
+impl<S: IntoBytes, D: IntoBytes> MomentoRequest for ListCopyRequest<S, D> {
+    type Response = ListCopyResponse;
+
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new("ListCopy", self.cache_name.clone()))
+    }
+
+    fn is_composite(&self) -> bool {
+        true
+    }
+
+    async fn send(self, cache_client: &CacheClient) -> MomentoResult<ListCopyResponse> {
+        is_cache_name_valid(&self.cache_name)?;
+
+        let values = match cache_client
+            .send_sub_request(ListFetchRequest::new(&self.cache_name, self.source_name))
+            .await?
+        {
+            ListFetchResponse::Hit { values } => values.raw_item,
+            ListFetchResponse::Miss => return Ok(ListCopyResponse::Miss),
+        };
+        let count = values.len();
+
+        let destination_name = self.destination_name.into_bytes();
+        cache_client
+            .send_sub_request(DeleteRequest::new(
+                &self.cache_name,
+                destination_name.clone(),
+            ))
+            .await?;
+        cache_client
+            .send_sub_request(
+                ListConcatenateBackRequest::new(&self.cache_name, destination_name, values)
+                    .ttl(CollectionTtl::refresh_on_update(self.ttl)),
+            )
+            .await?;
+        Ok(ListCopyResponse::Copied { count })
+    }
+}
+
+/// Response for a list copy operation.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ListCopyResponse {
+    /// The list was copied.
+    Copied {
+        /// The number of elements copied.
+        count: usize,
+    },
+    /// The source list was not found. The destination was left unchanged.
+    Miss,
+}
@@ -1,4 +1,5 @@
 use std::convert::{TryFrom, TryInto};
+use std::ops::{Bound, RangeBounds};
 
 use momento_protos::{
     cache_client::{
@@ -11,7 +12,7 @@ use momento_protos::{
 use crate::{
     cache::MomentoRequest,
     utils::{parse_string, prep_request_with_timeout},
-    CacheClient, IntoBytes, IntoBytesIterable, MomentoError, MomentoResult,
+    CacheClient, ErrorContext, IntoBytes, IntoBytesIterable, MomentoError, MomentoResult,
 };
 
 /// Gets a list item from a cache with optional slices.
@@ -24,6 +25,11 @@ use crate::{
 ///
 /// * `start_index` - The starting inclusive element of the list to fetch. Default is 0.
 /// * `end_index` - The ending exclusive element of the list to fetch. Default is up to and including end of list.
+/// * `range` - Sets both indices from a Rust range such as `2..5`, `-10..` or `..-1`.
+///
+/// Negative indices count back from the end of the list, so `-1` is the last element. Indices
+/// past either end of the list are clamped by the server, so `-10..` returns the whole list if it
+/// has fewer than 10 elements.
 ///
 /// # Examples
 /// Assumes that a CacheClient named `cache_client` has been created and is available.
@@ -73,12 +79,58 @@ impl<L: IntoBytes> ListFetchRequest<L> {
         self.end_index = end_index.into();
         self
     }
+
+    /// Set the start and end indices of the list to fetch from a range. Negative bounds count
+    /// back from the end of the list, e.g. `-10..` fetches the last 10 elements and `..-1`
+    /// fetches every element except the last.
+    pub fn range(mut self, range: impl RangeBounds<i32>) -> Self {
+        let (start_index, end_index) = range_to_indices(range);
+        self.start_index = start_index;
+        self.end_index = end_index;
+        self
+    }
+}
+
+/// Converts a range into the inclusive start and exclusive end indices used by the server.
+/// `None` means the range is unbounded on that side.
+pub(crate) fn range_to_indices(range: impl RangeBounds<i32>) -> (Option<i32>, Option<i32>) {
+    let start_index = match range.start_bound() {
+        Bound::Included(&start) => Some(start),
+        // Starting after the last element selects nothing. Adding one would wrap around to the
+        // first element instead, so start past any possible end of the list.
+        Bound::Excluded(&-1) => Some(i32::MAX),
+        Bound::Excluded(&start) => Some(start.saturating_add(1)),
+        Bound::Unbounded => None,
+    };
+    let end_index = match range.end_bound() {
+        // An inclusive end of -1 is the last element, which is the same as no end at all.
+        Bound::Included(&-1) => None,
+        Bound::Included(&end) => Some(end.saturating_add(1)),
+        Bound::Excluded(&end) => Some(end),
+        Bound::Unbounded => None,
+    };
+    (start_index, end_index)
 }
 
 impl<L: IntoBytes> MomentoRequest for ListFetchRequest<L> {
     type Response = ListFetchResponse;
 
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new("ListFetch", self.cache_name.clone()))
+    }
+
+    fn is_hit(response: &ListFetchResponse) -> Option<bool> {
+        Some(matches!(response, ListFetchResponse::Hit { .. }))
+    }
+
+    fn miss() -> Option<ListFetchResponse> {
+        Some(ListFetchResponse::Miss)
+    }
+
     async fn send(self, cache_client: &CacheClient) -> MomentoResult<ListFetchResponse> {
+        let error_context = self.error_context();
+        let list_name = self.list_name.into_bytes();
+        let error_context = error_context.map(|context| context.with_collection_name(&list_name));
         let start_index = match self.start_index {
             Some(start) => Some(StartIndex::InclusiveStart(start)),
             None => Some(StartIndex::UnboundedStart(Unbounded {})),
@@ -91,16 +143,20 @@ impl<L: IntoBytes> MomentoRequest for ListFetchRequest<L> {
             &self.cache_name,
             cache_client.deadline_millis(),
             momento_protos::cache_client::ListFetchRequest {
-                list_name: self.list_name.into_bytes(),
+                list_name,
                 start_index,
                 end_index,
             },
         )?;
 
         let response = cache_client
-            .next_data_client()
-            .list_fetch(request)
-            .await?
+            .send_with_retries(
+                request,
+                Self::is_idempotent(),
+                |mut client, request| async move { client.list_fetch(request).await },
+            )
+            .await
+            .map_err(|e| e.with_context(error_context))?
             .into_inner();
 
         match response.list {
@@ -214,3 +270,57 @@ impl TryFrom<ListFetchResponse> for Vec<String> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_range_to_indices() {
+        assert_eq!(range_to_indices(..), (None, None));
+        assert_eq!(range_to_indices(2..5), (Some(2), Some(5)));
+        assert_eq!(range_to_indices(2..=5), (Some(2), Some(6)));
+        assert_eq!(range_to_indices(-10..), (Some(-10), None));
+        assert_eq!(range_to_indices(..-1), (None, Some(-1)));
+        assert_eq!(range_to_indices(-3..=-1), (Some(-3), None));
+        assert_eq!(range_to_indices(-3..=-2), (Some(-3), Some(-1)));
+        assert_eq!(
+            range_to_indices((Bound::Excluded(1), Bound::Unbounded)),
+            (Some(2), None)
+        );
+    }
+
+    #[test]
+    fn test_range_to_indices_with_negative_and_excluded_bounds() {
+        assert_eq!(range_to_indices(-5..-2), (Some(-5), Some(-2)));
+        assert_eq!(range_to_indices(..=-2), (None, Some(-1)));
+        assert_eq!(range_to_indices(..=-1), (None, None));
+        assert_eq!(
+            range_to_indices((Bound::Excluded(-3), Bound::Unbounded)),
+            (Some(-2), None)
+        );
+        assert_eq!(
+            range_to_indices((Bound::Excluded(-3), Bound::Included(-1))),
+            (Some(-2), None)
+        );
+        assert_eq!(
+            range_to_indices((Bound::Excluded(-3), Bound::Excluded(-1))),
+            (Some(-2), Some(-1))
+        );
+        assert_eq!(
+            range_to_indices((Bound::Excluded(i32::MAX), Bound::Unbounded)),
+            (Some(i32::MAX), None)
+        );
+
+        // Nothing comes after the last element, so these select nothing rather than the whole
+        // list
+        assert_eq!(
+            range_to_indices((Bound::Excluded(-1), Bound::Unbounded)),
+            (Some(i32::MAX), None)
+        );
+        assert_eq!(
+            range_to_indices((Bound::Excluded(-1), Bound::Included(-1))),
+            (Some(i32::MAX), None)
+        );
+    }
+}
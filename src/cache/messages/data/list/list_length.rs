@@ -3,8 +3,8 @@ use std::convert::TryFrom;
 use momento_protos::cache_client::list_length_response;
 
 use crate::{
-    cache::MomentoRequest, utils::prep_request_with_timeout, CacheClient, IntoBytes, MomentoError,
-    MomentoResult,
+    cache::MomentoRequest, utils::prep_request_with_timeout, CacheClient, ErrorContext, IntoBytes,
+    MomentoError, MomentoResult,
 };
 
 /// Gets the number of elements in the given list.
@@ -50,19 +50,28 @@ impl<L: IntoBytes> ListLengthRequest<L> {
 impl<L: IntoBytes> MomentoRequest for ListLengthRequest<L> {
     type Response = ListLengthResponse;
 
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new("ListLength", self.cache_name.clone()))
+    }
+
     async fn send(self, cache_client: &CacheClient) -> MomentoResult<ListLengthResponse> {
+        let error_context = self.error_context();
+        let list_name = self.list_name.into_bytes();
+        let error_context = error_context.map(|context| context.with_collection_name(&list_name));
         let request = prep_request_with_timeout(
             &self.cache_name,
             cache_client.deadline_millis(),
-            momento_protos::cache_client::ListLengthRequest {
-                list_name: self.list_name.into_bytes(),
-            },
+            momento_protos::cache_client::ListLengthRequest { list_name },
         )?;
 
         let response = cache_client
-            .next_data_client()
-            .list_length(request)
-            .await?
+            .send_with_retries(
+                request,
+                Self::is_idempotent(),
+                |mut client, request| async move { client.list_length(request).await },
+            )
+            .await
+            .map_err(|e| e.with_context(error_context))?
             .into_inner();
 
         match response.list {
@@ -3,9 +3,9 @@ use std::convert::{TryFrom, TryInto};
 use momento_protos::cache_client::list_pop_front_response;
 
 use crate::{
-    cache::MomentoRequest,
+    cache::{AsBytes, MomentoRequest},
     utils::{parse_string, prep_request_with_timeout},
-    CacheClient, IntoBytes, MomentoError, MomentoResult,
+    CacheClient, ErrorContext, IntoBytes, MomentoError, MomentoResult,
 };
 
 /// Remove and return the first element from a list item.
@@ -51,19 +51,32 @@ impl<L: IntoBytes> ListPopFrontRequest<L> {
 impl<L: IntoBytes> MomentoRequest for ListPopFrontRequest<L> {
     type Response = ListPopFrontResponse;
 
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new("ListPopFront", self.cache_name.clone()))
+    }
+
+    fn is_idempotent() -> bool {
+        false
+    }
+
     async fn send(self, cache_client: &CacheClient) -> MomentoResult<ListPopFrontResponse> {
+        let error_context = self.error_context();
+        let list_name = self.list_name.into_bytes();
+        let error_context = error_context.map(|context| context.with_collection_name(&list_name));
         let request = prep_request_with_timeout(
             &self.cache_name,
             cache_client.deadline_millis(),
-            momento_protos::cache_client::ListPopFrontRequest {
-                list_name: self.list_name.into_bytes(),
-            },
+            momento_protos::cache_client::ListPopFrontRequest { list_name },
         )?;
 
         let response = cache_client
-            .next_data_client()
-            .list_pop_front(request)
-            .await?
+            .send_with_retries(
+                request,
+                Self::is_idempotent(),
+                |mut client, request| async move { client.list_pop_front(request).await },
+            )
+            .await
+            .map_err(|e| e.with_context(error_context))?
             .into_inner();
 
         match response.list {
@@ -138,6 +151,15 @@ impl Value {
     }
 }
 
+impl AsBytes for ListPopFrontResponse {
+    fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            ListPopFrontResponse::Hit { value } => Some(&value.raw_item),
+            ListPopFrontResponse::Miss => None,
+        }
+    }
+}
+
 impl TryFrom<Value> for Vec<u8> {
     type Error = MomentoError;
 
@@ -1,7 +1,7 @@
 use crate::{
     cache::{CollectionTtl, MomentoRequest},
     utils::prep_request_with_timeout,
-    CacheClient, IntoBytes, MomentoResult,
+    CacheClient, ErrorContext, IntoBytes, MomentoResult,
 };
 
 /// Adds an element to the back of the given list. Creates the list if it does not already exist.
@@ -63,6 +63,8 @@ impl<L: IntoBytes, V: IntoBytes> ListPushBackRequest<L, V> {
         self
     }
 
+    collection_ttl_shortcuts!("list");
+
     /// If the list exceeds this length, remove excess from the front of the list.
     pub fn truncate_front_to_size(
         mut self,
@@ -76,10 +78,20 @@ impl<L: IntoBytes, V: IntoBytes> ListPushBackRequest<L, V> {
 impl<L: IntoBytes, V: IntoBytes> MomentoRequest for ListPushBackRequest<L, V> {
     type Response = ListPushBackResponse;
 
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new("ListPushBack", self.cache_name.clone()))
+    }
+
+    fn is_idempotent() -> bool {
+        false
+    }
+
     async fn send(self, cache_client: &CacheClient) -> MomentoResult<ListPushBackResponse> {
+        let error_context = self.error_context();
         let collection_ttl = self.collection_ttl.unwrap_or_default();
         let value = self.value.into_bytes();
         let list_name = self.list_name.into_bytes();
+        let error_context = error_context.map(|context| context.with_collection_name(&list_name));
         let cache_name = &self.cache_name;
         let request = prep_request_with_timeout(
             cache_name,
@@ -94,9 +106,13 @@ impl<L: IntoBytes, V: IntoBytes> MomentoRequest for ListPushBackRequest<L, V> {
         )?;
 
         let _ = cache_client
-            .next_data_client()
-            .list_push_back(request)
-            .await?;
+            .send_with_retries(
+                request,
+                Self::is_idempotent(),
+                |mut client, request| async move { client.list_push_back(request).await },
+            )
+            .await
+            .map_err(|e| e.with_context(error_context))?;
         Ok(ListPushBackResponse {})
     }
 }
@@ -104,3 +120,44 @@ impl<L: IntoBytes, V: IntoBytes> MomentoRequest for ListPushBackRequest<L, V> {
 /// The response type for a successful list push back request.
 #[derive(Debug, PartialEq, Eq)]
 pub struct ListPushBackResponse {}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn ttl_settings(collection_ttl: Option<CollectionTtl>) -> (Option<Duration>, bool) {
+        let collection_ttl = collection_ttl.unwrap_or_default();
+        (collection_ttl.ttl(), collection_ttl.refresh())
+    }
+
+    #[test]
+    fn ttl_shortcuts_set_the_collection_ttl() {
+        let ttl = Duration::from_secs(60);
+        assert_eq!(
+            ttl_settings(
+                ListPushBackRequest::new("cache", "list", "value")
+                    .ttl_with_refresh(ttl)
+                    .collection_ttl
+            ),
+            (Some(ttl), true)
+        );
+        assert_eq!(
+            ttl_settings(
+                ListPushBackRequest::new("cache", "list", "value")
+                    .ttl_no_refresh(ttl)
+                    .collection_ttl
+            ),
+            (Some(ttl), false)
+        );
+        assert_eq!(
+            ttl_settings(
+                ListPushBackRequest::new("cache", "list", "value")
+                    .keep_ttl()
+                    .collection_ttl
+            ),
+            (None, false)
+        );
+    }
+}
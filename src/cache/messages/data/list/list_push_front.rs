@@ -1,7 +1,7 @@
 use crate::{
     cache::{CollectionTtl, MomentoRequest},
     utils::prep_request_with_timeout,
-    CacheClient, IntoBytes, MomentoResult,
+    CacheClient, ErrorContext, IntoBytes, MomentoResult,
 };
 
 /// Adds an element to the front of the given list. Creates the list if it does not already exist.
@@ -63,6 +63,8 @@ impl<L: IntoBytes, V: IntoBytes> ListPushFrontRequest<L, V> {
         self
     }
 
+    collection_ttl_shortcuts!("list");
+
     /// If the list exceeds this length, remove excess from the front of the list.
     pub fn truncate_back_to_size(mut self, truncate_back_to_size: impl Into<Option<u32>>) -> Self {
         self.truncate_back_to_size = truncate_back_to_size.into();
@@ -73,10 +75,20 @@ impl<L: IntoBytes, V: IntoBytes> ListPushFrontRequest<L, V> {
 impl<L: IntoBytes, V: IntoBytes> MomentoRequest for ListPushFrontRequest<L, V> {
     type Response = ListPushFrontResponse;
 
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new("ListPushFront", self.cache_name.clone()))
+    }
+
+    fn is_idempotent() -> bool {
+        false
+    }
+
     async fn send(self, cache_client: &CacheClient) -> MomentoResult<ListPushFrontResponse> {
+        let error_context = self.error_context();
         let collection_ttl = self.collection_ttl.unwrap_or_default();
         let value = self.value.into_bytes();
         let list_name = self.list_name.into_bytes();
+        let error_context = error_context.map(|context| context.with_collection_name(&list_name));
         let cache_name = &self.cache_name;
         let request = prep_request_with_timeout(
             cache_name,
@@ -91,9 +103,13 @@ impl<L: IntoBytes, V: IntoBytes> MomentoRequest for ListPushFrontRequest<L, V> {
         )?;
 
         let _ = cache_client
-            .next_data_client()
-            .list_push_front(request)
-            .await?;
+            .send_with_retries(
+                request,
+                Self::is_idempotent(),
+                |mut client, request| async move { client.list_push_front(request).await },
+            )
+            .await
+            .map_err(|e| e.with_context(error_context))?;
         Ok(ListPushFrontResponse {})
     }
 }
@@ -1,7 +1,8 @@
 use momento_protos::cache_client::list_remove_request::Remove;
 
 use crate::{
-    cache::MomentoRequest, utils::prep_request_with_timeout, CacheClient, IntoBytes, MomentoResult,
+    cache::MomentoRequest, utils::prep_request_with_timeout, CacheClient, ErrorContext, IntoBytes,
+    MomentoResult,
 };
 
 /// Remove all elements in a list item equal to a particular value.
@@ -52,20 +53,34 @@ impl<L: IntoBytes, V: IntoBytes> ListRemoveValueRequest<L, V> {
 impl<L: IntoBytes, V: IntoBytes> MomentoRequest for ListRemoveValueRequest<L, V> {
     type Response = ListRemoveValueResponse;
 
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new(
+            "ListRemoveValue",
+            self.cache_name.clone(),
+        ))
+    }
+
     async fn send(self, cache_client: &CacheClient) -> MomentoResult<ListRemoveValueResponse> {
+        let error_context = self.error_context();
+        let list_name = self.list_name.into_bytes();
+        let error_context = error_context.map(|context| context.with_collection_name(&list_name));
         let request = prep_request_with_timeout(
             &self.cache_name,
             cache_client.deadline_millis(),
             momento_protos::cache_client::ListRemoveRequest {
-                list_name: self.list_name.into_bytes(),
+                list_name,
                 remove: Some(Remove::AllElementsWithValue(self.value.into_bytes())),
             },
         )?;
 
         cache_client
-            .next_data_client()
-            .list_remove(request)
-            .await?
+            .send_with_retries(
+                request,
+                Self::is_idempotent(),
+                |mut client, request| async move { client.list_remove(request).await },
+            )
+            .await
+            .map_err(|e| e.with_context(error_context))?
             .into_inner();
         Ok(ListRemoveValueResponse {})
     }
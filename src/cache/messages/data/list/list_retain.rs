@@ -0,0 +1,166 @@
+use momento_protos::{
+    cache_client::{
+        list_retain_request::{EndIndex, StartIndex},
+        list_retain_response,
+    },
+    common::Unbounded,
+};
+use std::ops::RangeBounds;
+
+use crate::{
+    cache::{messages::data::list::list_fetch::range_to_indices, CollectionTtl, MomentoRequest},
+    utils::prep_request_with_timeout,
+    CacheClient, ErrorContext, IntoBytes, MomentoError, MomentoResult,
+};
+
+/// Trims a list in place, keeping only the elements in the given index range.
+///
+/// # Arguments
+/// * `cache_name` - name of cache
+/// * `list_name` - name of the list
+///
+/// # Optional Arguments
+///
+/// * `start_index` - The starting inclusive element of the list to keep. Default is 0.
+/// * `end_index` - The ending exclusive element of the list to keep. Default is up to and including end of list.
+/// * `range` - Sets both indices from a Rust range such as `2..5`, `-10..` or `..-1`.
+/// * `collection_ttl` - The time-to-live for the collection. If not provided, the client's default time-to-live is used.
+///
+/// Negative indices count back from the end of the list, so `-1` is the last element. With
+/// neither index set, every element is kept.
+///
+/// # Examples
+/// Assumes that a CacheClient named `cache_client` has been created and is available.
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// # use momento_test_util::create_doctest_cache_client;
+/// # tokio_test::block_on(async {
+/// use momento::cache::{CollectionTtl, ListRetainRequest, ListRetainResponse};
+/// # let (cache_client, cache_name) = create_doctest_cache_client();
+/// let list_name = "list-name";
+/// # cache_client.list_concatenate_back(&cache_name, list_name, vec!["value1", "value2", "value3"]).await?;
+///
+/// // Keep only the last two elements
+/// let retain_request = ListRetainRequest::new(cache_name, list_name)
+///     .range(-2..)
+///     .ttl(CollectionTtl::default());
+///
+/// match cache_client.send_request(retain_request).await? {
+///     ListRetainResponse::Hit => println!("List trimmed"),
+///     ListRetainResponse::Miss => println!("List does not exist"),
+/// }
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+pub struct ListRetainRequest<L: IntoBytes> {
+    cache_name: String,
+    list_name: L,
+    start_index: Option<i32>,
+    end_index: Option<i32>,
+    collection_ttl: Option<CollectionTtl>,
+}
+
+impl<L: IntoBytes> ListRetainRequest<L> {
+    /// Constructs a new ListRetainRequest.
+    pub fn new(cache_name: impl Into<String>, list_name: L) -> Self {
+        Self {
+            cache_name: cache_name.into(),
+            list_name,
+            start_index: None,
+            end_index: None,
+            collection_ttl: None,
+        }
+    }
+
+    /// Set the starting inclusive element of the list to keep.
+    pub fn start_index(mut self, start_index: impl Into<Option<i32>>) -> Self {
+        self.start_index = start_index.into();
+        self
+    }
+
+    /// Set the ending exclusive element of the list to keep.
+    pub fn end_index(mut self, end_index: impl Into<Option<i32>>) -> Self {
+        self.end_index = end_index.into();
+        self
+    }
+
+    /// Set the start and end indices of the elements to keep from a range. Negative bounds
+    /// count back from the end of the list, e.g. `-10..` keeps the last 10 elements.
+    pub fn range(mut self, range: impl RangeBounds<i32>) -> Self {
+        let (start_index, end_index) = range_to_indices(range);
+        self.start_index = start_index;
+        self.end_index = end_index;
+        self
+    }
+
+    /// Set the time-to-live for the collection.
+    pub fn ttl(mut self, collection_ttl: impl Into<Option<CollectionTtl>>) -> Self {
+        self.collection_ttl = collection_ttl.into();
+        self
+    }
+
+    collection_ttl_shortcuts!("list");
+}
+
+impl<L: IntoBytes> MomentoRequest for ListRetainRequest<L> {
+    type Response = ListRetainResponse;
+
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new("ListRetain", self.cache_name.clone()))
+    }
+
+    async fn send(self, cache_client: &CacheClient) -> MomentoResult<ListRetainResponse> {
+        let error_context = self.error_context();
+        let list_name = self.list_name.into_bytes();
+        let error_context = error_context.map(|context| context.with_collection_name(&list_name));
+        let collection_ttl = self.collection_ttl.unwrap_or_default();
+        let start_index = match self.start_index {
+            Some(start) => Some(StartIndex::InclusiveStart(start)),
+            None => Some(StartIndex::UnboundedStart(Unbounded {})),
+        };
+        let end_index = match self.end_index {
+            Some(end) => Some(EndIndex::ExclusiveEnd(end)),
+            None => Some(EndIndex::UnboundedEnd(Unbounded {})),
+        };
+        let request = prep_request_with_timeout(
+            &self.cache_name,
+            cache_client.deadline_millis(),
+            momento_protos::cache_client::ListRetainRequest {
+                list_name,
+                start_index,
+                end_index,
+                ttl_milliseconds: cache_client.expand_ttl_ms(collection_ttl.ttl())?,
+                refresh_ttl: collection_ttl.refresh(),
+            },
+        )?;
+
+        let response = cache_client
+            .send_with_retries(
+                request,
+                Self::is_idempotent(),
+                |mut client, request| async move { client.list_retain(request).await },
+            )
+            .await
+            .map_err(|e| e.with_context(error_context))?
+            .into_inner();
+
+        match response.list {
+            Some(list_retain_response::List::Missing(_)) => Ok(ListRetainResponse::Miss),
+            Some(list_retain_response::List::Found(_)) => Ok(ListRetainResponse::Hit),
+            _ => Err(MomentoError::unknown_error(
+                "ListRetain",
+                Some(format!("{:#?}", response)),
+            )),
+        }
+    }
+}
+
+/// Response for a list retain operation.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ListRetainResponse {
+    /// The list was found and trimmed to the requested range.
+    Hit,
+    /// The list was not found.
+    Miss,
+}
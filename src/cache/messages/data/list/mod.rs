@@ -2,6 +2,8 @@
 pub mod list_concatenate_back;
 /// Contains the request and response types for concatenating a list to the front of another list.
 pub mod list_concatenate_front;
+/// Contains the request and response types for copying a list to a new name.
+pub mod list_copy;
 /// Contains the request and response types for fetching elements from a list.
 pub mod list_fetch;
 /// Contains the request and response types for getting the number of elements in a list.
@@ -16,3 +18,5 @@ pub mod list_push_back;
 pub mod list_push_front;
 /// Contains the request and response types for removing elements from a list.
 pub mod list_remove_value;
+/// Contains the request and response types for trimming a list to a range of indices.
+pub mod list_retain;
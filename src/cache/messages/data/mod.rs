@@ -1,3 +1,40 @@
+/// The number of requests a batch request that sends one request per item, such as
+/// [DictionaryGetFieldBatchRequest](crate::cache::DictionaryGetFieldBatchRequest), sends at once
+/// unless configured otherwise.
+pub const DEFAULT_BATCH_CONCURRENCY: usize = 10;
+
+/// Implements `ttl_with_refresh`, `ttl_no_refresh` and `keep_ttl` on a collection write request
+/// in terms of its `ttl` builder. `$collection` names the collection in the generated docs.
+macro_rules! collection_ttl_shortcuts {
+    ($collection:literal) => {
+        #[doc = concat!(
+                            "Set the time-to-live for the ", $collection,
+                            ", refreshing it every time the ", $collection, " is updated."
+                        )]
+        pub fn ttl_with_refresh(self, ttl: ::std::time::Duration) -> Self {
+            self.ttl($crate::cache::CollectionTtl::refresh_on_update(ttl))
+        }
+
+        #[doc = concat!(
+                            "Set the time-to-live for the ", $collection,
+                            " only if this request creates it. Updating an existing ", $collection,
+                            " leaves its time-to-live unchanged."
+                        )]
+        pub fn ttl_no_refresh(self, ttl: ::std::time::Duration) -> Self {
+            self.ttl($crate::cache::CollectionTtl::initialize_only(ttl))
+        }
+
+        #[doc = concat!(
+                            "Leave the time-to-live of an existing ", $collection,
+                            " unchanged. If this request creates the ", $collection,
+                            ", it uses the client's default time-to-live."
+                        )]
+        pub fn keep_ttl(self) -> Self {
+            self.ttl($crate::cache::CollectionTtl::initialize_only(None))
+        }
+    };
+}
+
 /// Contains the request and response types for working with dictionaries.
 pub mod dictionary;
 /// Contains the request and response types for working with lists.
@@ -1,11 +1,15 @@
 use std::time::Duration;
 
+use derive_more::Display;
 use momento_protos::cache_client::update_ttl_request::UpdateTtl::DecreaseToMilliseconds;
 use momento_protos::cache_client::update_ttl_response::{self};
+use serde::Serialize;
 
 use crate::MomentoError;
 use crate::{
-    cache::MomentoRequest, utils::prep_request_with_timeout, CacheClient, IntoBytes, MomentoResult,
+    cache::MomentoRequest,
+    utils::{prep_request_with_timeout, record_key_length},
+    CacheClient, ErrorContext, IntoBytes, MomentoResult,
 };
 
 /// Decrease the ttl of an item in the cache.
@@ -57,12 +61,19 @@ impl<K: IntoBytes> DecreaseTtlRequest<K> {
 impl<K: IntoBytes> MomentoRequest for DecreaseTtlRequest<K> {
     type Response = DecreaseTtlResponse;
 
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new("DecreaseTtl", self.cache_name.clone()))
+    }
+
     async fn send(self, cache_client: &CacheClient) -> MomentoResult<DecreaseTtlResponse> {
+        let key = self.key.into_bytes();
+        record_key_length(&key);
+
         let request = prep_request_with_timeout(
             &self.cache_name,
             cache_client.deadline_millis(),
             momento_protos::cache_client::UpdateTtlRequest {
-                cache_key: self.key.into_bytes(),
+                cache_key: key,
                 update_ttl: Some(DecreaseToMilliseconds(
                     cache_client.expand_ttl_ms(Some(self.ttl))?,
                 )),
@@ -70,8 +81,11 @@ impl<K: IntoBytes> MomentoRequest for DecreaseTtlRequest<K> {
         )?;
 
         let response = cache_client
-            .next_data_client()
-            .update_ttl(request)
+            .send_with_retries(
+                request,
+                Self::is_idempotent(),
+                |mut client, request| async move { client.update_ttl(request).await },
+            )
             .await?
             .into_inner();
 
@@ -88,7 +102,7 @@ impl<K: IntoBytes> MomentoRequest for DecreaseTtlRequest<K> {
 }
 
 /// Response for a decrease ttl operation.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Display, Serialize, PartialEq, Eq)]
 pub enum DecreaseTtlResponse {
     /// The ttl was successfully decreased.
     Set,
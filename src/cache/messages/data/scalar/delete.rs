@@ -1,6 +1,10 @@
 use crate::{
-    cache::MomentoRequest, utils::prep_request_with_timeout, CacheClient, IntoBytes, MomentoResult,
+    cache::MomentoRequest,
+    utils::{prep_request_with_timeout, record_key_length},
+    CacheClient, ErrorContext, IntoBytes, MomentoResult,
 };
+use derive_more::Display;
+use serde::Serialize;
 
 /// Deletes an item in a Momento Cache
 ///
@@ -54,20 +58,32 @@ impl<K: IntoBytes> DeleteRequest<K> {
 impl<K: IntoBytes> MomentoRequest for DeleteRequest<K> {
     type Response = DeleteResponse;
 
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new("Delete", self.cache_name.clone()))
+    }
+
     async fn send(self, cache_client: &CacheClient) -> MomentoResult<DeleteResponse> {
+        let key = self.key.into_bytes();
+        record_key_length(&key);
+
         let request = prep_request_with_timeout(
             &self.cache_name,
             cache_client.deadline_millis(),
-            momento_protos::cache_client::DeleteRequest {
-                cache_key: self.key.into_bytes(),
-            },
+            momento_protos::cache_client::DeleteRequest { cache_key: key },
         )?;
 
-        let _ = cache_client.next_data_client().delete(request).await?;
+        let _ = cache_client
+            .send_with_retries(
+                request,
+                Self::is_idempotent(),
+                |mut client, request| async move { client.delete(request).await },
+            )
+            .await?;
         Ok(DeleteResponse {})
     }
 }
 
 /// The response type for a successful delete request
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Display, Serialize, PartialEq, Eq)]
+#[display(fmt = "DeleteResponse")]
 pub struct DeleteResponse {}
@@ -1,10 +1,13 @@
-use crate::cache::messages::MomentoRequest;
+use crate::cache::messages::{AsBytes, MomentoRawRequest, MomentoRequest};
 use crate::utils;
 use crate::utils::fmt::AsDebuggableValue;
 use crate::CacheClient;
-use crate::{IntoBytes, MomentoError, MomentoResult};
+use crate::ErrorContext;
+use crate::{ErrorSource, IntoBytes, MomentoError, MomentoErrorCode, MomentoResult};
 use derive_more::Display;
 use momento_protos::cache_client::ECacheResult;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::convert::{TryFrom, TryInto};
 
 /// Request to get an item from a cache
@@ -57,32 +60,64 @@ impl<K: IntoBytes> GetRequest<K> {
 impl<K: IntoBytes> MomentoRequest for GetRequest<K> {
     type Response = GetResponse;
 
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new("Get", self.cache_name.clone()))
+    }
+
+    fn is_hit(response: &GetResponse) -> Option<bool> {
+        Some(matches!(response, GetResponse::Hit { .. }))
+    }
+
+    fn miss() -> Option<GetResponse> {
+        Some(GetResponse::Miss)
+    }
+
     async fn send(self, cache_client: &CacheClient) -> MomentoResult<GetResponse> {
+        self.send_raw(cache_client)
+            .await
+            .map(|(response, _raw_response)| response)
+    }
+}
+
+impl<K: IntoBytes> MomentoRawRequest for GetRequest<K> {
+    type RawResponse = momento_protos::cache_client::GetResponse;
+
+    async fn send_raw(
+        self,
+        cache_client: &CacheClient,
+    ) -> MomentoResult<(GetResponse, Self::RawResponse)> {
+        let key = self.key.into_bytes();
+        utils::record_key_length(&key);
+
         let request = utils::prep_request_with_timeout(
             &self.cache_name,
             cache_client.deadline_millis(),
-            momento_protos::cache_client::GetRequest {
-                cache_key: self.key.into_bytes(),
-            },
+            momento_protos::cache_client::GetRequest { cache_key: key },
         )?;
 
-        let response = cache_client
-            .next_data_client()
-            .get(request)
+        let raw_response = cache_client
+            .send_with_retries(
+                request,
+                Self::is_idempotent(),
+                |mut client, request| async move { client.get(request).await },
+            )
             .await?
             .into_inner();
-        match response.result() {
-            ECacheResult::Hit => Ok(GetResponse::Hit {
+        let response = match raw_response.result() {
+            ECacheResult::Hit => GetResponse::Hit {
                 value: Value {
-                    raw_item: response.cache_body,
+                    raw_item: raw_response.cache_body.clone(),
                 },
-            }),
-            ECacheResult::Miss => Ok(GetResponse::Miss),
-            _ => Err(MomentoError::unknown_error(
-                "Get",
-                Some(format!("{:#?}", response)),
-            )),
-        }
+            },
+            ECacheResult::Miss => GetResponse::Miss,
+            _ => {
+                return Err(MomentoError::unknown_error(
+                    "Get",
+                    Some(format!("{:#?}", raw_response)),
+                ))
+            }
+        };
+        Ok((response, raw_response))
     }
 }
 
@@ -132,7 +167,7 @@ impl<K: IntoBytes> MomentoRequest for GetRequest<K> {
 /// use std::convert::TryInto;
 /// let item: MomentoResult<Vec<u8>> = get_response.try_into();
 /// ```
-#[derive(Debug, Display, PartialEq, Eq, Clone)]
+#[derive(Debug, Display, Serialize, Deserialize, PartialEq, Eq, Clone)]
 pub enum GetResponse {
     /// The item was found in the cache.
     Hit {
@@ -143,6 +178,74 @@ pub enum GetResponse {
     Miss,
 }
 
+impl GetResponse {
+    /// Deserializes the value of a hit from JSON into a `T`.
+    ///
+    /// The raw bytes are parsed directly, without first converting them to a `String`. A miss
+    /// is returned as a `Miss` error, and a value that is not valid JSON for `T` as an
+    /// `InvalidArgumentError`.
+    /// ```
+    /// # use momento::cache::GetResponse;
+    /// # use momento::MomentoResult;
+    /// # fn main() -> MomentoResult<()> {
+    /// use std::collections::HashMap;
+    /// # let get_response = GetResponse::from(r#"{"score": 42}"#);
+    /// let item: HashMap<String, u32> = get_response.into_json()?;
+    /// # assert_eq!(item.get("score"), Some(&42));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_json<T: DeserializeOwned>(self) -> MomentoResult<T> {
+        match self {
+            GetResponse::Hit { value } => {
+                serde_json::from_slice(&value.raw_item).map_err(|e| MomentoError {
+                    message: "cached value is not valid JSON for the requested type".to_string(),
+                    error_code: MomentoErrorCode::InvalidArgumentError,
+                    inner_error: Some(ErrorSource::Unknown(Box::new(e))),
+                    details: None,
+                    context: None,
+                })
+            }
+            GetResponse::Miss => Err(MomentoError::miss("Get")),
+        }
+    }
+
+    /// Parses the value of a hit as a decimal integer, the format stored by
+    /// [increment](CacheClient::increment) and [SetRequest::new_i64](crate::cache::SetRequest::new_i64).
+    ///
+    /// A miss is returned as an error with the `Miss` code, and a value that is not a valid `i64`
+    /// as a `FailedPreconditionError`.
+    /// ```
+    /// # use momento::cache::GetResponse;
+    /// # use momento::MomentoResult;
+    /// # fn main() -> MomentoResult<()> {
+    /// # let get_response = GetResponse::from("42");
+    /// let count = get_response.as_i64()?;
+    /// # assert_eq!(count, 42);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn as_i64(&self) -> MomentoResult<i64> {
+        match self {
+            GetResponse::Hit { value } => value.as_i64(),
+            GetResponse::Miss => Err(MomentoError::miss("Get")),
+        }
+    }
+
+    /// Parses the value of a hit as a decimal number. Integers stored by
+    /// [increment](CacheClient::increment) are accepted as well as the values stored by
+    /// [SetRequest::new_f64](crate::cache::SetRequest::new_f64).
+    ///
+    /// A miss is returned as an error with the `Miss` code, and a value that is not a valid `f64`
+    /// as a `FailedPreconditionError`.
+    pub fn as_f64(&self) -> MomentoResult<f64> {
+        match self {
+            GetResponse::Hit { value } => value.as_f64(),
+            GetResponse::Miss => Err(MomentoError::miss("Get")),
+        }
+    }
+}
+
 impl<I: IntoBytes> From<I> for GetResponse {
     fn from(value: I) -> Self {
         GetResponse::Hit {
@@ -174,11 +277,74 @@ impl std::fmt::Display for Value {
     }
 }
 
+/// Serializes as a string if the value is valid UTF-8, and as bytes otherwise, matching how
+/// [Debug] formats it.
+impl Serialize for Value {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match std::str::from_utf8(&self.raw_item) {
+            Ok(text) => serializer.serialize_str(text),
+            Err(_) => serializer.serialize_bytes(&self.raw_item),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Value {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum RawItem {
+            Text(String),
+            Bytes(Vec<u8>),
+        }
+
+        let raw_item = match RawItem::deserialize(deserializer)? {
+            RawItem::Text(text) => text.into_bytes(),
+            RawItem::Bytes(bytes) => bytes,
+        };
+        Ok(Value::new(raw_item))
+    }
+}
+
 impl Value {
     /// Constructs a new Value.
     pub fn new(raw_item: Vec<u8>) -> Self {
         Self { raw_item }
     }
+
+    /// Parses the value as a decimal integer. See [GetResponse::as_i64].
+    pub fn as_i64(&self) -> MomentoResult<i64> {
+        self.parse_number("i64")
+    }
+
+    /// Parses the value as a decimal number. See [GetResponse::as_f64].
+    pub fn as_f64(&self) -> MomentoResult<f64> {
+        self.parse_number("f64")
+    }
+
+    fn parse_number<T>(&self, type_name: &str) -> MomentoResult<T>
+    where
+        T: std::str::FromStr,
+        T::Err: std::error::Error + Send + Sync + 'static,
+    {
+        let not_a_number = |e: Box<dyn std::error::Error + Send + Sync>| MomentoError {
+            message: format!("cached value is not a valid {}", type_name),
+            error_code: MomentoErrorCode::FailedPreconditionError,
+            inner_error: Some(ErrorSource::Unknown(e)),
+            details: None,
+            context: None,
+        };
+        let text = std::str::from_utf8(&self.raw_item).map_err(|e| not_a_number(Box::new(e)))?;
+        text.parse().map_err(|e: T::Err| not_a_number(Box::new(e)))
+    }
+}
+
+impl AsBytes for GetResponse {
+    fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            GetResponse::Hit { value } => Some(&value.raw_item),
+            GetResponse::Miss => None,
+        }
+    }
 }
 
 impl TryFrom<Value> for String {
@@ -276,4 +442,81 @@ Hit {
 
         Ok(())
     }
+
+    #[test]
+    fn test_get_response_json_round_trip() {
+        let hit = GetResponse::from("hello");
+        let json = serde_json::to_string(&hit).expect("response should serialize");
+        assert_eq!(json, r#"{"Hit":{"value":"hello"}}"#);
+        assert_eq!(
+            serde_json::from_str::<GetResponse>(&json).expect("response should deserialize"),
+            hit
+        );
+
+        let binary_hit = GetResponse::from(vec![0, 150, 146, 159]);
+        let json = serde_json::to_string(&binary_hit).expect("response should serialize");
+        assert_eq!(json, r#"{"Hit":{"value":[0,150,146,159]}}"#);
+        assert_eq!(
+            serde_json::from_str::<GetResponse>(&json).expect("response should deserialize"),
+            binary_hit
+        );
+
+        let json = serde_json::to_string(&GetResponse::Miss).expect("response should serialize");
+        assert_eq!(json, r#""Miss""#);
+        assert_eq!(
+            serde_json::from_str::<GetResponse>(&json).expect("response should deserialize"),
+            GetResponse::Miss
+        );
+    }
+
+    #[test]
+    fn test_get_response_into_json() {
+        let hit = GetResponse::from(r#"[1, 2, 3]"#);
+        assert_eq!(hit.into_json::<Vec<u32>>().unwrap(), vec![1, 2, 3]);
+
+        let not_json = GetResponse::from("not json");
+        let error = not_json.into_json::<Vec<u32>>().unwrap_err();
+        assert_eq!(error.error_code, MomentoErrorCode::InvalidArgumentError);
+        assert!(error.inner_error.is_some());
+
+        let miss = GetResponse::Miss.into_json::<Vec<u32>>().unwrap_err();
+        assert_eq!(miss.error_code, MomentoErrorCode::Miss);
+    }
+
+    #[test]
+    fn test_get_response_as_numbers() {
+        let integer = GetResponse::from("-42");
+        assert_eq!(integer.as_i64().unwrap(), -42);
+        assert_eq!(integer.as_f64().unwrap(), -42.0);
+
+        let float = GetResponse::from(0.1f64.to_string());
+        assert_eq!(float.as_f64().unwrap(), 0.1);
+        assert_eq!(
+            float.as_i64().unwrap_err().error_code,
+            MomentoErrorCode::FailedPreconditionError
+        );
+
+        for not_a_number in [
+            GetResponse::from("forty-two"),
+            GetResponse::from(vec![0xff]),
+        ] {
+            let error = not_a_number.as_i64().unwrap_err();
+            assert_eq!(error.error_code, MomentoErrorCode::FailedPreconditionError);
+            assert!(error.inner_error.is_some());
+            assert_eq!(
+                not_a_number.as_f64().unwrap_err().error_code,
+                MomentoErrorCode::FailedPreconditionError
+            );
+        }
+
+        let miss = GetResponse::Miss;
+        assert_eq!(
+            miss.as_i64().unwrap_err().error_code,
+            MomentoErrorCode::Miss
+        );
+        assert_eq!(
+            miss.as_f64().unwrap_err().error_code,
+            MomentoErrorCode::Miss
+        );
+    }
 }
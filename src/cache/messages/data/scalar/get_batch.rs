@@ -1,3 +1,5 @@
+use futures::future::BoxFuture;
+use futures::{future, Future, FutureExt, StreamExt, TryStreamExt};
 use momento_protos::cache_client::ECacheResult;
 use std::{
     collections::HashMap,
@@ -6,11 +8,11 @@ use std::{
 
 use crate::{
     cache::MomentoRequest,
-    utils::{self, parse_string},
-    CacheClient, IntoBytesIterable, MomentoError, MomentoResult,
+    utils::{self, bounded_fan_out, parse_string},
+    CacheClient, ErrorContext, IntoBytesIterable, MomentoError, MomentoResult,
 };
 
-use crate::cache::messages::data::scalar::get::{GetResponse, Value};
+use crate::cache::messages::data::scalar::get::{GetRequest, GetResponse, Value};
 
 /// Request to get a batch of items from a Momento Cache
 ///
@@ -19,6 +21,14 @@ use crate::cache::messages::data::scalar::get::{GetResponse, Value};
 /// * `cache_name` - name of cache
 /// * `keys` - list of keys to fetch
 ///
+/// An empty batch returns an empty response without making a network call, even if the cache
+/// does not exist. The cache name is still validated.
+///
+/// # Optional Arguments
+///
+/// * `max_concurrent_requests` - Send the keys as individual get requests, at most this many at
+///   a time, instead of in a single batch request.
+///
 /// # Examples
 /// Assumes that a CacheClient named `cache_client` has been created and is available.
 /// ```
@@ -53,6 +63,8 @@ use crate::cache::messages::data::scalar::get::{GetResponse, Value};
 pub struct GetBatchRequest<K: IntoBytesIterable> {
     cache_name: String,
     keys: K,
+    max_concurrent_requests: Option<usize>,
+    cancellation: Option<BoxFuture<'static, ()>>,
 }
 
 impl<K: IntoBytesIterable> GetBatchRequest<K> {
@@ -61,17 +73,96 @@ impl<K: IntoBytesIterable> GetBatchRequest<K> {
         Self {
             cache_name: cache_name.into(),
             keys,
+            max_concurrent_requests: None,
+            cancellation: None,
         }
     }
+
+    /// Send the keys as individual get requests, with at most `max_concurrent_requests` of them
+    /// in flight at once, instead of in a single batch request. Use this to limit the load a
+    /// large batch puts on the connection. Values below 1 are treated as 1.
+    pub fn max_concurrent_requests(mut self, max_concurrent_requests: usize) -> Self {
+        self.max_concurrent_requests = Some(max_concurrent_requests.max(1));
+        self
+    }
+
+    /// Stops receiving results when `signal` completes. The response then holds only the
+    /// results received so far and [GetBatchResponse::is_cancelled] returns true.
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use momento_test_util::create_doctest_cache_client;
+    /// # tokio_test::block_on(async {
+    /// # let (cache_client, cache_name) = create_doctest_cache_client();
+    /// use std::time::Duration;
+    /// use momento::cache::GetBatchRequest;
+    ///
+    /// let get_batch_request = GetBatchRequest::new(cache_name, vec!["key1", "key2"])
+    ///     .cancel_on(tokio::time::sleep(Duration::from_millis(100)));
+    /// let response = cache_client.send_request(get_batch_request).await?;
+    /// if response.is_cancelled() {
+    ///     println!("Batch was cancelled with partial results");
+    /// }
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    pub fn cancel_on(mut self, signal: impl Future<Output = ()> + Send + 'static) -> Self {
+        self.cancellation = Some(signal.boxed());
+        self
+    }
 }
 
 impl<K: IntoBytesIterable> MomentoRequest for GetBatchRequest<K> {
     type Response = GetBatchResponse;
 
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new("GetBatch", self.cache_name.clone()))
+    }
+
+    fn is_composite(&self) -> bool {
+        self.max_concurrent_requests.is_some()
+    }
+
     async fn send(self, cache_client: &CacheClient) -> MomentoResult<GetBatchResponse> {
         // Convert all keys to bytes so they can be sent over the wire
         // and create a HashMap of (key, GetResponse) pairs for the response
         let byte_keys: Vec<Vec<u8>> = self.keys.into_bytes();
+        if byte_keys.is_empty() {
+            utils::is_cache_name_valid(&self.cache_name)?;
+            return Ok(GetBatchResponse {
+                results_dictionary: HashMap::new(),
+                cancelled: false,
+            });
+        }
+
+        let cancellation = self
+            .cancellation
+            .unwrap_or_else(|| future::pending().boxed());
+        if let Some(max_concurrent_requests) = self.max_concurrent_requests {
+            utils::is_cache_name_valid(&self.cache_name)?;
+            let cache_name = &self.cache_name;
+            let gets = byte_keys.into_iter().map(|key| {
+                let request = GetRequest::new(cache_name, key.clone());
+                async move {
+                    cache_client
+                        .send_sub_request(request)
+                        .await
+                        .map(|response| (key, response))
+                }
+            });
+            let mut results =
+                bounded_fan_out(gets, max_concurrent_requests).take_until(cancellation);
+
+            let mut responses: HashMap<Vec<u8>, GetResponse> = HashMap::new();
+            while let Some((key, response)) = results.try_next().await? {
+                responses.insert(key, response);
+            }
+            return Ok(GetBatchResponse {
+                results_dictionary: responses,
+                cancelled: results.is_stopped(),
+            });
+        }
 
         let get_requests = byte_keys
             .iter()
@@ -89,15 +180,19 @@ impl<K: IntoBytesIterable> MomentoRequest for GetBatchRequest<K> {
         )?;
 
         let mut response_stream = cache_client
-            .next_data_client()
-            .get_batch(get_batch_request)
+            .send_with_retries(
+                get_batch_request,
+                Self::is_idempotent(),
+                |mut client, request| async move { client.get_batch(request).await },
+            )
             .await?
-            .into_inner();
+            .into_inner()
+            .take_until(cancellation);
 
-        // receive stream of get responses
+        // receive stream of get responses until it ends or the request is cancelled
         let mut responses: HashMap<Vec<u8>, GetResponse> = HashMap::new();
         let mut byte_keys_iter = byte_keys.into_iter();
-        while let Some(get_response) = response_stream.message().await? {
+        while let Some(get_response) = response_stream.try_next().await? {
             let sdk_get_response = match get_response.result() {
                 ECacheResult::Hit => GetResponse::Hit {
                     value: Value {
@@ -126,6 +221,7 @@ impl<K: IntoBytesIterable> MomentoRequest for GetBatchRequest<K> {
 
         Ok(GetBatchResponse {
             results_dictionary: responses,
+            cancelled: response_stream.is_stopped(),
         })
     }
 }
@@ -178,6 +274,15 @@ impl<K: IntoBytesIterable> MomentoRequest for GetBatchRequest<K> {
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct GetBatchResponse {
     results_dictionary: HashMap<Vec<u8>, GetResponse>,
+    cancelled: bool,
+}
+
+impl GetBatchResponse {
+    /// Returns true if the request was cancelled with [GetBatchRequest::cancel_on] before all
+    /// results were received. The response then holds only the keys received before cancelling.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled
+    }
 }
 
 // (Bytes key, GetResponse) pairs -- does NOT filter out Miss responses
@@ -0,0 +1,97 @@
+use std::time::Duration;
+
+use futures::future::try_join;
+
+use crate::cache::messages::data::scalar::get::{GetRequest, GetResponse};
+use crate::cache::messages::data::scalar::update_ttl::UpdateTtlRequest;
+use crate::cache::MomentoRequest;
+use crate::{CacheClient, ErrorContext, IntoBytes, MomentoResult};
+
+/// Get an item from the cache and, if it is found, reset its ttl, for sliding expiration where
+/// every read keeps the item alive.
+///
+/// The service has no single request that reads an item and updates its ttl, so this sends a get
+/// and a ttl update for the same key at the same time, which takes about one round trip. The two
+/// are not atomic: an item that expires or is deleted just as they are sent may be returned
+/// without its ttl being reset, and an item written by another client in between is given the
+/// new ttl. The ttl is overwritten rather than lengthened, so a read can shorten the ttl of an
+/// item that was written with a longer one; use the same ttl for writes and sliding reads.
+///
+/// If either request fails, the error is returned, even if the get succeeded.
+///
+/// # Arguments
+/// * `cache_name` - name of cache
+/// * `key` - key of the item to get
+/// * `ttl` - the ttl to give the item if it is found
+///
+/// # Examples
+/// Assumes that a CacheClient named `cache_client` has been created and is available.
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// # use momento_test_util::create_doctest_cache_client;
+/// # tokio_test::block_on(async {
+/// # let (cache_client, cache_name) = create_doctest_cache_client();
+/// use std::convert::TryInto;
+/// use std::time::Duration;
+/// use momento::cache::{GetResponse, GetSlidingRequest};
+/// # cache_client.set(&cache_name, "session", "data").await?;
+///
+/// let request = GetSlidingRequest::new(&cache_name, "session", Duration::from_secs(1800));
+///
+/// match cache_client.send_request(request).await? {
+///     GetResponse::Hit { value } => {
+///         let session: String = value.try_into()?;
+///         println!("Session {} extended", session);
+///     }
+///     GetResponse::Miss => println!("Session expired"),
+/// };
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+pub struct GetSlidingRequest<K: IntoBytes> {
+    cache_name: String,
+    key: K,
+    ttl: Duration,
+}
+
+impl<K: IntoBytes> GetSlidingRequest<K> {
+    /// Constructs a new GetSlidingRequest.
+    pub fn new(cache_name: impl Into<String>, key: K, ttl: Duration) -> Self {
+        Self {
+            cache_name: cache_name.into(),
+            key,
+            ttl,
+        }
+    }
+}
+
+impl<K: IntoBytes> MomentoRequest for GetSlidingRequest<K> {
+    type Response = GetResponse;
+
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new("GetSliding", self.cache_name.clone()))
+    }
+
+    fn is_hit(response: &GetResponse) -> Option<bool> {
+        Some(matches!(response, GetResponse::Hit { .. }))
+    }
+
+    fn miss() -> Option<GetResponse> {
+        Some(GetResponse::Miss)
+    }
+
+    fn is_composite(&self) -> bool {
+        true
+    }
+
+    async fn send(self, cache_client: &CacheClient) -> MomentoResult<GetResponse> {
+        let key = self.key.into_bytes();
+        let get = cache_client.send_sub_request(GetRequest::new(&self.cache_name, key.clone()));
+        let update_ttl =
+            cache_client.send_sub_request(UpdateTtlRequest::new(&self.cache_name, key, self.ttl));
+        // A miss from the ttl update means the item was not there to refresh, whatever the get saw
+        let (response, _) = try_join(get, update_ttl).await?;
+        Ok(response)
+    }
+}
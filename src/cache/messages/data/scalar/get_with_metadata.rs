@@ -0,0 +1,159 @@
+use std::convert::TryFrom;
+use std::time::Duration;
+
+use futures::future::try_join;
+
+use crate::cache::messages::data::scalar::get::{GetRequest, GetResponse, Value};
+use crate::cache::messages::data::scalar::item_get_ttl::{ItemGetTtlRequest, ItemGetTtlResponse};
+use crate::cache::MomentoRequest;
+use crate::utils::is_cache_name_valid;
+use crate::{CacheClient, ErrorContext, IntoBytes, MomentoError, MomentoResult};
+
+/// Request to get an item from a cache together with its remaining time-to-live.
+///
+/// The service's Get does not return the ttl, so the item and its ttl are read with concurrent
+/// Get and ItemGetTtl requests. This takes about one round trip, but the two reads are not
+/// atomic: if the item is overwritten between them, the ttl may belong to the newer item.
+///
+/// # Arguments
+///
+/// * `cache_name` - name of cache
+/// * `key` - key of entry within the cache.
+///
+/// # Examples
+/// Assumes that a CacheClient named `cache_client` has been created and is available.
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// # use momento_test_util::create_doctest_cache_client;
+/// # tokio_test::block_on(async {
+/// use std::convert::TryInto;
+/// use std::time::Duration;
+/// use momento::cache::{GetWithMetadataRequest, GetWithMetadataResponse};
+/// # let (cache_client, cache_name) = create_doctest_cache_client();
+/// # cache_client.set(&cache_name, "key", "value").await?;
+///
+/// let request = GetWithMetadataRequest::new(&cache_name, "key");
+///
+/// match cache_client.send_request(request).await? {
+///     GetWithMetadataResponse::Hit { value, remaining_ttl } => {
+///         let value: String = value.try_into()?;
+///         if remaining_ttl < Duration::from_secs(10) {
+///             println!("{} is about to expire", value);
+///         }
+///     }
+///     GetWithMetadataResponse::Miss => println!("cache miss"),
+/// }
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+pub struct GetWithMetadataRequest<K: IntoBytes> {
+    cache_name: String,
+    key: K,
+}
+
+impl<K: IntoBytes> GetWithMetadataRequest<K> {
+    /// Constructs a new GetWithMetadataRequest.
+    pub fn new(cache_name: impl Into<String>, key: K) -> Self {
+        Self {
+            cache_name: cache_name.into(),
+            key,
+        }
+    }
+}
+
+impl<K: IntoBytes> MomentoRequest for GetWithMetadataRequest<K> {
+    type Response = GetWithMetadataResponse;
+
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new(
+            "GetWithMetadata",
+            self.cache_name.clone(),
+        ))
+    }
+
+    fn is_hit(response: &GetWithMetadataResponse) -> Option<bool> {
+        Some(matches!(response, GetWithMetadataResponse::Hit { .. }))
+    }
+
+    fn miss() -> Option<GetWithMetadataResponse> {
+        Some(GetWithMetadataResponse::Miss)
+    }
+
+    fn is_composite(&self) -> bool {
+        true
+    }
+
+    async fn send(self, cache_client: &CacheClient) -> MomentoResult<GetWithMetadataResponse> {
+        is_cache_name_valid(&self.cache_name)?;
+
+        let key = self.key.into_bytes();
+        let get = cache_client.send_sub_request(GetRequest::new(&self.cache_name, key.clone()));
+        let get_ttl = cache_client.send_sub_request(ItemGetTtlRequest::new(&self.cache_name, key));
+        match try_join(get, get_ttl).await? {
+            (GetResponse::Hit { value }, ItemGetTtlResponse::Hit { remaining_ttl }) => {
+                Ok(GetWithMetadataResponse::Hit {
+                    value,
+                    remaining_ttl,
+                })
+            }
+            // The item expired or was deleted between the two reads
+            _ => Ok(GetWithMetadataResponse::Miss),
+        }
+    }
+}
+
+/// Response for a get with metadata operation.
+///
+/// Like [GetResponse], but a hit also carries the item's remaining time-to-live. You can use
+/// `into()` to convert it into a [GetResponse], and `try_into()` to convert it into a
+/// `(Vec<u8>, Duration)` or `(String, Duration)` pair, which fails on a miss.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum GetWithMetadataResponse {
+    /// The item was found in the cache.
+    Hit {
+        /// The value of the item.
+        value: Value,
+        /// The remaining time-to-live of the item.
+        remaining_ttl: Duration,
+    },
+    /// The item was not found in the cache.
+    Miss,
+}
+
+impl From<GetWithMetadataResponse> for GetResponse {
+    fn from(response: GetWithMetadataResponse) -> Self {
+        match response {
+            GetWithMetadataResponse::Hit { value, .. } => GetResponse::Hit { value },
+            GetWithMetadataResponse::Miss => GetResponse::Miss,
+        }
+    }
+}
+
+impl TryFrom<GetWithMetadataResponse> for (Vec<u8>, Duration) {
+    type Error = MomentoError;
+
+    fn try_from(response: GetWithMetadataResponse) -> Result<Self, Self::Error> {
+        match response {
+            GetWithMetadataResponse::Hit {
+                value,
+                remaining_ttl,
+            } => Ok((value.into(), remaining_ttl)),
+            GetWithMetadataResponse::Miss => Err(MomentoError::miss("GetWithMetadata")),
+        }
+    }
+}
+
+impl TryFrom<GetWithMetadataResponse> for (String, Duration) {
+    type Error = MomentoError;
+
+    fn try_from(response: GetWithMetadataResponse) -> Result<Self, Self::Error> {
+        match response {
+            GetWithMetadataResponse::Hit {
+                value,
+                remaining_ttl,
+            } => Ok((String::try_from(value)?, remaining_ttl)),
+            GetWithMetadataResponse::Miss => Err(MomentoError::miss("GetWithMetadata")),
+        }
+    }
+}
@@ -1,11 +1,15 @@
 use std::time::Duration;
 
+use derive_more::Display;
 use momento_protos::cache_client::update_ttl_request::UpdateTtl::IncreaseToMilliseconds;
 use momento_protos::cache_client::update_ttl_response::{self};
+use serde::Serialize;
 
 use crate::MomentoError;
 use crate::{
-    cache::MomentoRequest, utils::prep_request_with_timeout, CacheClient, IntoBytes, MomentoResult,
+    cache::MomentoRequest,
+    utils::{prep_request_with_timeout, record_key_length},
+    CacheClient, ErrorContext, IntoBytes, MomentoResult,
 };
 
 /// Increase the ttl of an item in the cache.
@@ -57,12 +61,19 @@ impl<K: IntoBytes> IncreaseTtlRequest<K> {
 impl<K: IntoBytes> MomentoRequest for IncreaseTtlRequest<K> {
     type Response = IncreaseTtlResponse;
 
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new("IncreaseTtl", self.cache_name.clone()))
+    }
+
     async fn send(self, cache_client: &CacheClient) -> MomentoResult<IncreaseTtlResponse> {
+        let key = self.key.into_bytes();
+        record_key_length(&key);
+
         let request = prep_request_with_timeout(
             &self.cache_name,
             cache_client.deadline_millis(),
             momento_protos::cache_client::UpdateTtlRequest {
-                cache_key: self.key.into_bytes(),
+                cache_key: key,
                 update_ttl: Some(IncreaseToMilliseconds(
                     cache_client.expand_ttl_ms(Some(self.ttl))?,
                 )),
@@ -70,8 +81,11 @@ impl<K: IntoBytes> MomentoRequest for IncreaseTtlRequest<K> {
         )?;
 
         let response = cache_client
-            .next_data_client()
-            .update_ttl(request)
+            .send_with_retries(
+                request,
+                Self::is_idempotent(),
+                |mut client, request| async move { client.update_ttl(request).await },
+            )
             .await?
             .into_inner();
 
@@ -88,7 +102,7 @@ impl<K: IntoBytes> MomentoRequest for IncreaseTtlRequest<K> {
 }
 
 /// Response for an increase ttl operation.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Display, Serialize, PartialEq, Eq)]
 pub enum IncreaseTtlResponse {
     /// The ttl was updated successfully.
     Set,
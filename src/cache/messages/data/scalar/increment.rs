@@ -1,7 +1,12 @@
 use std::time::Duration;
 
+use derive_more::Display;
+use serde::Serialize;
+
 use crate::{
-    cache::MomentoRequest, utils::prep_request_with_timeout, CacheClient, IntoBytes, MomentoResult,
+    cache::MomentoRequest,
+    utils::{prep_request_with_timeout, record_key_length},
+    CacheClient, ErrorContext, IntoBytes, MomentoResult,
 };
 
 /// Adds an integer quantity to a cache item.
@@ -75,20 +80,34 @@ impl<K: IntoBytes> IncrementRequest<K> {
 impl<K: IntoBytes> MomentoRequest for IncrementRequest<K> {
     type Response = IncrementResponse;
 
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new("Increment", self.cache_name.clone()))
+    }
+
+    fn is_idempotent() -> bool {
+        false
+    }
+
     async fn send(self, cache_client: &CacheClient) -> MomentoResult<IncrementResponse> {
+        let key = self.key.into_bytes();
+        record_key_length(&key);
+
         let request = prep_request_with_timeout(
             &self.cache_name,
             cache_client.deadline_millis(),
             momento_protos::cache_client::IncrementRequest {
-                cache_key: self.key.into_bytes(),
+                cache_key: key,
                 amount: self.amount,
                 ttl_milliseconds: cache_client.expand_ttl_ms(self.ttl)?,
             },
         )?;
 
         let response = cache_client
-            .next_data_client()
-            .increment(request)
+            .send_with_retries(
+                request,
+                Self::is_idempotent(),
+                |mut client, request| async move { client.increment(request).await },
+            )
             .await?
             .into_inner();
         Ok(IncrementResponse {
@@ -98,7 +117,7 @@ impl<K: IntoBytes> MomentoRequest for IncrementRequest<K> {
 }
 
 /// The response type for a successful increment request.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Display, Serialize, PartialEq, Eq)]
 pub struct IncrementResponse {
     /// The new value of the item after the increment operation.
     pub value: i64,
@@ -4,8 +4,9 @@ use std::time::Duration;
 use momento_protos::cache_client::item_get_ttl_response::{self};
 
 use crate::{
-    cache::MomentoRequest, utils::prep_request_with_timeout, CacheClient, IntoBytes, MomentoError,
-    MomentoResult,
+    cache::MomentoRequest,
+    utils::{prep_request_with_timeout, record_key_length},
+    CacheClient, ErrorContext, IntoBytes, MomentoError, MomentoResult,
 };
 
 /// Return the remaining ttl of an item in the cache
@@ -52,18 +53,26 @@ impl<K: IntoBytes> ItemGetTtlRequest<K> {
 impl<K: IntoBytes> MomentoRequest for ItemGetTtlRequest<K> {
     type Response = ItemGetTtlResponse;
 
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new("ItemGetTtl", self.cache_name.clone()))
+    }
+
     async fn send(self, cache_client: &CacheClient) -> MomentoResult<ItemGetTtlResponse> {
+        let key = self.key.into_bytes();
+        record_key_length(&key);
+
         let request = prep_request_with_timeout(
             &self.cache_name,
             cache_client.deadline_millis(),
-            momento_protos::cache_client::ItemGetTtlRequest {
-                cache_key: self.key.into_bytes(),
-            },
+            momento_protos::cache_client::ItemGetTtlRequest { cache_key: key },
         )?;
 
         let response = cache_client
-            .next_data_client()
-            .item_get_ttl(request)
+            .send_with_retries(
+                request,
+                Self::is_idempotent(),
+                |mut client, request| async move { client.item_get_ttl(request).await },
+            )
             .await?
             .into_inner();
 
@@ -1,10 +1,13 @@
 use std::convert::TryFrom;
 
+use derive_more::Display;
 use momento_protos::cache_client::item_get_type_response::{self};
+use serde::Serialize;
 
 use crate::{
-    cache::MomentoRequest, utils::prep_request_with_timeout, CacheClient, IntoBytes, MomentoError,
-    MomentoResult,
+    cache::MomentoRequest,
+    utils::{prep_request_with_timeout, record_key_length},
+    CacheClient, ErrorContext, IntoBytes, MomentoError, MomentoResult,
 };
 
 /// Return the type of an item in the cache.
@@ -53,18 +56,26 @@ impl<K: IntoBytes> ItemGetTypeRequest<K> {
 impl<K: IntoBytes> MomentoRequest for ItemGetTypeRequest<K> {
     type Response = ItemGetTypeResponse;
 
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new("ItemGetType", self.cache_name.clone()))
+    }
+
     async fn send(self, cache_client: &CacheClient) -> MomentoResult<ItemGetTypeResponse> {
+        let key = self.key.into_bytes();
+        record_key_length(&key);
+
         let request = prep_request_with_timeout(
             &self.cache_name,
             cache_client.deadline_millis(),
-            momento_protos::cache_client::ItemGetTypeRequest {
-                cache_key: self.key.into_bytes(),
-            },
+            momento_protos::cache_client::ItemGetTypeRequest { cache_key: key },
         )?;
 
         let response = cache_client
-            .next_data_client()
-            .item_get_type(request)
+            .send_with_retries(
+                request,
+                Self::is_idempotent(),
+                |mut client, request| async move { client.item_get_type(request).await },
+            )
             .await?
             .into_inner();
 
@@ -98,7 +109,7 @@ impl<K: IntoBytes> MomentoRequest for ItemGetTypeRequest<K> {
 }
 
 /// The type of an item in the cache.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Display, Serialize, PartialEq, Eq)]
 pub enum ItemType {
     /// The item is a scalar value.
     Scalar = 0,
@@ -138,7 +149,7 @@ pub enum ItemType {
 /// # let response = ItemGetTypeResponse::Hit { key_type: ItemType::Scalar };
 /// let itemType: MomentoResult<ItemType> = response.try_into();
 /// ```
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Display, Serialize, PartialEq, Eq)]
 pub enum ItemGetTypeResponse {
     /// The item was found.
     Hit {
@@ -159,3 +170,29 @@ impl TryFrom<ItemGetTypeResponse> for ItemType {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_item_get_type_response_display_and_json() -> MomentoResult<()> {
+        let hit = ItemGetTypeResponse::Hit {
+            key_type: ItemType::SortedSet,
+        };
+        assert_eq!(format!("{}", hit), "SortedSet");
+        assert_eq!(
+            serde_json::to_string(&hit).expect("response should serialize"),
+            r#"{"Hit":{"key_type":"SortedSet"}}"#
+        );
+
+        let miss = ItemGetTypeResponse::Miss;
+        assert_eq!(format!("{}", miss), "Miss");
+        assert_eq!(
+            serde_json::to_string(&miss).expect("response should serialize"),
+            r#""Miss""#
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,174 @@
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+use futures::TryStreamExt;
+
+use crate::cache::messages::data::scalar::item_get_type::{
+    ItemGetTypeRequest, ItemGetTypeResponse, ItemType,
+};
+use crate::cache::messages::data::DEFAULT_BATCH_CONCURRENCY;
+use crate::cache::MomentoRequest;
+use crate::utils::{bounded_fan_out, is_cache_name_valid, parse_string};
+use crate::{CacheClient, ErrorContext, IntoBytesIterable, MomentoError, MomentoResult};
+
+/// Request to get the types of a batch of items in a cache.
+///
+/// The server has no batched item type lookup, so one ItemGetType request is sent per key, with
+/// at most `max_concurrent_requests` of them in flight at once.
+///
+/// # Arguments
+///
+/// * `cache_name` - name of cache
+/// * `keys` - list of keys to get the types of
+///
+/// # Optional Arguments
+///
+/// * `max_concurrent_requests` - The maximum number of requests in flight at once. Defaults to
+///   [DEFAULT_BATCH_CONCURRENCY](crate::cache::DEFAULT_BATCH_CONCURRENCY).
+///
+/// # Examples
+/// Assumes that a CacheClient named `cache_client` has been created and is available.
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// # use momento_test_util::create_doctest_cache_client;
+/// # tokio_test::block_on(async {
+/// # let (cache_client, cache_name) = create_doctest_cache_client();
+/// use std::convert::TryInto;
+/// use std::collections::HashMap;
+/// use momento::cache::{ItemGetTypeBatchRequest, ItemType};
+/// # cache_client.set(&cache_name, "key1", "value").await?;
+/// # cache_client.set_add_elements(&cache_name, "key2", vec!["value"]).await?;
+///
+/// let request = ItemGetTypeBatchRequest::new(&cache_name, vec!["key1", "key2", "key3"]);
+/// let item_types: HashMap<String, ItemType> = cache_client.send_request(request).await?.try_into()?;
+/// # assert_eq!(item_types.get("key1"), Some(&ItemType::Scalar));
+/// # assert_eq!(item_types.get("key2"), Some(&ItemType::Set));
+/// # assert_eq!(item_types.get("key3"), None);
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+pub struct ItemGetTypeBatchRequest<K: IntoBytesIterable> {
+    cache_name: String,
+    keys: K,
+    max_concurrent_requests: usize,
+}
+
+impl<K: IntoBytesIterable> ItemGetTypeBatchRequest<K> {
+    /// Constructs a new ItemGetTypeBatchRequest.
+    pub fn new(cache_name: impl Into<String>, keys: K) -> Self {
+        Self {
+            cache_name: cache_name.into(),
+            keys,
+            max_concurrent_requests: DEFAULT_BATCH_CONCURRENCY,
+        }
+    }
+
+    /// Set the maximum number of requests in flight at once. Values below 1 are treated as 1.
+    pub fn max_concurrent_requests(mut self, max_concurrent_requests: usize) -> Self {
+        self.max_concurrent_requests = max_concurrent_requests.max(1);
+        self
+    }
+}
+
+impl<K: IntoBytesIterable> MomentoRequest for ItemGetTypeBatchRequest<K> {
+    type Response = ItemGetTypeBatchResponse;
+
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new(
+            "ItemGetTypeBatch",
+            self.cache_name.clone(),
+        ))
+    }
+
+    fn is_composite(&self) -> bool {
+        true
+    }
+
+    async fn send(self, cache_client: &CacheClient) -> MomentoResult<ItemGetTypeBatchResponse> {
+        is_cache_name_valid(&self.cache_name)?;
+
+        let cache_name = &self.cache_name;
+        let lookups = self.keys.into_bytes().into_iter().map(|key| {
+            let request = ItemGetTypeRequest::new(cache_name, key.clone());
+            async move {
+                cache_client
+                    .send_sub_request(request)
+                    .await
+                    .map(|response| (key, response))
+            }
+        });
+        let mut results = bounded_fan_out(lookups, self.max_concurrent_requests);
+
+        let mut responses: HashMap<Vec<u8>, ItemGetTypeResponse> = HashMap::new();
+        while let Some((key, response)) = results.try_next().await? {
+            responses.insert(key, response);
+        }
+        Ok(ItemGetTypeBatchResponse {
+            results_dictionary: responses,
+        })
+    }
+}
+
+/// Response for a get item type batch operation.
+///
+/// You can use `into()` to convert an `ItemGetTypeBatchResponse` into one of the following:
+/// - `HashMap<Vec<u8>, ItemGetTypeResponse>`
+/// - `HashMap<Vec<u8>, ItemType>`
+///
+/// You can use `try_into()` to convert an `ItemGetTypeBatchResponse` into one of the following:
+/// - `HashMap<String, ItemGetTypeResponse>`
+/// - `HashMap<String, ItemType>`
+///
+/// The `HashMap<_, ItemGetTypeResponse>` maps hold every key, whether it was a hit or a miss.
+/// The `HashMap<_, ItemType>` maps filter out the keys that were not found.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ItemGetTypeBatchResponse {
+    results_dictionary: HashMap<Vec<u8>, ItemGetTypeResponse>,
+}
+
+// (Bytes key, ItemGetTypeResponse) pairs -- does NOT filter out Miss responses
+impl From<ItemGetTypeBatchResponse> for HashMap<Vec<u8>, ItemGetTypeResponse> {
+    fn from(response: ItemGetTypeBatchResponse) -> Self {
+        response.results_dictionary
+    }
+}
+
+// (Bytes key, ItemType) pairs -- filters out Miss responses
+impl From<ItemGetTypeBatchResponse> for HashMap<Vec<u8>, ItemType> {
+    fn from(response: ItemGetTypeBatchResponse) -> Self {
+        response
+            .results_dictionary
+            .into_iter()
+            .filter_map(|(key, response)| match response {
+                ItemGetTypeResponse::Hit { key_type } => Some((key, key_type)),
+                ItemGetTypeResponse::Miss => None,
+            })
+            .collect()
+    }
+}
+
+// (String key, ItemGetTypeResponse) pairs -- does NOT filter out Miss responses
+impl TryFrom<ItemGetTypeBatchResponse> for HashMap<String, ItemGetTypeResponse> {
+    type Error = MomentoError;
+
+    fn try_from(response: ItemGetTypeBatchResponse) -> Result<Self, Self::Error> {
+        response
+            .results_dictionary
+            .into_iter()
+            .map(|(key, response)| parse_string(key).map(|key| (key, response)))
+            .collect()
+    }
+}
+
+// (String key, ItemType) pairs -- filters out Miss responses
+impl TryFrom<ItemGetTypeBatchResponse> for HashMap<String, ItemType> {
+    type Error = MomentoError;
+
+    fn try_from(response: ItemGetTypeBatchResponse) -> Result<Self, Self::Error> {
+        HashMap::<Vec<u8>, ItemType>::from(response)
+            .into_iter()
+            .map(|(key, key_type)| parse_string(key).map(|key| (key, key_type)))
+            .collect()
+    }
+}
@@ -1,6 +1,8 @@
 use crate::cache::MomentoRequest;
-use crate::utils::prep_request_with_timeout;
-use crate::{CacheClient, IntoBytes, MomentoError, MomentoResult};
+use crate::utils::{prep_request_with_timeout, record_key_length};
+use crate::{CacheClient, ErrorContext, IntoBytes, MomentoError, MomentoResult};
+use derive_more::Display;
+use serde::Serialize;
 
 /// Request to check if a key exists in a cache.
 ///
@@ -50,18 +52,28 @@ impl<K: IntoBytes> KeyExistsRequest<K> {
 impl<K: IntoBytes> MomentoRequest for KeyExistsRequest<K> {
     type Response = KeyExistsResponse;
 
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new("KeyExists", self.cache_name.clone()))
+    }
+
     async fn send(self, cache_client: &CacheClient) -> MomentoResult<KeyExistsResponse> {
+        let key = self.key.into_bytes();
+        record_key_length(&key);
+
         let request = prep_request_with_timeout(
             &self.cache_name,
             cache_client.deadline_millis(),
             momento_protos::cache_client::KeysExistRequest {
-                cache_keys: vec![self.key.into_bytes()],
+                cache_keys: vec![key],
             },
         )?;
 
         let response = cache_client
-            .next_data_client()
-            .keys_exist(request)
+            .send_with_retries(
+                request,
+                Self::is_idempotent(),
+                |mut client, request| async move { client.keys_exist(request).await },
+            )
             .await?
             .into_inner();
 
@@ -76,7 +88,7 @@ impl<K: IntoBytes> MomentoRequest for KeyExistsRequest<K> {
 }
 
 /// Response for a key exists operation.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Display, Serialize, PartialEq, Eq)]
 pub struct KeyExistsResponse {
     /// True if the key exists in the cache.
     pub exists: bool,
@@ -2,9 +2,9 @@ use std::collections::HashMap;
 
 use crate::cache::MomentoRequest;
 use crate::utils::parse_string;
-use crate::utils::prep_request_with_timeout;
+use crate::utils::{is_cache_name_valid, prep_request_with_timeout};
 use crate::IntoBytesIterable;
-use crate::{CacheClient, MomentoResult};
+use crate::{CacheClient, ErrorContext, MomentoResult};
 
 /// Request to check if the provided keys exist in the cache.
 /// Returns an object that is accessible as a list or map of booleans indicating whether each given key was found in the cache.
@@ -13,6 +13,9 @@ use crate::{CacheClient, MomentoResult};
 /// * `cache_name` - name of cache
 /// * `keys` - list of keys to check for existence
 ///
+/// An empty batch returns an empty response without making a network call, even if the cache
+/// does not exist. The cache name is still validated.
+///
 /// # Examples
 /// Assumes that a CacheClient named `cache_client` has been created and is available.
 ///
@@ -75,9 +78,20 @@ impl<K: IntoBytesIterable> KeysExistRequest<K> {
 impl<K: IntoBytesIterable> MomentoRequest for KeysExistRequest<K> {
     type Response = KeysExistResponse;
 
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new("KeysExist", self.cache_name.clone()))
+    }
+
     async fn send(self, cache_client: &CacheClient) -> MomentoResult<KeysExistResponse> {
         // consume self.keys once to convert all keys to bytes
         let byte_keys: Vec<Vec<u8>> = self.keys.into_bytes();
+        if byte_keys.is_empty() {
+            is_cache_name_valid(&self.cache_name)?;
+            return Ok(KeysExistResponse {
+                exists: vec![],
+                exists_dictionary: HashMap::new(),
+            });
+        }
 
         // convert keys to strings for the response exists_dictionary because HashMap<IntoBytes, bool> is not allowed
         let string_keys: Vec<String> = byte_keys
@@ -94,8 +108,11 @@ impl<K: IntoBytesIterable> MomentoRequest for KeysExistRequest<K> {
         )?;
 
         let response = cache_client
-            .next_data_client()
-            .keys_exist(request)
+            .send_with_retries(
+                request,
+                Self::is_idempotent(),
+                |mut client, request| async move { client.keys_exist(request).await },
+            )
             .await?
             .into_inner();
 
@@ -6,6 +6,10 @@ pub mod delete;
 pub mod get;
 /// Contains the request and response types for getting a batch of items from a cache.
 pub mod get_batch;
+/// Contains the request type for getting an item from a cache and resetting its time-to-live.
+pub mod get_sliding;
+/// Contains the request and response types for getting an item from a cache along with its time-to-live.
+pub mod get_with_metadata;
 /// Contains the request and response types for increasing the time-to-live of an item in a cache.
 pub mod increase_ttl;
 /// Contains the request and response types for incrementing the value of an item in a cache.
@@ -14,6 +18,8 @@ pub mod increment;
 pub mod item_get_ttl;
 /// Contains the request and response types for getting the type of an item in a cache.
 pub mod item_get_type;
+/// Contains the request and response types for getting the types of a batch of items in a cache.
+pub mod item_get_type_batch;
 /// Contains the request and response types for checking if an item exists in a cache.
 pub mod key_exists;
 /// Contains the request and response types for checking if multiple items exist in a cache.
@@ -24,6 +30,8 @@ pub mod set;
 pub mod set_batch;
 /// Contains the request and response types for setting an item in a cache if it is absent from the cache.
 pub mod set_if_absent;
+/// Contains the request and response types for setting a batch of items in a cache if they are absent from the cache.
+pub mod set_if_absent_batch;
 /// Contains the request and response types for setting an item in a cache if it is absent or equal to a given value.
 pub mod set_if_absent_or_equal;
 /// Contains the request and response types for setting an item in a cache if it is equal to a given value.
@@ -34,5 +42,7 @@ pub mod set_if_not_equal;
 pub mod set_if_present;
 /// Contains the request and response types for setting an item in a cache if it is present and not equal to a given value.
 pub mod set_if_present_and_not_equal;
+/// Contains the request and response types for refreshing the time-to-live of an item in a cache when it is below a threshold.
+pub mod touch_ttl_if_below;
 /// Contains the request and response types for overwriting the time-to-live of an item in a cache.
 pub mod update_ttl;
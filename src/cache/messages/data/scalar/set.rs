@@ -1,8 +1,12 @@
+use derive_more::Display;
 use momento_protos::cache_client::ECacheResult;
+use serde::Serialize;
 
-use crate::cache::messages::MomentoRequest;
-use crate::utils::prep_request_with_timeout;
+use crate::auth::ExpiresAt;
+use crate::cache::messages::{MomentoRawRequest, MomentoRequest};
+use crate::utils::{expires_at_to_ttl, prep_request_with_timeout, record_key_length};
 use crate::{CacheClient, MomentoError};
+use crate::{ErrorContext, ErrorSource, MomentoErrorCode};
 use crate::{IntoBytes, MomentoResult};
 use std::time::Duration;
 
@@ -17,6 +21,8 @@ use std::time::Duration;
 /// # Optional Arguments
 ///
 /// * `ttl` - The time-to-live for the item. If not provided, the client's default time-to-live is used.
+/// * `expires_at` - An absolute expiry time for the item. The remaining time-to-live is computed
+///   when the request is sent and takes precedence over `ttl`.
 ///
 /// # Example
 /// Assumes that a CacheClient named `cache_client` has been created and is available.
@@ -52,6 +58,7 @@ pub struct SetRequest<K: IntoBytes, V: IntoBytes> {
     key: K,
     value: V,
     ttl: Option<Duration>,
+    expires_at: Option<ExpiresAt>,
 }
 
 impl<K: IntoBytes, V: IntoBytes> SetRequest<K, V> {
@@ -63,6 +70,7 @@ impl<K: IntoBytes, V: IntoBytes> SetRequest<K, V> {
             key,
             value,
             ttl,
+            expires_at: None,
         }
     }
 
@@ -71,37 +79,158 @@ impl<K: IntoBytes, V: IntoBytes> SetRequest<K, V> {
         self.ttl = ttl.into();
         self
     }
+
+    /// Set an absolute expiry time for the item. The remaining time-to-live is computed
+    /// when the request is sent, and the request fails if the expiry time has already passed.
+    pub fn expires_at(mut self, expires_at: impl Into<Option<ExpiresAt>>) -> Self {
+        self.expires_at = expires_at.into();
+        self
+    }
+}
+
+impl<K: IntoBytes> SetRequest<K, Vec<u8>> {
+    /// Construct a new SetRequest whose value is `value` serialized as JSON.
+    ///
+    /// Returns an `InvalidArgumentError` if `value` cannot be serialized.
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use momento_test_util::create_doctest_cache_client;
+    /// # tokio_test::block_on(async {
+    /// use std::collections::HashMap;
+    /// use momento::cache::SetRequest;
+    /// # let (cache_client, cache_name) = create_doctest_cache_client();
+    ///
+    /// let scores = HashMap::from([("alice", 42)]);
+    /// let set_request = SetRequest::from_json(&cache_name, "scores", &scores)?;
+    /// cache_client.send_request(set_request).await?;
+    /// # let stored: HashMap<String, u32> = cache_client.get(&cache_name, "scores").await?.into_json()?;
+    /// # assert_eq!(stored.get("alice"), Some(&42));
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    pub fn from_json<T: Serialize + ?Sized>(
+        cache_name: impl Into<String>,
+        key: K,
+        value: &T,
+    ) -> MomentoResult<Self> {
+        let value = serde_json::to_vec(value).map_err(|e| MomentoError {
+            message: "value could not be serialized as JSON".to_string(),
+            error_code: MomentoErrorCode::InvalidArgumentError,
+            inner_error: Some(ErrorSource::Unknown(Box::new(e))),
+            details: None,
+            context: None,
+        })?;
+        Ok(Self::new(cache_name, key, value))
+    }
+}
+
+impl<K: IntoBytes> SetRequest<K, String> {
+    /// Construct a new SetRequest whose value is `value` in decimal, the format used by
+    /// [increment](CacheClient::increment) and
+    /// [dictionary_increment](CacheClient::dictionary_increment). The stored value can be
+    /// incremented, and read back with [GetResponse::as_i64](crate::cache::GetResponse::as_i64).
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use momento_test_util::create_doctest_cache_client;
+    /// # tokio_test::block_on(async {
+    /// use momento::cache::SetRequest;
+    /// # let (cache_client, cache_name) = create_doctest_cache_client();
+    ///
+    /// cache_client.send_request(SetRequest::new_i64(&cache_name, "page-views", 41)).await?;
+    /// let incremented = cache_client.increment(&cache_name, "page-views", 1).await?;
+    /// assert_eq!(incremented.value, 42);
+    ///
+    /// let page_views = cache_client.get(&cache_name, "page-views").await?.as_i64()?;
+    /// assert_eq!(page_views, 42);
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    pub fn new_i64(cache_name: impl Into<String>, key: K, value: i64) -> Self {
+        Self::new(cache_name, key, value.to_string())
+    }
+
+    /// Construct a new SetRequest whose value is `value` in decimal, with as many digits as
+    /// needed to read back the same number with
+    /// [GetResponse::as_f64](crate::cache::GetResponse::as_f64). The service can only increment
+    /// integers, so a value with a fractional part cannot be incremented.
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use momento_test_util::create_doctest_cache_client;
+    /// # tokio_test::block_on(async {
+    /// use momento::cache::SetRequest;
+    /// # let (cache_client, cache_name) = create_doctest_cache_client();
+    ///
+    /// cache_client.send_request(SetRequest::new_f64(&cache_name, "ratio", 0.75)).await?;
+    /// let ratio = cache_client.get(&cache_name, "ratio").await?.as_f64()?;
+    /// assert_eq!(ratio, 0.75);
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    pub fn new_f64(cache_name: impl Into<String>, key: K, value: f64) -> Self {
+        Self::new(cache_name, key, value.to_string())
+    }
 }
 
 impl<K: IntoBytes, V: IntoBytes> MomentoRequest for SetRequest<K, V> {
     type Response = SetResponse;
 
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new("Set", self.cache_name.clone()))
+    }
+
     async fn send(self, cache_client: &CacheClient) -> MomentoResult<SetResponse> {
+        self.send_raw(cache_client)
+            .await
+            .map(|(response, _raw_response)| response)
+    }
+}
+
+impl<K: IntoBytes, V: IntoBytes> MomentoRawRequest for SetRequest<K, V> {
+    type RawResponse = momento_protos::cache_client::SetResponse;
+
+    async fn send_raw(
+        self,
+        cache_client: &CacheClient,
+    ) -> MomentoResult<(SetResponse, Self::RawResponse)> {
+        let ttl = match &self.expires_at {
+            Some(expires_at) => Some(expires_at_to_ttl(expires_at)?),
+            None => self.ttl,
+        };
+        let key = self.key.into_bytes();
+        record_key_length(&key);
+
         let request = prep_request_with_timeout(
             &self.cache_name,
             cache_client.deadline_millis(),
             momento_protos::cache_client::SetRequest {
-                cache_key: self.key.into_bytes(),
+                cache_key: key,
                 cache_body: self.value.into_bytes(),
-                ttl_milliseconds: cache_client.expand_ttl_ms(self.ttl)?,
+                ttl_milliseconds: cache_client.expand_ttl_ms(ttl)?,
             },
         )?;
 
-        let response = cache_client
-            .next_data_client()
-            .set(request)
+        let raw_response = cache_client
+            .send_with_retries(
+                request,
+                Self::is_idempotent(),
+                |mut client, request| async move { client.set(request).await },
+            )
             .await?
             .into_inner();
-        match response.result() {
-            ECacheResult::Ok => Ok(SetResponse {}),
+        match raw_response.result() {
+            ECacheResult::Ok => Ok((SetResponse {}, raw_response)),
             _ => Err(MomentoError::unknown_error(
                 "Set",
-                Some(format!("{:#?}", response)),
+                Some(format!("{:#?}", raw_response)),
             )),
         }
     }
 }
 
 /// The response type for a successful set request.
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, Display, Serialize, PartialEq, Eq, Clone)]
+#[display(fmt = "SetResponse")]
 pub struct SetResponse {}
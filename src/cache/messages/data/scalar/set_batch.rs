@@ -2,13 +2,15 @@ use std::collections::HashMap;
 use std::convert::TryFrom;
 use std::time::Duration;
 
+use futures::future::BoxFuture;
+use futures::{future, Future, FutureExt, StreamExt, TryStreamExt};
 use momento_protos::cache_client::ECacheResult;
 
 use crate::cache::MomentoRequest;
-use crate::utils::{parse_string, prep_request_with_timeout};
-use crate::{CacheClient, IntoBytes, MomentoError, MomentoResult};
+use crate::utils::{bounded_fan_out, is_cache_name_valid, parse_string, prep_request_with_timeout};
+use crate::{CacheClient, ErrorContext, IntoBytes, MomentoError, MomentoResult};
 
-use crate::cache::messages::data::scalar::set::SetResponse;
+use crate::cache::messages::data::scalar::set::{SetRequest, SetResponse};
 
 /// Request to set a batch of items in a cache.
 ///
@@ -17,9 +19,17 @@ use crate::cache::messages::data::scalar::set::SetResponse;
 /// * `cache_name` - name of the cache
 /// * `items` - HashMap of (key, value) pairs to set
 ///
+/// An empty batch returns an empty response without making a network call, even if the cache
+/// does not exist. The cache name is still validated.
+///
 /// # Optional Arguments
 ///
 /// * `ttl` - The time-to-live for the items. If not provided, the client's default time-to-live is used.
+/// * `max_concurrent_requests` - Send the items as individual set requests, at most this many at
+///   a time, instead of in a single batch request.
+///
+/// To give items their own time-to-live, construct the request with
+/// [with_item_ttls](SetBatchRequest::with_item_ttls). Items without one use the batch's `ttl`.
 ///
 /// # Example
 /// Assumes that a CacheClient named `cache_client` has been created and is available.
@@ -53,17 +63,56 @@ use crate::cache::messages::data::scalar::set::SetResponse;
 /// ```
 pub struct SetBatchRequest<K: IntoBytes, V: IntoBytes> {
     cache_name: String,
-    items: Vec<(K, V)>,
+    items: Vec<(K, V, Option<Duration>)>,
     ttl: Option<Duration>,
+    max_concurrent_requests: Option<usize>,
+    cancellation: Option<BoxFuture<'static, ()>>,
 }
 
 impl<K: IntoBytes, V: IntoBytes> SetBatchRequest<K, V> {
     /// Construct a new SetBatchRequest.
     pub fn new(cache_name: impl Into<String>, items: impl IntoIterator<Item = (K, V)>) -> Self {
+        Self::with_item_ttls(
+            cache_name,
+            items.into_iter().map(|(key, value)| (key, value, None)),
+        )
+    }
+
+    /// Construct a new SetBatchRequest where each item may have its own time-to-live. Items
+    /// whose time-to-live is `None` use the batch's `ttl`, or the client's default time-to-live
+    /// if that is not set either.
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use momento_test_util::create_doctest_cache_client;
+    /// # tokio_test::block_on(async {
+    /// use std::time::Duration;
+    /// use momento::cache::SetBatchRequest;
+    /// # let (cache_client, cache_name) = create_doctest_cache_client();
+    ///
+    /// let set_batch_request = SetBatchRequest::with_item_ttls(
+    ///     &cache_name,
+    ///     vec![
+    ///         ("session", "abc", Some(Duration::from_secs(30))),
+    ///         ("profile", "def", Some(Duration::from_secs(3600))),
+    ///         ("settings", "ghi", None),
+    ///     ],
+    /// );
+    /// cache_client.send_request(set_batch_request).await?;
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    pub fn with_item_ttls(
+        cache_name: impl Into<String>,
+        items: impl IntoIterator<Item = (K, V, Option<Duration>)>,
+    ) -> Self {
         Self {
             cache_name: cache_name.into(),
             items: items.into_iter().collect(),
             ttl: None,
+            max_concurrent_requests: None,
+            cancellation: None,
         }
     }
 
@@ -72,22 +121,87 @@ impl<K: IntoBytes, V: IntoBytes> SetBatchRequest<K, V> {
         self.ttl = ttl.into();
         self
     }
+
+    /// Send the items as individual set requests, with at most `max_concurrent_requests` of them
+    /// in flight at once, instead of in a single batch request. Use this to limit the load a
+    /// large batch puts on the connection. Values below 1 are treated as 1.
+    pub fn max_concurrent_requests(mut self, max_concurrent_requests: usize) -> Self {
+        self.max_concurrent_requests = Some(max_concurrent_requests.max(1));
+        self
+    }
+
+    /// Stops receiving results when `signal` completes. The response then holds only the
+    /// results received so far and [SetBatchResponse::is_cancelled] returns true.
+    ///
+    /// Cancelling does not undo the writes whose results were not received. Unless
+    /// [max_concurrent_requests](Self::max_concurrent_requests) is set, the items are sent to
+    /// the server in a single request, so it does not prevent them either.
+    pub fn cancel_on(mut self, signal: impl Future<Output = ()> + Send + 'static) -> Self {
+        self.cancellation = Some(signal.boxed());
+        self
+    }
 }
 
 impl<K: IntoBytes, V: IntoBytes> MomentoRequest for SetBatchRequest<K, V> {
     type Response = SetBatchResponse;
 
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new("SetBatch", self.cache_name.clone()))
+    }
+
+    fn is_composite(&self) -> bool {
+        self.max_concurrent_requests.is_some()
+    }
+
     async fn send(self, cache_client: &CacheClient) -> MomentoResult<SetBatchResponse> {
+        if self.items.is_empty() {
+            is_cache_name_valid(&self.cache_name)?;
+            return Ok(SetBatchResponse {
+                results_dictionary: HashMap::new(),
+                cancelled: false,
+            });
+        }
+
+        let cancellation = self
+            .cancellation
+            .unwrap_or_else(|| future::pending().boxed());
+        if let Some(max_concurrent_requests) = self.max_concurrent_requests {
+            is_cache_name_valid(&self.cache_name)?;
+            let cache_name = &self.cache_name;
+            let ttl = self.ttl;
+            let sets = self.items.into_iter().map(|(key, value, item_ttl)| {
+                let key = key.into_bytes();
+                let request = SetRequest::new(cache_name, key.clone(), value).ttl(item_ttl.or(ttl));
+                async move {
+                    cache_client
+                        .send_sub_request(request)
+                        .await
+                        .map(|response| (key, response))
+                }
+            });
+            let mut results =
+                bounded_fan_out(sets, max_concurrent_requests).take_until(cancellation);
+
+            let mut responses: HashMap<Vec<u8>, SetResponse> = HashMap::new();
+            while let Some((key, response)) = results.try_next().await? {
+                responses.insert(key, response);
+            }
+            return Ok(SetBatchResponse {
+                results_dictionary: responses,
+                cancelled: results.is_stopped(),
+            });
+        }
+
         // Turn map of items into a vector of keys and vector of SetRequest objects
         // so we can map keys to the correct SetResponse objects later
         let mut set_requests: Vec<momento_protos::cache_client::SetRequest> = vec![];
         let mut set_request_keys: Vec<Vec<u8>> = vec![];
-        for (key, value) in self.items.into_iter() {
+        for (key, value, item_ttl) in self.items.into_iter() {
             let byte_key = key.into_bytes();
             let set_request = momento_protos::cache_client::SetRequest {
                 cache_key: byte_key.clone(),
                 cache_body: value.into_bytes(),
-                ttl_milliseconds: cache_client.expand_ttl_ms(self.ttl)?,
+                ttl_milliseconds: cache_client.expand_ttl_ms(item_ttl.or(self.ttl))?,
             };
             set_requests.push(set_request);
             set_request_keys.push(byte_key);
@@ -102,15 +216,19 @@ impl<K: IntoBytes, V: IntoBytes> MomentoRequest for SetBatchRequest<K, V> {
         )?;
 
         let mut response_stream = cache_client
-            .next_data_client()
-            .set_batch(set_batch_request)
+            .send_with_retries(
+                set_batch_request,
+                Self::is_idempotent(),
+                |mut client, request| async move { client.set_batch(request).await },
+            )
             .await?
-            .into_inner();
+            .into_inner()
+            .take_until(cancellation);
 
-        // receive stream of set responses
+        // receive stream of set responses until it ends or the request is cancelled
         let mut responses: HashMap<Vec<u8>, SetResponse> = HashMap::new();
         let mut set_request_keys_iter = set_request_keys.into_iter();
-        while let Some(set_response) = response_stream.message().await? {
+        while let Some(set_response) = response_stream.try_next().await? {
             let sdk_set_response = match set_response.result() {
                 ECacheResult::Ok => SetResponse {},
                 _ => {
@@ -134,6 +252,7 @@ impl<K: IntoBytes, V: IntoBytes> MomentoRequest for SetBatchRequest<K, V> {
 
         Ok(SetBatchResponse {
             results_dictionary: responses,
+            cancelled: response_stream.is_stopped(),
         })
     }
 }
@@ -165,6 +284,15 @@ impl<K: IntoBytes, V: IntoBytes> MomentoRequest for SetBatchRequest<K, V> {
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct SetBatchResponse {
     results_dictionary: HashMap<Vec<u8>, SetResponse>,
+    cancelled: bool,
+}
+
+impl SetBatchResponse {
+    /// Returns true if the request was cancelled with [SetBatchRequest::cancel_on] before all
+    /// results were received. The response then holds only the keys received before cancelling.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled
+    }
 }
 
 impl From<SetBatchResponse> for HashMap<Vec<u8>, SetResponse> {
@@ -1,9 +1,12 @@
+use derive_more::Display;
 use momento_protos::cache_client::set_if_request::Condition::Absent;
 use momento_protos::cache_client::set_if_response;
+use serde::Serialize;
 
 use crate::cache::messages::MomentoRequest;
-use crate::utils::prep_request_with_timeout;
+use crate::utils::{prep_request_with_timeout, record_key_length};
 use crate::CacheClient;
+use crate::ErrorContext;
 use crate::{IntoBytes, MomentoError, MomentoResult};
 use std::time::Duration;
 
@@ -80,12 +83,23 @@ impl<K: IntoBytes, V: IntoBytes> SetIfAbsentRequest<K, V> {
 impl<K: IntoBytes, V: IntoBytes> MomentoRequest for SetIfAbsentRequest<K, V> {
     type Response = SetIfAbsentResponse;
 
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new("SetIfAbsent", self.cache_name.clone()))
+    }
+
+    fn is_idempotent() -> bool {
+        false
+    }
+
     async fn send(self, cache_client: &CacheClient) -> MomentoResult<SetIfAbsentResponse> {
+        let key = self.key.into_bytes();
+        record_key_length(&key);
+
         let request = prep_request_with_timeout(
             &self.cache_name,
             cache_client.deadline_millis(),
             momento_protos::cache_client::SetIfRequest {
-                cache_key: self.key.into_bytes(),
+                cache_key: key,
                 cache_body: self.value.into_bytes(),
                 ttl_milliseconds: cache_client.expand_ttl_ms(self.ttl)?,
                 condition: Some(Absent(momento_protos::common::Absent {})),
@@ -93,8 +107,11 @@ impl<K: IntoBytes, V: IntoBytes> MomentoRequest for SetIfAbsentRequest<K, V> {
         )?;
 
         let response = cache_client
-            .next_data_client()
-            .set_if(request)
+            .send_with_retries(
+                request,
+                Self::is_idempotent(),
+                |mut client, request| async move { client.set_if(request).await },
+            )
             .await?
             .into_inner();
         match response.result {
@@ -109,7 +126,7 @@ impl<K: IntoBytes, V: IntoBytes> MomentoRequest for SetIfAbsentRequest<K, V> {
 }
 
 /// Response for a set if absent request.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Display, Serialize, PartialEq, Eq)]
 pub enum SetIfAbsentResponse {
     /// The value was successfully stored.
     Stored,
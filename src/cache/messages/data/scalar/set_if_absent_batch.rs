@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::time::Duration;
+
+use futures::TryStreamExt;
+
+use crate::cache::messages::data::scalar::set_if_absent::{
+    SetIfAbsentRequest, SetIfAbsentResponse,
+};
+use crate::cache::messages::data::DEFAULT_BATCH_CONCURRENCY;
+use crate::cache::messages::MomentoRequest;
+use crate::utils::{bounded_fan_out, is_cache_name_valid, parse_string};
+use crate::{CacheClient, ErrorContext, IntoBytes, MomentoError, MomentoResult};
+
+/// Request to set a batch of items, each only if its key is not already present in the cache.
+///
+/// The server has no batched conditional set, so one SetIfAbsent request is sent per item, with
+/// at most `max_concurrent_requests` of them in flight at once. Each item is stored or not on its own; the batch as a whole is
+/// not atomic. If any request fails, the whole batch returns that error and the items not yet
+/// sent are not sent, but items whose requests had already succeeded stay stored. When the items are locks, give them a ttl so that locks
+/// acquired by a failed batch are released.
+///
+/// # Arguments
+///
+/// * `cache_name` - name of cache
+/// * `items` - the keys and values to set
+///
+/// # Optional Arguments
+///
+/// * `ttl` - The time-to-live for the items. If not provided, the client's default time-to-live is used.
+/// * `max_concurrent_requests` - The maximum number of requests in flight at once. Defaults to
+///   [DEFAULT_BATCH_CONCURRENCY](crate::cache::DEFAULT_BATCH_CONCURRENCY).
+///
+/// # Examples
+/// Assumes that a CacheClient named `cache_client` has been created and is available.
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// # use momento_test_util::create_doctest_cache_client;
+/// # tokio_test::block_on(async {
+/// use std::collections::HashMap;
+/// use std::convert::TryInto;
+/// use std::time::Duration;
+/// use momento::cache::{SetIfAbsentBatchRequest, SetIfAbsentResponse};
+/// # let (cache_client, cache_name) = create_doctest_cache_client();
+/// # cache_client.set(&cache_name, "lock:resource-2", "another-worker").await?;
+///
+/// let request = SetIfAbsentBatchRequest::new(
+///     &cache_name,
+///     vec![("lock:resource-1", "worker-1"), ("lock:resource-2", "worker-1")]
+/// ).ttl(Duration::from_secs(30));
+///
+/// let results: HashMap<String, SetIfAbsentResponse> =
+///     cache_client.send_request(request).await?.try_into()?;
+/// # assert_eq!(results.get("lock:resource-2"), Some(&SetIfAbsentResponse::NotStored));
+/// for (lock, result) in results {
+///     if result == SetIfAbsentResponse::Stored {
+///         println!("Acquired {}", lock);
+///     }
+/// }
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+pub struct SetIfAbsentBatchRequest<K: IntoBytes, V: IntoBytes> {
+    cache_name: String,
+    items: Vec<(K, V)>,
+    ttl: Option<Duration>,
+    max_concurrent_requests: usize,
+}
+
+impl<K: IntoBytes, V: IntoBytes> SetIfAbsentBatchRequest<K, V> {
+    /// Construct a new SetIfAbsentBatchRequest.
+    pub fn new(cache_name: impl Into<String>, items: impl IntoIterator<Item = (K, V)>) -> Self {
+        Self {
+            cache_name: cache_name.into(),
+            items: items.into_iter().collect(),
+            ttl: None,
+            max_concurrent_requests: DEFAULT_BATCH_CONCURRENCY,
+        }
+    }
+
+    /// Set the time-to-live for the items.
+    pub fn ttl(mut self, ttl: impl Into<Option<Duration>>) -> Self {
+        self.ttl = ttl.into();
+        self
+    }
+
+    /// Set the maximum number of requests in flight at once. Values below 1 are treated as 1.
+    pub fn max_concurrent_requests(mut self, max_concurrent_requests: usize) -> Self {
+        self.max_concurrent_requests = max_concurrent_requests.max(1);
+        self
+    }
+}
+
+impl<K: IntoBytes, V: IntoBytes> MomentoRequest for SetIfAbsentBatchRequest<K, V> {
+    type Response = SetIfAbsentBatchResponse;
+
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new(
+            "SetIfAbsentBatch",
+            self.cache_name.clone(),
+        ))
+    }
+
+    fn is_composite(&self) -> bool {
+        true
+    }
+
+    async fn send(self, cache_client: &CacheClient) -> MomentoResult<SetIfAbsentBatchResponse> {
+        is_cache_name_valid(&self.cache_name)?;
+
+        let cache_name = &self.cache_name;
+        let ttl = self.ttl;
+        let sets = self.items.into_iter().map(|(key, value)| {
+            let key = key.into_bytes();
+            let request = SetIfAbsentRequest::new(cache_name, key.clone(), value).ttl(ttl);
+            async move {
+                cache_client
+                    .send_sub_request(request)
+                    .await
+                    .map(|response| (key, response))
+            }
+        });
+        let mut results = bounded_fan_out(sets, self.max_concurrent_requests);
+
+        let mut responses: HashMap<Vec<u8>, SetIfAbsentResponse> = HashMap::new();
+        while let Some((key, response)) = results.try_next().await? {
+            responses.insert(key, response);
+        }
+        Ok(SetIfAbsentBatchResponse {
+            results_dictionary: responses,
+        })
+    }
+}
+
+/// Response for a set if absent batch operation.
+///
+/// Maps each key to whether its item was stored. You can use `into()` to convert it into a
+/// `HashMap<Vec<u8>, SetIfAbsentResponse>` and `try_into()` to convert it into a
+/// `HashMap<String, SetIfAbsentResponse>`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct SetIfAbsentBatchResponse {
+    results_dictionary: HashMap<Vec<u8>, SetIfAbsentResponse>,
+}
+
+impl SetIfAbsentBatchResponse {
+    /// Returns the keys whose items were stored because they were absent.
+    pub fn stored_keys(&self) -> Vec<&[u8]> {
+        self.keys_with(SetIfAbsentResponse::Stored)
+    }
+
+    /// Returns the keys whose items were not stored because the key was already present.
+    pub fn not_stored_keys(&self) -> Vec<&[u8]> {
+        self.keys_with(SetIfAbsentResponse::NotStored)
+    }
+
+    fn keys_with(&self, result: SetIfAbsentResponse) -> Vec<&[u8]> {
+        self.results_dictionary
+            .iter()
+            .filter(|(_, response)| **response == result)
+            .map(|(key, _)| key.as_slice())
+            .collect()
+    }
+}
+
+impl From<SetIfAbsentBatchResponse> for HashMap<Vec<u8>, SetIfAbsentResponse> {
+    fn from(response: SetIfAbsentBatchResponse) -> Self {
+        response.results_dictionary
+    }
+}
+
+impl TryFrom<SetIfAbsentBatchResponse> for HashMap<String, SetIfAbsentResponse> {
+    type Error = MomentoError;
+
+    fn try_from(response: SetIfAbsentBatchResponse) -> Result<Self, Self::Error> {
+        response
+            .results_dictionary
+            .into_iter()
+            .map(|(key, response)| parse_string(key).map(|key| (key, response)))
+            .collect()
+    }
+}
@@ -1,10 +1,12 @@
+use derive_more::Display;
 use momento_protos::cache_client::set_if_request::Condition::AbsentOrEqual;
 use momento_protos::cache_client::set_if_response;
+use serde::Serialize;
 
 use crate::cache::messages::MomentoRequest;
-use crate::utils::prep_request_with_timeout;
+use crate::utils::{prep_request_with_timeout, record_key_length};
 use crate::CacheClient;
-use crate::{IntoBytes, MomentoError, MomentoResult};
+use crate::{ErrorContext, IntoBytes, MomentoError, MomentoResult};
 use std::time::Duration;
 
 /// Request to associate the given key with the given value if the key does not already
@@ -87,12 +89,26 @@ impl<K: IntoBytes, V: IntoBytes, E: IntoBytes> MomentoRequest
 {
     type Response = SetIfAbsentOrEqualResponse;
 
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new(
+            "SetIfAbsentOrEqual",
+            self.cache_name.clone(),
+        ))
+    }
+
+    fn is_idempotent() -> bool {
+        false
+    }
+
     async fn send(self, cache_client: &CacheClient) -> MomentoResult<SetIfAbsentOrEqualResponse> {
+        let key = self.key.into_bytes();
+        record_key_length(&key);
+
         let request = prep_request_with_timeout(
             &self.cache_name,
             cache_client.deadline_millis(),
             momento_protos::cache_client::SetIfRequest {
-                cache_key: self.key.into_bytes(),
+                cache_key: key,
                 cache_body: self.value.into_bytes(),
                 ttl_milliseconds: cache_client.expand_ttl_ms(self.ttl)?,
                 condition: Some(AbsentOrEqual(momento_protos::common::AbsentOrEqual {
@@ -102,8 +118,11 @@ impl<K: IntoBytes, V: IntoBytes, E: IntoBytes> MomentoRequest
         )?;
 
         let response = cache_client
-            .next_data_client()
-            .set_if(request)
+            .send_with_retries(
+                request,
+                Self::is_idempotent(),
+                |mut client, request| async move { client.set_if(request).await },
+            )
             .await?
             .into_inner();
         match response.result {
@@ -120,7 +139,7 @@ impl<K: IntoBytes, V: IntoBytes, E: IntoBytes> MomentoRequest
 }
 
 /// Response for a set if absent or equal request.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Display, Serialize, PartialEq, Eq)]
 pub enum SetIfAbsentOrEqualResponse {
     /// The value was successfully stored.
     Stored,
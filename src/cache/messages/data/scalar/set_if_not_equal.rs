@@ -1,9 +1,12 @@
+use derive_more::Display;
 use momento_protos::cache_client::set_if_request::Condition::NotEqual;
 use momento_protos::cache_client::set_if_response;
+use serde::Serialize;
 
 use crate::cache::messages::MomentoRequest;
-use crate::utils::prep_request_with_timeout;
+use crate::utils::{prep_request_with_timeout, record_key_length};
 use crate::CacheClient;
+use crate::ErrorContext;
 use crate::{IntoBytes, MomentoError, MomentoResult};
 use std::time::Duration;
 
@@ -85,12 +88,23 @@ impl<K: IntoBytes, V: IntoBytes, E: IntoBytes> SetIfNotEqualRequest<K, V, E> {
 impl<K: IntoBytes, V: IntoBytes, E: IntoBytes> MomentoRequest for SetIfNotEqualRequest<K, V, E> {
     type Response = SetIfNotEqualResponse;
 
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new("SetIfNotEqual", self.cache_name.clone()))
+    }
+
+    fn is_idempotent() -> bool {
+        false
+    }
+
     async fn send(self, cache_client: &CacheClient) -> MomentoResult<SetIfNotEqualResponse> {
+        let key = self.key.into_bytes();
+        record_key_length(&key);
+
         let request = prep_request_with_timeout(
             &self.cache_name,
             cache_client.deadline_millis(),
             momento_protos::cache_client::SetIfRequest {
-                cache_key: self.key.into_bytes(),
+                cache_key: key,
                 cache_body: self.value.into_bytes(),
                 ttl_milliseconds: cache_client.expand_ttl_ms(self.ttl)?,
                 condition: Some(NotEqual(momento_protos::common::NotEqual {
@@ -100,8 +114,11 @@ impl<K: IntoBytes, V: IntoBytes, E: IntoBytes> MomentoRequest for SetIfNotEqualR
         )?;
 
         let response = cache_client
-            .next_data_client()
-            .set_if(request)
+            .send_with_retries(
+                request,
+                Self::is_idempotent(),
+                |mut client, request| async move { client.set_if(request).await },
+            )
             .await?
             .into_inner();
         match response.result {
@@ -116,7 +133,7 @@ impl<K: IntoBytes, V: IntoBytes, E: IntoBytes> MomentoRequest for SetIfNotEqualR
 }
 
 /// Response for a set if not equal operation
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Display, Serialize, PartialEq, Eq)]
 pub enum SetIfNotEqualResponse {
     /// The value was successfully stored.
     Stored,
@@ -0,0 +1,130 @@
+use std::time::Duration;
+
+use crate::cache::messages::data::scalar::increase_ttl::{IncreaseTtlRequest, IncreaseTtlResponse};
+use crate::cache::messages::data::scalar::item_get_ttl::{ItemGetTtlRequest, ItemGetTtlResponse};
+use crate::cache::MomentoRequest;
+use crate::{CacheClient, ErrorContext, IntoBytes, MomentoResult};
+
+/// Refresh the ttl of an item in the cache, but only if its remaining ttl has dropped below a
+/// threshold.
+///
+/// This suits sliding expiration, such as keeping a session alive while it is in use, without
+/// writing the ttl on every access. The remaining ttl is read first, so an item that does not
+/// need refreshing costs one round trip and one that does costs two.
+///
+/// The refresh only ever lengthens the ttl. If another client refreshes the item between the
+/// read and the refresh, the longer of the two ttls is kept.
+///
+/// # Arguments
+/// * `cache_name` - name of cache
+/// * `key` - the key of the item to refresh
+/// * `threshold` - refresh the item only if its remaining ttl is below this
+/// * `new_ttl` - the ttl to give the item when it is refreshed. Should be greater than `threshold`.
+///
+/// # Examples
+/// Assumes that a CacheClient named `cache_client` has been created and is available.
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// # use momento_test_util::create_doctest_cache_client;
+/// # tokio_test::block_on(async {
+/// # let (cache_client, cache_name) = create_doctest_cache_client();
+/// use std::time::Duration;
+/// use momento::cache::{TouchTtlIfBelowRequest, TouchTtlIfBelowResponse};
+/// # cache_client.set(&cache_name, "session", "data").await?;
+///
+/// let request = TouchTtlIfBelowRequest::new(
+///     &cache_name,
+///     "session",
+///     Duration::from_secs(300),
+///     Duration::from_secs(1800),
+/// );
+///
+/// match cache_client.send_request(request).await? {
+///     TouchTtlIfBelowResponse::Refreshed => println!("Session extended"),
+///     TouchTtlIfBelowResponse::NotRefreshed { remaining_ttl } => {
+///         println!("Session still has {:?} left", remaining_ttl)
+///     }
+///     TouchTtlIfBelowResponse::Miss => println!("Session expired"),
+/// };
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+pub struct TouchTtlIfBelowRequest<K: IntoBytes> {
+    cache_name: String,
+    key: K,
+    threshold: Duration,
+    new_ttl: Duration,
+}
+
+impl<K: IntoBytes> TouchTtlIfBelowRequest<K> {
+    /// Constructs a new TouchTtlIfBelowRequest.
+    pub fn new(
+        cache_name: impl Into<String>,
+        key: K,
+        threshold: Duration,
+        new_ttl: Duration,
+    ) -> Self {
+        Self {
+            cache_name: cache_name.into(),
+            key,
+            threshold,
+            new_ttl,
+        }
+    }
+}
+
+impl<K: IntoBytes> MomentoRequest for TouchTtlIfBelowRequest<K> {
+    type Response = TouchTtlIfBelowResponse;
+
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new(
+            "TouchTtlIfBelow",
+            self.cache_name.clone(),
+        ))
+    }
+
+    fn is_composite(&self) -> bool {
+        true
+    }
+
+    async fn send(self, cache_client: &CacheClient) -> MomentoResult<TouchTtlIfBelowResponse> {
+        let key = self.key.into_bytes();
+        let remaining_ttl = match cache_client
+            .send_sub_request(ItemGetTtlRequest::new(&self.cache_name, key.clone()))
+            .await?
+        {
+            ItemGetTtlResponse::Hit { remaining_ttl } => remaining_ttl,
+            ItemGetTtlResponse::Miss => return Ok(TouchTtlIfBelowResponse::Miss),
+        };
+        if remaining_ttl >= self.threshold {
+            return Ok(TouchTtlIfBelowResponse::NotRefreshed { remaining_ttl });
+        }
+
+        match cache_client
+            .send_sub_request(IncreaseTtlRequest::new(&self.cache_name, key, self.new_ttl))
+            .await?
+        {
+            IncreaseTtlResponse::Set => Ok(TouchTtlIfBelowResponse::Refreshed),
+            // Another client already gave the item a ttl at least as long as new_ttl
+            IncreaseTtlResponse::NotSet => {
+                Ok(TouchTtlIfBelowResponse::NotRefreshed { remaining_ttl })
+            }
+            IncreaseTtlResponse::Miss => Ok(TouchTtlIfBelowResponse::Miss),
+        }
+    }
+}
+
+/// Response for a touch ttl if below operation.
+#[derive(Debug, PartialEq, Eq)]
+pub enum TouchTtlIfBelowResponse {
+    /// The remaining ttl was below the threshold and the item was given the new ttl.
+    Refreshed,
+    /// The ttl was left unchanged.
+    NotRefreshed {
+        /// The remaining ttl of the item when it was read.
+        remaining_ttl: Duration,
+    },
+    /// The item was not found in the cache.
+    Miss,
+}
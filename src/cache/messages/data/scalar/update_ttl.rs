@@ -1,18 +1,25 @@
 use std::time::Duration;
 
+use derive_more::Display;
 use momento_protos::cache_client::update_ttl_request::UpdateTtl::OverwriteToMilliseconds;
 use momento_protos::cache_client::update_ttl_response::{self};
+use serde::Serialize;
 
 use crate::MomentoError;
 use crate::{
-    cache::MomentoRequest, utils::prep_request_with_timeout, CacheClient, IntoBytes, MomentoResult,
+    cache::MomentoRequest,
+    utils::{prep_request_with_timeout, record_key_length},
+    CacheClient, ErrorContext, IntoBytes, MomentoResult,
 };
 
 /// Update the ttl of an item in the cache.
 ///
+/// This also works on a collection when given its name as the key. Only an item that exists is
+/// updated, so the request never recreates a collection that has expired or been emptied.
+///
 /// # Arguments
 /// * `cache_name` - name of cache
-/// * `key` - the key of the item for which ttl is requested
+/// * `key` - the key of the item, or the name of the collection, for which ttl is updated
 /// * `ttl` - The time-to-live that should overwrite the current ttl.
 ///
 /// # Examples
@@ -56,12 +63,19 @@ impl<K: IntoBytes> UpdateTtlRequest<K> {
 impl<K: IntoBytes> MomentoRequest for UpdateTtlRequest<K> {
     type Response = UpdateTtlResponse;
 
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new("UpdateTtl", self.cache_name.clone()))
+    }
+
     async fn send(self, cache_client: &CacheClient) -> MomentoResult<UpdateTtlResponse> {
+        let key = self.key.into_bytes();
+        record_key_length(&key);
+
         let request = prep_request_with_timeout(
             &self.cache_name,
             cache_client.deadline_millis(),
             momento_protos::cache_client::UpdateTtlRequest {
-                cache_key: self.key.into_bytes(),
+                cache_key: key,
                 update_ttl: Some(OverwriteToMilliseconds(
                     cache_client.expand_ttl_ms(Some(self.ttl))?,
                 )),
@@ -69,8 +83,11 @@ impl<K: IntoBytes> MomentoRequest for UpdateTtlRequest<K> {
         )?;
 
         let response = cache_client
-            .next_data_client()
-            .update_ttl(request)
+            .send_with_retries(
+                request,
+                Self::is_idempotent(),
+                |mut client, request| async move { client.update_ttl(request).await },
+            )
             .await?
             .into_inner();
 
@@ -86,7 +103,11 @@ impl<K: IntoBytes> MomentoRequest for UpdateTtlRequest<K> {
 }
 
 /// Response for an update ttl operation.
-#[derive(Debug, PartialEq, Eq)]
+///
+/// The server does not report the ttl the item had before the update. To log it, read the
+/// remaining ttl with [CacheClient::item_get_ttl](crate::CacheClient::item_get_ttl) first. The
+/// two calls are not atomic, so the ttl may change in between.
+#[derive(Debug, Display, Serialize, PartialEq, Eq)]
 pub enum UpdateTtlResponse {
     /// The ttl was successfully updated.
     Set,
@@ -4,6 +4,7 @@ use crate::cache::messages::MomentoRequest;
 use crate::cache::CollectionTtl;
 use crate::utils::prep_request_with_timeout;
 use crate::CacheClient;
+use crate::ErrorContext;
 use crate::{IntoBytes, IntoBytesIterable, MomentoResult};
 
 /// Request to add elements to the given set. Creates the set if it does not exist.
@@ -66,15 +67,23 @@ impl<S: IntoBytes, E: IntoBytesIterable> SetAddElementsRequest<S, E> {
         self.collection_ttl = collection_ttl.into();
         self
     }
+
+    collection_ttl_shortcuts!("set");
 }
 
 impl<S: IntoBytes, E: IntoBytesIterable> MomentoRequest for SetAddElementsRequest<S, E> {
     type Response = SetAddElementsResponse;
 
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new("SetAddElements", self.cache_name.clone()))
+    }
+
     async fn send(self, cache_client: &CacheClient) -> MomentoResult<SetAddElementsResponse> {
+        let error_context = self.error_context();
         let collection_ttl = self.collection_ttl.unwrap_or_default();
         let elements = self.elements.into_bytes();
         let set_name = self.set_name.into_bytes();
+        let error_context = error_context.map(|context| context.with_collection_name(&set_name));
         let cache_name = &self.cache_name;
         let request = prep_request_with_timeout(
             cache_name,
@@ -87,7 +96,14 @@ impl<S: IntoBytes, E: IntoBytesIterable> MomentoRequest for SetAddElementsReques
             },
         )?;
 
-        let _ = cache_client.next_data_client().set_union(request).await?;
+        let _ = cache_client
+            .send_with_retries(
+                request,
+                Self::is_idempotent(),
+                |mut client, request| async move { client.set_union(request).await },
+            )
+            .await
+            .map_err(|e| e.with_context(error_context))?;
         Ok(SetAddElementsResponse {})
     }
 }
@@ -95,3 +111,44 @@ impl<S: IntoBytes, E: IntoBytesIterable> MomentoRequest for SetAddElementsReques
 /// The response type for a successful set add elements request.
 #[derive(Debug, PartialEq, Eq)]
 pub struct SetAddElementsResponse {}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn ttl_settings(collection_ttl: Option<CollectionTtl>) -> (Option<Duration>, bool) {
+        let collection_ttl = collection_ttl.unwrap_or_default();
+        (collection_ttl.ttl(), collection_ttl.refresh())
+    }
+
+    #[test]
+    fn ttl_shortcuts_set_the_collection_ttl() {
+        let ttl = Duration::from_secs(60);
+        assert_eq!(
+            ttl_settings(
+                SetAddElementsRequest::new("cache", "set", vec!["element"])
+                    .ttl_with_refresh(ttl)
+                    .collection_ttl
+            ),
+            (Some(ttl), true)
+        );
+        assert_eq!(
+            ttl_settings(
+                SetAddElementsRequest::new("cache", "set", vec!["element"])
+                    .ttl_no_refresh(ttl)
+                    .collection_ttl
+            ),
+            (Some(ttl), false)
+        );
+        assert_eq!(
+            ttl_settings(
+                SetAddElementsRequest::new("cache", "set", vec!["element"])
+                    .keep_ttl()
+                    .collection_ttl
+            ),
+            (None, false)
+        );
+    }
+}
@@ -5,7 +5,7 @@ use momento_protos::cache_client::set_fetch_response;
 use crate::{
     cache::MomentoRequest,
     utils::{parse_string, prep_request_with_timeout},
-    CacheClient, IntoBytes, IntoBytesIterable, MomentoError, MomentoResult,
+    CacheClient, ErrorContext, IntoBytes, IntoBytesIterable, MomentoError, MomentoResult,
 };
 
 /// Fetch the elements in the given set.
@@ -54,19 +54,36 @@ impl<S: IntoBytes> SetFetchRequest<S> {
 impl<S: IntoBytes> MomentoRequest for SetFetchRequest<S> {
     type Response = SetFetchResponse;
 
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new("SetFetch", self.cache_name.clone()))
+    }
+
+    fn is_hit(response: &SetFetchResponse) -> Option<bool> {
+        Some(matches!(response, SetFetchResponse::Hit { .. }))
+    }
+
+    fn miss() -> Option<SetFetchResponse> {
+        Some(SetFetchResponse::Miss)
+    }
+
     async fn send(self, cache_client: &CacheClient) -> MomentoResult<SetFetchResponse> {
+        let error_context = self.error_context();
+        let set_name = self.set_name.into_bytes();
+        let error_context = error_context.map(|context| context.with_collection_name(&set_name));
         let request = prep_request_with_timeout(
             &self.cache_name,
             cache_client.deadline_millis(),
-            momento_protos::cache_client::SetFetchRequest {
-                set_name: self.set_name.into_bytes(),
-            },
+            momento_protos::cache_client::SetFetchRequest { set_name },
         )?;
 
         let response = cache_client
-            .next_data_client()
-            .set_fetch(request)
-            .await?
+            .send_with_retries(
+                request,
+                Self::is_idempotent(),
+                |mut client, request| async move { client.set_fetch(request).await },
+            )
+            .await
+            .map_err(|e| e.with_context(error_context))?
             .into_inner();
 
         match response.set {
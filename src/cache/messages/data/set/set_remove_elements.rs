@@ -7,7 +7,8 @@ use momento_protos::cache_client::{
 };
 
 use crate::{
-    cache::MomentoRequest, utils::prep_request_with_timeout, CacheClient, IntoBytes, MomentoResult,
+    cache::MomentoRequest, utils::prep_request_with_timeout, CacheClient, ErrorContext, IntoBytes,
+    MomentoResult,
 };
 
 /// Removes multiple elements from an existing set. If the set is emptied as a result, the set is deleted.
@@ -59,9 +60,18 @@ impl<S: IntoBytes, E: IntoBytes> SetRemoveElementsRequest<S, E> {
 impl<S: IntoBytes, E: IntoBytes> MomentoRequest for SetRemoveElementsRequest<S, E> {
     type Response = SetRemoveElementsResponse;
 
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new(
+            "SetRemoveElements",
+            self.cache_name.clone(),
+        ))
+    }
+
     async fn send(self, cache_client: &CacheClient) -> MomentoResult<SetRemoveElementsResponse> {
+        let error_context = self.error_context();
         let elements = self.elements.into_iter().map(|e| e.into_bytes()).collect();
         let set_name = self.set_name.into_bytes();
+        let error_context = error_context.map(|context| context.with_collection_name(&set_name));
         let cache_name = &self.cache_name;
         let request = prep_request_with_timeout(
             cache_name,
@@ -75,9 +85,13 @@ impl<S: IntoBytes, E: IntoBytes> MomentoRequest for SetRemoveElementsRequest<S,
         )?;
 
         let _ = cache_client
-            .next_data_client()
-            .set_difference(request)
-            .await?;
+            .send_with_retries(
+                request,
+                Self::is_idempotent(),
+                |mut client, request| async move { client.set_difference(request).await },
+            )
+            .await
+            .map_err(|e| e.with_context(error_context))?;
         Ok(SetRemoveElementsResponse {})
     }
 }
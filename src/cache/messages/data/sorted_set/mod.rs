@@ -1,7 +1,11 @@
+/// Contains the request and response types for copying a sorted set to a new name.
+pub mod sorted_set_copy;
 /// Contains the request and response types for fetching elements from a sorted set.
 pub mod sorted_set_fetch_by_rank;
 /// Contains the request and response types for fetching elements from a sorted set.
 pub mod sorted_set_fetch_by_score;
+/// Contains the request and response types for fetching the highest and lowest scored elements of a sorted set.
+pub mod sorted_set_fetch_extremes;
 /// Contains the request and response types for fetching elements from a sorted set.
 pub mod sorted_set_fetch_response;
 /// Contains the request and response types for getting the rank of an element in a sorted set.
@@ -12,11 +16,19 @@ pub mod sorted_set_get_score;
 pub mod sorted_set_get_scores;
 /// Contains the request and response types for incrementing a score for an element from a sorted set.
 pub mod sorted_set_increment_score;
+/// Contains the request and response types for incrementing the score of an element in a sorted set and getting its new rank.
+pub mod sorted_set_increment_score_and_get_rank;
+/// Contains the request and response types for incrementing the scores of several elements in a sorted set.
+pub mod sorted_set_increment_scores;
 /// Contains the request and response types for getting the length of a sorted set.
 pub mod sorted_set_length;
+/// Contains the request and response types for counting the elements of a sorted set within a score range.
+pub mod sorted_set_length_by_score;
 /// Contains the request and response types for adding an element to a sorted set.
 pub mod sorted_set_put_element;
 /// Contains the request and response types for adding elements to a sorted set.
 pub mod sorted_set_put_elements;
 /// Contains the request and response types for removing multiple elements from a sorted set.
 pub mod sorted_set_remove_elements;
+/// Contains the request type for computing the union of sorted sets without storing it.
+pub mod sorted_set_union;
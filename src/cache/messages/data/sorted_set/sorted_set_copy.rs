@@ -0,0 +1,130 @@
+use std::time::Duration;
+
+use crate::cache::messages::data::scalar::delete::DeleteRequest;
+use crate::cache::messages::data::sorted_set::sorted_set_fetch_by_rank::SortedSetFetchByRankRequest;
+use crate::cache::messages::data::sorted_set::sorted_set_fetch_response::SortedSetFetchResponse;
+use crate::cache::messages::data::sorted_set::sorted_set_put_elements::SortedSetPutElementsRequest;
+use crate::cache::{CollectionTtl, MomentoRequest};
+use crate::utils::is_cache_name_valid;
+use crate::{CacheClient, ErrorContext, IntoBytes, MomentoResult};
+
+/// Copy a sorted set to a new name, replacing anything stored under the destination name.
+///
+/// The service has no copy operation, so the sorted set is fetched and then written to the
+/// destination, passing through the client. The copy is not atomic: changes made to the source
+/// while it is being copied may or may not be included, and the destination is deleted before it
+/// is written, so readers can briefly find it missing. If the write fails after the delete, the
+/// destination is left missing.
+///
+/// # Arguments
+///
+/// * `cache_name` - The name of the cache containing the sorted set.
+/// * `source_name` - The name of the sorted set to copy.
+/// * `destination_name` - The name to copy the sorted set to.
+///
+/// # Optional Arguments
+///
+/// * `ttl` - The time-to-live of the copy. If not provided, the client's default time-to-live is
+///   used. The source's remaining time-to-live is not copied.
+///
+/// # Examples
+/// Assumes that a CacheClient named `cache_client` has been created and is available.
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// # use momento_test_util::create_doctest_cache_client;
+/// # tokio_test::block_on(async {
+/// use std::time::Duration;
+/// use momento::cache::{SortedSetCopyRequest, SortedSetCopyResponse};
+/// # let (cache_client, cache_name) = create_doctest_cache_client();
+/// # cache_client.sorted_set_put_elements(&cache_name, "scores", vec![("alice", 1.0), ("bob", 2.0)]).await?;
+///
+/// let request = SortedSetCopyRequest::new(&cache_name, "scores", "scores-snapshot")
+///     .ttl(Duration::from_secs(3600));
+///
+/// match cache_client.send_request(request).await? {
+///     SortedSetCopyResponse::Copied { count } => println!("Copied {} elements", count),
+///     SortedSetCopyResponse::Miss => println!("Nothing to copy"),
+/// };
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+pub struct SortedSetCopyRequest<S: IntoBytes, D: IntoBytes> {
+    cache_name: String,
+    source_name: S,
+    destination_name: D,
+    ttl: Option<Duration>,
+}
+
+impl<S: IntoBytes, D: IntoBytes> SortedSetCopyRequest<S, D> {
+    /// Constructs a new SortedSetCopyRequest.
+    pub fn new(cache_name: impl Into<String>, source_name: S, destination_name: D) -> Self {
+        Self {
+            cache_name: cache_name.into(),
+            source_name,
+            destination_name,
+            ttl: None,
+        }
+    }
+
+    /// Set the time-to-live of the copy.
+    pub fn ttl(mut self, ttl: impl Into<Option<Duration>>) -> Self {
+        self.ttl = ttl.into();
+        self
+    }
+}
+
+impl<S: IntoBytes, D: IntoBytes> MomentoRequest for SortedSetCopyRequest<S, D> {
+    type Response = SortedSetCopyResponse;
+
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new("SortedSetCopy", self.cache_name.clone()))
+    }
+
+    fn is_composite(&self) -> bool {
+        true
+    }
+
+    async fn send(self, cache_client: &CacheClient) -> MomentoResult<SortedSetCopyResponse> {
+        is_cache_name_valid(&self.cache_name)?;
+
+        let elements = match cache_client
+            .send_sub_request(SortedSetFetchByRankRequest::new(
+                &self.cache_name,
+                self.source_name,
+            ))
+            .await?
+        {
+            SortedSetFetchResponse::Hit { value } => value.elements,
+            SortedSetFetchResponse::Miss => return Ok(SortedSetCopyResponse::Miss),
+        };
+        let count = elements.len();
+
+        let destination_name = self.destination_name.into_bytes();
+        cache_client
+            .send_sub_request(DeleteRequest::new(
+                &self.cache_name,
+                destination_name.clone(),
+            ))
+            .await?;
+        cache_client
+            .send_sub_request(
+                SortedSetPutElementsRequest::new(&self.cache_name, destination_name, elements)
+                    .ttl(CollectionTtl::refresh_on_update(self.ttl)),
+            )
+            .await?;
+        Ok(SortedSetCopyResponse::Copied { count })
+    }
+}
+
+/// Response for a sorted set copy operation.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SortedSetCopyResponse {
+    /// The sorted set was copied.
+    Copied {
+        /// The number of elements copied.
+        count: usize,
+    },
+    /// The source sorted set was not found. The destination was left unchanged.
+    Miss,
+}
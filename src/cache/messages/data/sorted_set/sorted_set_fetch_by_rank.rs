@@ -5,6 +5,7 @@ use momento_protos::common::Unbounded;
 use crate::cache::messages::data::sorted_set::sorted_set_fetch_response::SortedSetFetchResponse;
 use crate::cache::messages::MomentoRequest;
 use crate::utils::prep_request_with_timeout;
+use crate::ErrorContext;
 use crate::{CacheClient, IntoBytes, MomentoResult};
 
 /// The order with which to sort the elements by score in the sorted set.
@@ -13,7 +14,8 @@ use crate::{CacheClient, IntoBytes, MomentoResult};
 #[repr(i32)]
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum SortedSetOrder {
-    /// Scores are ordered from low to high. This is the default order.
+    /// Scores are ordered from low to high. This is the default order unless a different one
+    /// is configured on the client.
     Ascending = 0,
     /// Scores are ordered from high to low.
     Descending = 1,
@@ -29,13 +31,16 @@ pub enum SortedSetOrder {
 /// # Optional Arguments
 ///
 /// * `order` - The order to sort the elements by. [SortedSetOrder::Ascending] or [SortedSetOrder::Descending].
-///   Defaults to Ascending.
+///   Defaults to the client's default sorted set order, which is Ascending unless configured otherwise.
 /// * `start_rank` - The rank of the first element to fetch. Defaults to 0. This rank is
 ///   inclusive, i.e. the element at this rank will be fetched.
 /// * `end_rank` - The rank of the last element to fetch. This rank is exclusive, i.e. the
 ///   element at this rank will not be fetched. Defaults to -1, which fetches up until and
 ///   including the last element.
 ///
+/// The fetched elements always include their scores, so a page of a sorted set can be read
+/// with both the value and score of each element in a single call.
+///
 /// # Examples
 /// Assumes that a CacheClient named `cache_client` has been created and is available.
 /// ```
@@ -72,7 +77,7 @@ pub struct SortedSetFetchByRankRequest<S: IntoBytes> {
     sorted_set_name: S,
     start_rank: Option<i32>,
     end_rank: Option<i32>,
-    order: SortedSetOrder,
+    order: Option<SortedSetOrder>,
 }
 
 impl<S: IntoBytes> SortedSetFetchByRankRequest<S> {
@@ -83,7 +88,7 @@ impl<S: IntoBytes> SortedSetFetchByRankRequest<S> {
             sorted_set_name,
             start_rank: None,
             end_rank: None,
-            order: SortedSetOrder::Ascending,
+            order: None,
         }
     }
 
@@ -101,7 +106,7 @@ impl<S: IntoBytes> SortedSetFetchByRankRequest<S> {
 
     /// Set the order of the request.
     pub fn order(mut self, order: impl Into<Option<SortedSetOrder>>) -> Self {
-        self.order = order.into().unwrap_or(SortedSetOrder::Ascending);
+        self.order = order.into();
         self
     }
 }
@@ -109,8 +114,25 @@ impl<S: IntoBytes> SortedSetFetchByRankRequest<S> {
 impl<S: IntoBytes> MomentoRequest for SortedSetFetchByRankRequest<S> {
     type Response = SortedSetFetchResponse;
 
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new(
+            "SortedSetFetchByRank",
+            self.cache_name.clone(),
+        ))
+    }
+
+    fn is_hit(response: &SortedSetFetchResponse) -> Option<bool> {
+        Some(matches!(response, SortedSetFetchResponse::Hit { .. }))
+    }
+
+    fn miss() -> Option<SortedSetFetchResponse> {
+        Some(SortedSetFetchResponse::Miss)
+    }
+
     async fn send(self, cache_client: &CacheClient) -> MomentoResult<SortedSetFetchResponse> {
+        let error_context = self.error_context();
         let set_name = self.sorted_set_name.into_bytes();
+        let error_context = error_context.map(|context| context.with_collection_name(&set_name));
         let cache_name = &self.cache_name;
 
         let by_index = ByIndex {
@@ -131,16 +153,23 @@ impl<S: IntoBytes> MomentoRequest for SortedSetFetchByRankRequest<S> {
             cache_client.deadline_millis(),
             SortedSetFetchRequest {
                 set_name,
-                order: self.order as i32,
+                order: self
+                    .order
+                    .unwrap_or_else(|| cache_client.default_sorted_set_order())
+                    as i32,
                 with_scores: true,
                 range: Some(Range::ByIndex(by_index)),
             },
         )?;
 
         let response = cache_client
-            .next_data_client()
-            .sorted_set_fetch(request)
-            .await?
+            .send_with_retries(
+                request,
+                Self::is_idempotent(),
+                |mut client, request| async move { client.sorted_set_fetch(request).await },
+            )
+            .await
+            .map_err(|e| e.with_context(error_context))?
             .into_inner();
 
         SortedSetFetchResponse::from_fetch_response(response)
@@ -169,7 +198,7 @@ mod tests {
         );
         assert_eq!(request.start_rank, Some(1));
         assert_eq!(request.end_rank, Some(3));
-        assert_eq!(request.order, SortedSetOrder::Ascending);
+        assert_eq!(request.order, Some(SortedSetOrder::Ascending));
 
         // Test with Some values
         let request = SortedSetFetchByRankRequest::new(cache_name, sorted_set_name)
@@ -184,7 +213,7 @@ mod tests {
         );
         assert_eq!(request.start_rank, Some(2));
         assert_eq!(request.end_rank, Some(4));
-        assert_eq!(request.order, SortedSetOrder::Descending);
+        assert_eq!(request.order, Some(SortedSetOrder::Descending));
 
         // Test with None values
         let request = SortedSetFetchByRankRequest::new(cache_name, sorted_set_name)
@@ -199,6 +228,6 @@ mod tests {
         );
         assert_eq!(request.start_rank, None);
         assert_eq!(request.end_rank, None);
-        assert_eq!(request.order, SortedSetOrder::Ascending);
+        assert_eq!(request.order, None);
     }
 }
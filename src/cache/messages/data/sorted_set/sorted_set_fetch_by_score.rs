@@ -4,10 +4,10 @@ use momento_protos::cache_client::SortedSetFetchRequest;
 use momento_protos::common::Unbounded;
 
 use crate::cache::messages::data::sorted_set::sorted_set_fetch_by_rank::SortedSetOrder;
-use crate::cache::messages::data::sorted_set::sorted_set_fetch_by_rank::SortedSetOrder::Ascending;
 use crate::cache::messages::data::sorted_set::sorted_set_fetch_response::SortedSetFetchResponse;
 use crate::cache::messages::MomentoRequest;
 use crate::utils::prep_request_with_timeout;
+use crate::ErrorContext;
 use crate::{CacheClient, IntoBytes, MomentoResult};
 
 /// Fetch the elements in the given sorted set by their score.
@@ -20,7 +20,7 @@ use crate::{CacheClient, IntoBytes, MomentoResult};
 /// # Optional Arguments
 ///
 /// * `order` - The order to sort the elements by. [SortedSetOrder::Ascending] or [SortedSetOrder::Descending].
-///   Defaults to Ascending.
+///   Defaults to the client's default sorted set order, which is Ascending unless configured otherwise.
 /// * `min_score` - The minimum score (inclusive) of the elements to fetch. Defaults to negative
 ///   infinity.
 /// * `max_score` - The maximum score (inclusive) of the elements to fetch. Defaults to positive
@@ -66,7 +66,7 @@ pub struct SortedSetFetchByScoreRequest<S: IntoBytes> {
     sorted_set_name: S,
     min_score: Option<f64>,
     max_score: Option<f64>,
-    order: SortedSetOrder,
+    order: Option<SortedSetOrder>,
     offset: Option<u32>,
     count: Option<i32>,
 }
@@ -79,7 +79,7 @@ impl<S: IntoBytes> SortedSetFetchByScoreRequest<S> {
             sorted_set_name,
             min_score: None,
             max_score: None,
-            order: Ascending,
+            order: None,
             offset: None,
             count: None,
         }
@@ -99,7 +99,7 @@ impl<S: IntoBytes> SortedSetFetchByScoreRequest<S> {
 
     /// Set the order of the request.
     pub fn order(mut self, order: impl Into<Option<SortedSetOrder>>) -> Self {
-        self.order = order.into().unwrap_or(SortedSetOrder::Ascending);
+        self.order = order.into();
         self
     }
 
@@ -119,8 +119,25 @@ impl<S: IntoBytes> SortedSetFetchByScoreRequest<S> {
 impl<S: IntoBytes> MomentoRequest for SortedSetFetchByScoreRequest<S> {
     type Response = SortedSetFetchResponse;
 
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new(
+            "SortedSetFetchByScore",
+            self.cache_name.clone(),
+        ))
+    }
+
+    fn is_hit(response: &SortedSetFetchResponse) -> Option<bool> {
+        Some(matches!(response, SortedSetFetchResponse::Hit { .. }))
+    }
+
+    fn miss() -> Option<SortedSetFetchResponse> {
+        Some(SortedSetFetchResponse::Miss)
+    }
+
     async fn send(self, cache_client: &CacheClient) -> MomentoResult<SortedSetFetchResponse> {
+        let error_context = self.error_context();
         let set_name = self.sorted_set_name.into_bytes();
+        let error_context = error_context.map(|context| context.with_collection_name(&set_name));
         let cache_name = &self.cache_name;
 
         let by_score = ByScore {
@@ -153,16 +170,23 @@ impl<S: IntoBytes> MomentoRequest for SortedSetFetchByScoreRequest<S> {
             cache_client.deadline_millis(),
             SortedSetFetchRequest {
                 set_name,
-                order: self.order as i32,
+                order: self
+                    .order
+                    .unwrap_or_else(|| cache_client.default_sorted_set_order())
+                    as i32,
                 with_scores: true,
                 range: Some(Range::ByScore(by_score)),
             },
         )?;
 
         let response = cache_client
-            .next_data_client()
-            .sorted_set_fetch(request)
-            .await?
+            .send_with_retries(
+                request,
+                Self::is_idempotent(),
+                |mut client, request| async move { client.sorted_set_fetch(request).await },
+            )
+            .await
+            .map_err(|e| e.with_context(error_context))?
             .into_inner();
 
         SortedSetFetchResponse::from_fetch_response(response)
@@ -194,7 +218,7 @@ mod test {
         assert_eq!(fetch_request.sorted_set_name, sorted_set_name);
         assert_eq!(fetch_request.min_score, Some(2.0));
         assert_eq!(fetch_request.max_score, Some(3.0));
-        assert_eq!(fetch_request.order, SortedSetOrder::Ascending);
+        assert_eq!(fetch_request.order, Some(SortedSetOrder::Ascending));
         assert_eq!(fetch_request.offset, Some(1));
         assert_eq!(fetch_request.count, Some(2));
 
@@ -211,7 +235,7 @@ mod test {
         assert_eq!(fetch_request.sorted_set_name, sorted_set_name);
         assert_eq!(fetch_request.min_score, Some(2.0));
         assert_eq!(fetch_request.max_score, Some(3.0));
-        assert_eq!(fetch_request.order, SortedSetOrder::Ascending);
+        assert_eq!(fetch_request.order, Some(SortedSetOrder::Ascending));
         assert_eq!(fetch_request.offset, Some(1));
         assert_eq!(fetch_request.count, Some(2));
 
@@ -228,7 +252,7 @@ mod test {
         assert_eq!(fetch_request.sorted_set_name, sorted_set_name);
         assert_eq!(fetch_request.min_score, None);
         assert_eq!(fetch_request.max_score, None);
-        assert_eq!(fetch_request.order, SortedSetOrder::Ascending);
+        assert_eq!(fetch_request.order, Some(SortedSetOrder::Ascending));
         assert_eq!(fetch_request.offset, None);
         assert_eq!(fetch_request.count, None);
     }
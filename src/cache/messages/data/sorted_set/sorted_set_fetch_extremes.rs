@@ -0,0 +1,156 @@
+use std::convert::TryFrom;
+
+use futures::future::try_join;
+
+use crate::cache::messages::data::sorted_set::sorted_set_fetch_by_rank::{
+    SortedSetFetchByRankRequest, SortedSetOrder,
+};
+use crate::cache::messages::data::sorted_set::sorted_set_fetch_response::{
+    SortedSetElements, SortedSetFetchResponse,
+};
+use crate::cache::messages::MomentoRequest;
+use crate::utils::is_cache_name_valid;
+use crate::{CacheClient, ErrorContext, IntoBytes, MomentoError, MomentoErrorCode, MomentoResult};
+
+/// Request to fetch the highest and lowest scored elements of a sorted set together.
+///
+/// The two ends are fetched with concurrent by-rank requests, so the call takes about one round
+/// trip rather than two. The server has no single request for both ends, so an update that lands
+/// between the two fetches can be reflected in one end and not the other.
+///
+/// # Arguments
+///
+/// * `cache_name` - The name of the cache containing the sorted set.
+/// * `sorted_set_name` - The name of the sorted set to fetch from.
+/// * `top_n` - The number of highest scored elements to fetch. Must be greater than zero.
+/// * `bottom_n` - The number of lowest scored elements to fetch. Must be greater than zero.
+///
+/// The top elements are ordered from the highest score down and the bottom elements from the
+/// lowest score up. If the sorted set has fewer than `top_n + bottom_n` elements, the two ends
+/// overlap.
+///
+/// # Examples
+/// Assumes that a CacheClient named `cache_client` has been created and is available.
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// # use momento_test_util::create_doctest_cache_client;
+/// # tokio_test::block_on(async {
+/// use momento::cache::{SortedSetFetchExtremesRequest, SortedSetFetchExtremesResponse};
+/// # let (cache_client, cache_name) = create_doctest_cache_client();
+/// # cache_client.sorted_set_put_elements(&cache_name, "leaderboard", vec![("alice", 1.0), ("bob", 2.0), ("carol", 3.0)]).await?;
+///
+/// let request = SortedSetFetchExtremesRequest::new(&cache_name, "leaderboard", 1, 1);
+///
+/// match cache_client.send_request(request).await? {
+///     SortedSetFetchExtremesResponse::Hit { top, bottom } => {
+///         println!("Leader: {:?}", top.into_strings()?);
+///         println!("Laggard: {:?}", bottom.into_strings()?);
+///     }
+///     SortedSetFetchExtremesResponse::Miss => println!("Leaderboard does not exist"),
+/// }
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+pub struct SortedSetFetchExtremesRequest<S: IntoBytes> {
+    cache_name: String,
+    sorted_set_name: S,
+    top_n: u32,
+    bottom_n: u32,
+}
+
+impl<S: IntoBytes> SortedSetFetchExtremesRequest<S> {
+    /// Constructs a new SortedSetFetchExtremesRequest.
+    pub fn new(
+        cache_name: impl Into<String>,
+        sorted_set_name: S,
+        top_n: u32,
+        bottom_n: u32,
+    ) -> Self {
+        Self {
+            cache_name: cache_name.into(),
+            sorted_set_name,
+            top_n,
+            bottom_n,
+        }
+    }
+}
+
+impl<S: IntoBytes> MomentoRequest for SortedSetFetchExtremesRequest<S> {
+    type Response = SortedSetFetchExtremesResponse;
+
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new(
+            "SortedSetFetchExtremes",
+            self.cache_name.clone(),
+        ))
+    }
+
+    fn is_hit(response: &SortedSetFetchExtremesResponse) -> Option<bool> {
+        Some(matches!(
+            response,
+            SortedSetFetchExtremesResponse::Hit { .. }
+        ))
+    }
+
+    fn miss() -> Option<SortedSetFetchExtremesResponse> {
+        Some(SortedSetFetchExtremesResponse::Miss)
+    }
+
+    fn is_composite(&self) -> bool {
+        true
+    }
+
+    async fn send(
+        self,
+        cache_client: &CacheClient,
+    ) -> MomentoResult<SortedSetFetchExtremesResponse> {
+        is_cache_name_valid(&self.cache_name)?;
+        if self.top_n == 0 || self.bottom_n == 0 {
+            return Err(MomentoError {
+                message: "top_n and bottom_n must be greater than zero".into(),
+                error_code: MomentoErrorCode::InvalidArgumentError,
+                inner_error: None,
+                details: None,
+                context: None,
+            });
+        }
+
+        let sorted_set_name = self.sorted_set_name.into_bytes();
+        let top = SortedSetFetchByRankRequest::new(&self.cache_name, sorted_set_name.clone())
+            .order(SortedSetOrder::Descending)
+            .start_rank(0)
+            .end_rank(i32::try_from(self.top_n).unwrap_or(i32::MAX));
+        let bottom = SortedSetFetchByRankRequest::new(&self.cache_name, sorted_set_name)
+            .order(SortedSetOrder::Ascending)
+            .start_rank(0)
+            .end_rank(i32::try_from(self.bottom_n).unwrap_or(i32::MAX));
+
+        let responses = try_join(
+            cache_client.send_sub_request(top),
+            cache_client.send_sub_request(bottom),
+        )
+        .await?;
+        match responses {
+            (
+                SortedSetFetchResponse::Hit { value: top },
+                SortedSetFetchResponse::Hit { value: bottom },
+            ) => Ok(SortedSetFetchExtremesResponse::Hit { top, bottom }),
+            _ => Ok(SortedSetFetchExtremesResponse::Miss),
+        }
+    }
+}
+
+/// Response for a [SortedSetFetchExtremesRequest].
+#[derive(Debug, PartialEq)]
+pub enum SortedSetFetchExtremesResponse {
+    /// The sorted set was found.
+    Hit {
+        /// The highest scored elements, from the highest score down.
+        top: SortedSetElements,
+        /// The lowest scored elements, from the lowest score up.
+        bottom: SortedSetElements,
+    },
+    /// The sorted set was not found.
+    Miss,
+}
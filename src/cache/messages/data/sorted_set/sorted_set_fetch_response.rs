@@ -1,9 +1,13 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
 use std::convert::{TryFrom, TryInto};
 
 use momento_protos::cache_client::sorted_set_fetch_response::found::Elements;
 use momento_protos::cache_client::sorted_set_fetch_response::SortedSet;
 use momento_protos::cache_client::SortedSetFetchResponse as ProtoSortedSetFetchResponse;
 
+use crate::cache::SortedSetElement;
+use crate::utils::parse_string;
 use crate::{ErrorSource, MomentoError, MomentoErrorCode, MomentoResult};
 
 /// Response object for a [SortedSetFetchByScoreRequest](crate::cache::SortedSetFetchByScoreRequest) or a [SortedSetFetchByRankRequest](crate::cache::SortedSetFetchByRankRequest).
@@ -56,6 +60,26 @@ use crate::{ErrorSource, MomentoError, MomentoErrorCode, MomentoResult};
 /// use std::convert::TryInto;
 /// let item: MomentoResult<Vec<(Vec<u8>, f64)>> = fetch_response.try_into();
 /// ```
+///
+/// Fetched elements always include their scores, so you can also convert the response into
+/// a `Vec<SortedSetElement<String>>` to access each value and score by name:
+/// ```
+/// # use momento::cache::{SortedSetFetchResponse, SortedSetElements, SortedSetElement};
+/// # use momento::MomentoResult;
+/// # let fetch_response = SortedSetFetchResponse::Hit { value: SortedSetElements::default() };
+/// use std::convert::TryInto;
+/// let item: MomentoResult<Vec<SortedSetElement<String>>> = fetch_response.try_into();
+/// ```
+///
+/// To keep only the highest-scoring elements of a fetched range, use
+/// [top_k](SortedSetFetchResponse::top_k) before converting:
+/// ```
+/// # use momento::cache::{SortedSetFetchResponse, SortedSetElements};
+/// # use momento::MomentoResult;
+/// # let fetch_response = SortedSetFetchResponse::Hit { value: SortedSetElements::default() };
+/// use std::convert::TryInto;
+/// let top_ten: MomentoResult<Vec<(String, f64)>> = fetch_response.top_k(10).try_into();
+/// ```
 #[derive(Debug, PartialEq)]
 pub enum SortedSetFetchResponse {
     /// The sorted set was found.
@@ -101,6 +125,7 @@ impl SortedSetFetchResponse {
                             .into(),
                         )),
                         details: None,
+                        context: None,
                     }),
                 },
             },
@@ -110,6 +135,17 @@ impl SortedSetFetchResponse {
             )),
         }
     }
+
+    /// Keeps only the `k` elements with the highest scores, ordered as described in
+    /// [SortedSetElements::top_k]. A miss stays a miss.
+    pub fn top_k(self, k: usize) -> Self {
+        match self {
+            SortedSetFetchResponse::Hit { value } => SortedSetFetchResponse::Hit {
+                value: value.top_k(k),
+            },
+            SortedSetFetchResponse::Miss => SortedSetFetchResponse::Miss,
+        }
+    }
 }
 
 impl TryFrom<SortedSetFetchResponse> for Vec<(Vec<u8>, f64)> {
@@ -134,6 +170,28 @@ impl TryFrom<SortedSetFetchResponse> for Vec<(String, f64)> {
     }
 }
 
+impl TryFrom<SortedSetFetchResponse> for Vec<SortedSetElement<Vec<u8>>> {
+    type Error = MomentoError;
+
+    fn try_from(value: SortedSetFetchResponse) -> Result<Self, Self::Error> {
+        match value {
+            SortedSetFetchResponse::Hit { value: elements } => Ok(elements.into()),
+            SortedSetFetchResponse::Miss => Err(MomentoError::miss("SortedSetFetch")),
+        }
+    }
+}
+
+impl TryFrom<SortedSetFetchResponse> for Vec<SortedSetElement<String>> {
+    type Error = MomentoError;
+
+    fn try_from(value: SortedSetFetchResponse) -> Result<Self, Self::Error> {
+        match value {
+            SortedSetFetchResponse::Hit { value: elements } => elements.try_into(),
+            SortedSetFetchResponse::Miss => Err(MomentoError::miss("SortedSetFetch")),
+        }
+    }
+}
+
 impl From<Vec<(String, f64)>> for SortedSetFetchResponse {
     fn from(elements: Vec<(String, f64)>) -> Self {
         SortedSetFetchResponse::Hit {
@@ -174,14 +232,97 @@ impl SortedSetElements {
     pub fn is_empty(&self) -> bool {
         self.elements.is_empty()
     }
+
+    /// Keeps only the `k` elements with the highest scores, highest first.
+    ///
+    /// Elements with equal scores are ordered by value, compared as bytes, in descending order,
+    /// which is the order the server returns them in when fetching in descending order. Scores are
+    /// compared with [f64::total_cmp].
+    ///
+    /// This selects the elements with a heap of size `k` instead of sorting all of them, so it is
+    /// cheap to take a few elements from a large fetched range.
+    pub fn top_k(self, k: usize) -> Self {
+        if k == 0 {
+            return SortedSetElements::default();
+        }
+        let mut heap = BinaryHeap::with_capacity(k.min(self.elements.len()) + 1);
+        for (value, score) in self.elements {
+            heap.push(Reverse(ByScore { value, score }));
+            if heap.len() > k {
+                heap.pop();
+            }
+        }
+        // Sorting the reversed heap in ascending order puts the highest scores first
+        let elements = heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse(ByScore { value, score })| (value, score))
+            .collect();
+        SortedSetElements { elements }
+    }
+}
+
+/// Orders sorted set elements by score, then by value, as the server does.
+struct ByScore {
+    value: Vec<u8>,
+    score: f64,
+}
+
+impl Ord for ByScore {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.score
+            .total_cmp(&other.score)
+            .then_with(|| self.value.cmp(&other.value))
+    }
 }
 
+impl PartialOrd for ByScore {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for ByScore {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for ByScore {}
+
 impl From<SortedSetElements> for Vec<(Vec<u8>, f64)> {
     fn from(value: SortedSetElements) -> Self {
         value.elements
     }
 }
 
+impl From<SortedSetElements> for Vec<SortedSetElement<Vec<u8>>> {
+    fn from(value: SortedSetElements) -> Self {
+        value
+            .elements
+            .into_iter()
+            .map(|(value, score)| SortedSetElement { value, score })
+            .collect()
+    }
+}
+
+impl TryFrom<SortedSetElements> for Vec<SortedSetElement<String>> {
+    type Error = MomentoError;
+
+    fn try_from(value: SortedSetElements) -> Result<Self, Self::Error> {
+        value
+            .elements
+            .into_iter()
+            .map(|(value, score)| {
+                Ok(SortedSetElement {
+                    value: parse_string(value)?,
+                    score,
+                })
+            })
+            .collect()
+    }
+}
+
 impl TryFrom<SortedSetElements> for Vec<(String, f64)> {
     type Error = MomentoError;
 
@@ -198,6 +339,7 @@ impl TryFrom<SortedSetElements> for Vec<(String, f64)> {
                         error_code: MomentoErrorCode::TypeError,
                         inner_error: Some(ErrorSource::Unknown(Box::new(e))),
                         details: None,
+                        context: None,
                     });
                 }
             }
@@ -205,3 +347,52 @@ impl TryFrom<SortedSetElements> for Vec<(String, f64)> {
         Ok(result)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn elements(pairs: &[(&str, f64)]) -> SortedSetElements {
+        SortedSetElements::new(
+            pairs
+                .iter()
+                .map(|(value, score)| (value.as_bytes().to_vec(), *score))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn top_k_keeps_the_highest_scores_highest_first() -> MomentoResult<()> {
+        let fetched = elements(&[("a", 1.0), ("b", 5.0), ("c", -2.0), ("d", 3.0), ("e", 4.0)]);
+        assert_eq!(
+            fetched.top_k(3).into_strings()?,
+            vec![
+                ("b".to_string(), 5.0),
+                ("e".to_string(), 4.0),
+                ("d".to_string(), 3.0)
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn top_k_orders_ties_by_value_descending() -> MomentoResult<()> {
+        let fetched = elements(&[("a", 1.0), ("c", 1.0), ("b", 1.0), ("z", 0.0)]);
+        assert_eq!(
+            fetched.top_k(2).into_strings()?,
+            vec![("c".to_string(), 1.0), ("b".to_string(), 1.0)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn top_k_with_k_of_zero_or_larger_than_the_set() {
+        let fetched = elements(&[("a", 1.0), ("b", 2.0)]);
+        assert!(elements(&[("a", 1.0)]).top_k(0).is_empty());
+        assert_eq!(fetched.top_k(10), elements(&[("b", 2.0), ("a", 1.0)]));
+        assert_eq!(
+            SortedSetFetchResponse::Miss.top_k(10),
+            SortedSetFetchResponse::Miss
+        );
+    }
+}
@@ -3,8 +3,9 @@ use std::convert::TryFrom;
 use momento_protos::cache_client::{sorted_set_get_rank_response::Rank, ECacheResult};
 
 use crate::{
-    cache::MomentoRequest, utils::prep_request_with_timeout, CacheClient, IntoBytes, MomentoError,
-    MomentoResult,
+    cache::{MomentoRequest, SortedSetOrder},
+    utils::prep_request_with_timeout,
+    CacheClient, ErrorContext, IntoBytes, MomentoError, MomentoResult,
 };
 
 /// Get the rank (position) of a specific element in a sorted set.
@@ -14,6 +15,10 @@ use crate::{
 /// * `sorted_set_name` - name of the sorted set
 /// * `value` - the sorted set value to get the rank of
 ///
+/// # Optional Arguments
+/// * `order` - The order the rank is counted in. [SortedSetOrder::Ascending] ranks the lowest
+///   score 0 and [SortedSetOrder::Descending] ranks the highest score 0. Defaults to Ascending.
+///
 /// # Examples
 /// Assumes that a CacheClient named `cache_client` has been created and is available.
 /// ```
@@ -39,6 +44,7 @@ pub struct SortedSetGetRankRequest<L: IntoBytes, V: IntoBytes> {
     cache_name: String,
     sorted_set_name: L,
     value: V,
+    order: Option<SortedSetOrder>,
 }
 
 impl<L: IntoBytes, V: IntoBytes> SortedSetGetRankRequest<L, V> {
@@ -48,28 +54,50 @@ impl<L: IntoBytes, V: IntoBytes> SortedSetGetRankRequest<L, V> {
             cache_name: cache_name.into(),
             sorted_set_name,
             value,
+            order: None,
         }
     }
+
+    /// Set the order the rank is counted in.
+    pub fn order(mut self, order: impl Into<Option<SortedSetOrder>>) -> Self {
+        self.order = order.into();
+        self
+    }
 }
 
 impl<L: IntoBytes, V: IntoBytes> MomentoRequest for SortedSetGetRankRequest<L, V> {
     type Response = SortedSetGetRankResponse;
 
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new(
+            "SortedSetGetRank",
+            self.cache_name.clone(),
+        ))
+    }
+
     async fn send(self, cache_client: &CacheClient) -> MomentoResult<SortedSetGetRankResponse> {
+        let error_context = self.error_context();
+        let sorted_set_name = self.sorted_set_name.into_bytes();
+        let error_context =
+            error_context.map(|context| context.with_collection_name(&sorted_set_name));
         let request = prep_request_with_timeout(
             &self.cache_name,
             cache_client.deadline_millis(),
             momento_protos::cache_client::SortedSetGetRankRequest {
-                set_name: self.sorted_set_name.into_bytes(),
+                set_name: sorted_set_name,
                 value: self.value.into_bytes(),
-                order: 0,
+                order: self.order.unwrap_or(SortedSetOrder::Ascending) as i32,
             },
         )?;
 
         let response = cache_client
-            .next_data_client()
-            .sorted_set_get_rank(request)
-            .await?
+            .send_with_retries(
+                request,
+                Self::is_idempotent(),
+                |mut client, request| async move { client.sorted_set_get_rank(request).await },
+            )
+            .await
+            .map_err(|e| e.with_context(error_context))?
             .into_inner();
 
         match response.rank {
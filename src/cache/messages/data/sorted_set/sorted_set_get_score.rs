@@ -6,8 +6,8 @@ use momento_protos::cache_client::{
 };
 
 use crate::{
-    cache::MomentoRequest, utils::prep_request_with_timeout, CacheClient, IntoBytes, MomentoError,
-    MomentoResult,
+    cache::MomentoRequest, utils::prep_request_with_timeout, CacheClient, ErrorContext, IntoBytes,
+    MomentoError, MomentoResult,
 };
 
 /// Get the score of a specific element in a sorted set.
@@ -62,20 +62,35 @@ impl<L: IntoBytes, V: IntoBytes> SortedSetGetScoreRequest<L, V> {
 impl<L: IntoBytes, V: IntoBytes> MomentoRequest for SortedSetGetScoreRequest<L, V> {
     type Response = SortedSetGetScoreResponse;
 
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new(
+            "SortedSetGetScore",
+            self.cache_name.clone(),
+        ))
+    }
+
     async fn send(self, cache_client: &CacheClient) -> MomentoResult<SortedSetGetScoreResponse> {
+        let error_context = self.error_context();
+        let sorted_set_name = self.sorted_set_name.into_bytes();
+        let error_context =
+            error_context.map(|context| context.with_collection_name(&sorted_set_name));
         let request = prep_request_with_timeout(
             &self.cache_name,
             cache_client.deadline_millis(),
             momento_protos::cache_client::SortedSetGetScoreRequest {
-                set_name: self.sorted_set_name.into_bytes(),
+                set_name: sorted_set_name,
                 values: vec![self.value.into_bytes()],
             },
         )?;
 
         let response = cache_client
-            .next_data_client()
-            .sorted_set_get_score(request)
-            .await?
+            .send_with_retries(
+                request,
+                Self::is_idempotent(),
+                |mut client, request| async move { client.sorted_set_get_score(request).await },
+            )
+            .await
+            .map_err(|e| e.with_context(error_context))?
             .into_inner();
 
         match response.sorted_set {
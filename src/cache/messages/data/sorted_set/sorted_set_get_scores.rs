@@ -9,7 +9,7 @@ use crate::{cache::SortedSetGetScoreResponse, MomentoErrorCode};
 use crate::{
     cache::{MomentoRequest, SortedSetElement},
     utils::{parse_string, prep_request_with_timeout},
-    CacheClient, IntoBytes, IntoBytesIterable, MomentoError, MomentoResult,
+    CacheClient, ErrorContext, IntoBytes, IntoBytesIterable, MomentoError, MomentoResult,
 };
 
 /// Get the scores of specific elements in a sorted set.
@@ -66,23 +66,38 @@ impl<L: IntoBytes, V: IntoBytesIterable + Clone> MomentoRequest
 {
     type Response = SortedSetGetScoresResponse<V>;
 
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new(
+            "SortedSetGetScores",
+            self.cache_name.clone(),
+        ))
+    }
+
     async fn send(
         self,
         cache_client: &CacheClient,
     ) -> MomentoResult<SortedSetGetScoresResponse<V>> {
+        let error_context = self.error_context();
+        let sorted_set_name = self.sorted_set_name.into_bytes();
+        let error_context =
+            error_context.map(|context| context.with_collection_name(&sorted_set_name));
         let request = prep_request_with_timeout(
             &self.cache_name,
             cache_client.deadline_millis(),
             momento_protos::cache_client::SortedSetGetScoreRequest {
-                set_name: self.sorted_set_name.into_bytes(),
+                set_name: sorted_set_name,
                 values: self.values.clone().into_bytes(),
             },
         )?;
 
         let get_scores_response = cache_client
-            .next_data_client()
-            .sorted_set_get_score(request)
-            .await?
+            .send_with_retries(
+                request,
+                Self::is_idempotent(),
+                |mut client, request| async move { client.sorted_set_get_score(request).await },
+            )
+            .await
+            .map_err(|e| e.with_context(error_context))?
             .into_inner();
 
         match get_scores_response.sorted_set {
@@ -148,6 +163,7 @@ impl<F: IntoBytesIterable + Clone> TryFrom<SortedSetGetScoresResponse<F>>
                 error_code: MomentoErrorCode::Miss,
                 inner_error: None,
                 details: None,
+                context: None,
             }),
         }
     }
@@ -184,6 +200,7 @@ impl<F: IntoBytesIterable + Clone> TryFrom<SortedSetGetScoresResponse<F>>
                 error_code: MomentoErrorCode::Miss,
                 inner_error: None,
                 details: None,
+                context: None,
             }),
         }
     }
@@ -2,7 +2,8 @@ use momento_protos::cache_client::{SortedSetIncrementRequest, SortedSetIncrement
 
 use crate::cache::CollectionTtl;
 use crate::{
-    cache::MomentoRequest, utils::prep_request_with_timeout, CacheClient, IntoBytes, MomentoResult,
+    cache::MomentoRequest, utils::prep_request_with_timeout, CacheClient, ErrorContext, IntoBytes,
+    MomentoResult,
 };
 
 /// Increments the score of an element in a sorted set.
@@ -69,17 +70,32 @@ impl<S: IntoBytes, V: IntoBytes> SortedSetIncrementScoreRequest<S, V> {
         self.collection_ttl = collection_ttl.into();
         self
     }
+
+    collection_ttl_shortcuts!("sorted set");
 }
 
 impl<S: IntoBytes, V: IntoBytes> MomentoRequest for SortedSetIncrementScoreRequest<S, V> {
     type Response = SortedSetIncrementScoreResponse;
 
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new(
+            "SortedSetIncrementScore",
+            self.cache_name.clone(),
+        ))
+    }
+
+    fn is_idempotent() -> bool {
+        false
+    }
+
     async fn send(
         self,
         cache_client: &CacheClient,
     ) -> MomentoResult<SortedSetIncrementScoreResponse> {
+        let error_context = self.error_context();
         let collection_ttl = self.collection_ttl.unwrap_or_default();
         let set_name = self.sorted_set_name.into_bytes();
+        let error_context = error_context.map(|context| context.with_collection_name(&set_name));
         let cache_name = &self.cache_name;
         let value = self.value.into_bytes();
         let request = prep_request_with_timeout(
@@ -95,9 +111,13 @@ impl<S: IntoBytes, V: IntoBytes> MomentoRequest for SortedSetIncrementScoreReque
         )?;
 
         let response = cache_client
-            .next_data_client()
-            .sorted_set_increment(request)
-            .await?;
+            .send_with_retries(
+                request,
+                Self::is_idempotent(),
+                |mut client, request| async move { client.sorted_set_increment(request).await },
+            )
+            .await
+            .map_err(|e| e.with_context(error_context))?;
 
         let SortedSetIncrementResponse { score } = response.into_inner();
         Ok(SortedSetIncrementScoreResponse { score })
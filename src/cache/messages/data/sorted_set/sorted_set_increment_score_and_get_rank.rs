@@ -0,0 +1,156 @@
+use crate::cache::messages::data::sorted_set::sorted_set_get_rank::{
+    SortedSetGetRankRequest, SortedSetGetRankResponse,
+};
+use crate::cache::messages::data::sorted_set::sorted_set_increment_score::SortedSetIncrementScoreRequest;
+use crate::cache::{CollectionTtl, MomentoRequest, SortedSetOrder};
+use crate::{CacheClient, ErrorContext, IntoBytes, MomentoResult};
+
+/// Increments the score of an element in a sorted set and returns its new score and rank.
+/// Adds the element with the increment amount as its score if it does not exist. Creates the
+/// sorted set if it does not exist.
+///
+/// The server has no combined operation, so this sends a SortedSetIncrement request and then a
+/// SortedSetGetRank request. The two are not atomic: if other elements change in between, the
+/// rank reflects those changes as well. For a live leaderboard, that means the rank is as of
+/// just after the increment, not of the increment itself.
+///
+/// # Arguments
+///
+/// * `cache_name` - The name of the cache containing the sorted set.
+/// * `sorted_set_name` - The name of the sorted set.
+/// * `value` - The value of the element whose score to increment.
+/// * `amount` - The amount to add to the score.
+/// * `order` - The order the rank is counted in. [SortedSetOrder::Descending] ranks the highest
+///   score 0, as on a leaderboard.
+///
+/// # Optional Arguments
+///
+/// * `collection_ttl` - The time-to-live for the collection. If not provided, the client's default time-to-live is used.
+///
+/// # Examples
+/// Assumes that a CacheClient named `cache_client` has been created and is available.
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// # use momento_test_util::create_doctest_cache_client;
+/// # tokio_test::block_on(async {
+/// use momento::cache::{SortedSetIncrementScoreAndGetRankRequest, SortedSetOrder};
+/// # let (cache_client, cache_name) = create_doctest_cache_client();
+/// let leaderboard = "leaderboard";
+/// # cache_client.sorted_set_put_elements(&cache_name, leaderboard, vec![("alice", 10.0), ("bob", 20.0)]).await?;
+///
+/// let request = SortedSetIncrementScoreAndGetRankRequest::new(
+///     &cache_name,
+///     leaderboard,
+///     "alice",
+///     15.0,
+///     SortedSetOrder::Descending,
+/// );
+/// let response = cache_client.send_request(request).await?;
+/// # assert_eq!(response.score, 25.0);
+/// # assert_eq!(response.rank, Some(0));
+/// println!("alice now has {} points, rank {:?}", response.score, response.rank);
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+pub struct SortedSetIncrementScoreAndGetRankRequest<S: IntoBytes, V: IntoBytes> {
+    cache_name: String,
+    sorted_set_name: S,
+    value: V,
+    amount: f64,
+    order: SortedSetOrder,
+    collection_ttl: Option<CollectionTtl>,
+}
+
+impl<S: IntoBytes, V: IntoBytes> SortedSetIncrementScoreAndGetRankRequest<S, V> {
+    /// Constructs a new SortedSetIncrementScoreAndGetRankRequest.
+    pub fn new(
+        cache_name: impl Into<String>,
+        sorted_set_name: S,
+        value: V,
+        amount: f64,
+        order: SortedSetOrder,
+    ) -> Self {
+        let collection_ttl = CollectionTtl::default();
+        Self {
+            cache_name: cache_name.into(),
+            sorted_set_name,
+            value,
+            amount,
+            order,
+            collection_ttl: Some(collection_ttl),
+        }
+    }
+
+    /// Set the time-to-live for the collection.
+    pub fn ttl(mut self, collection_ttl: impl Into<Option<CollectionTtl>>) -> Self {
+        self.collection_ttl = collection_ttl.into();
+        self
+    }
+
+    collection_ttl_shortcuts!("sorted set");
+}
+
+impl<S: IntoBytes, V: IntoBytes> MomentoRequest for SortedSetIncrementScoreAndGetRankRequest<S, V> {
+    type Response = SortedSetIncrementScoreAndGetRankResponse;
+
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new(
+            "SortedSetIncrementScoreAndGetRank",
+            self.cache_name.clone(),
+        ))
+    }
+
+    fn is_idempotent() -> bool {
+        false
+    }
+
+    fn is_composite(&self) -> bool {
+        true
+    }
+
+    async fn send(
+        self,
+        cache_client: &CacheClient,
+    ) -> MomentoResult<SortedSetIncrementScoreAndGetRankResponse> {
+        let sorted_set_name = self.sorted_set_name.into_bytes();
+        let value = self.value.into_bytes();
+
+        let score = cache_client
+            .send_sub_request(
+                SortedSetIncrementScoreRequest::new(
+                    &self.cache_name,
+                    sorted_set_name.clone(),
+                    value.clone(),
+                    self.amount,
+                )
+                .ttl(self.collection_ttl),
+            )
+            .await?
+            .score;
+
+        let rank = match cache_client
+            .send_sub_request(
+                SortedSetGetRankRequest::new(&self.cache_name, sorted_set_name, value)
+                    .order(self.order),
+            )
+            .await?
+        {
+            SortedSetGetRankResponse::Hit { rank } => Some(rank),
+            // The element was removed after it was incremented
+            SortedSetGetRankResponse::Miss => None,
+        };
+
+        Ok(SortedSetIncrementScoreAndGetRankResponse { score, rank })
+    }
+}
+
+/// Response for a sorted set increment score and get rank operation.
+#[derive(Debug, PartialEq)]
+pub struct SortedSetIncrementScoreAndGetRankResponse {
+    /// The score of the element after the increment.
+    pub score: f64,
+    /// The rank of the element in the requested order, or `None` if another client removed the
+    /// element or the sorted set before its rank was read.
+    pub rank: Option<u64>,
+}
@@ -0,0 +1,176 @@
+use futures::StreamExt;
+use std::convert::TryFrom;
+use std::marker::PhantomData;
+
+use crate::cache::messages::data::sorted_set::sorted_set_increment_score::SortedSetIncrementScoreRequest;
+use crate::cache::messages::data::sorted_set::sorted_set_put_elements::IntoSortedSetElements;
+use crate::cache::{CollectionTtl, MomentoRequest};
+use crate::utils::is_cache_name_valid;
+use crate::{CacheClient, ErrorContext, IntoBytes, MomentoError, MomentoResult};
+
+/// The number of increments a [SortedSetIncrementScoresRequest] sends at once unless configured
+/// otherwise.
+pub const DEFAULT_INCREMENT_SCORES_CONCURRENCY: usize = 10;
+
+/// Increments the scores of several elements in a sorted set. Elements that do not exist are
+/// added with the increment amount as their score. Creates the sorted set if it does not exist.
+///
+/// The server has no batched increment, so one SortedSetIncrement request is sent per element,
+/// with at most `max_concurrency` of them in flight at once. Each increment succeeds or fails on
+/// its own, and the response holds a result for every element. Increments are not idempotent,
+/// so check which ones failed before retrying them.
+///
+/// # Arguments
+///
+/// * `cache_name` - The name of the cache containing the sorted set.
+/// * `sorted_set_name` - The name of the sorted set to increment scores in.
+/// * `elements` - The values to increment, each paired with the amount to increment it by.
+///
+/// # Optional Arguments
+///
+/// * `collection_ttl` - The time-to-live for the collection. If not provided, the client's default time-to-live is used.
+/// * `max_concurrency` - The maximum number of increments in flight at once. Defaults to
+///   [DEFAULT_INCREMENT_SCORES_CONCURRENCY].
+///
+/// # Examples
+/// Assumes that a CacheClient named `cache_client` has been created and is available.
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// # use momento_test_util::create_doctest_cache_client;
+/// # tokio_test::block_on(async {
+/// use std::convert::TryInto;
+/// use momento::cache::SortedSetIncrementScoresRequest;
+/// # let (cache_client, cache_name) = create_doctest_cache_client();
+///
+/// let increment_request = SortedSetIncrementScoresRequest::new(
+///     &cache_name,
+///     "page_views",
+///     vec![("home", 1.0), ("about", 1.0), ("pricing", 2.0)]
+/// ).max_concurrency(2);
+///
+/// let scores: Vec<f64> = cache_client.send_request(increment_request).await?.try_into()?;
+/// # assert_eq!(scores, vec![1.0, 1.0, 2.0]);
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+pub struct SortedSetIncrementScoresRequest<S: IntoBytes, V: IntoBytes, E: IntoSortedSetElements<V>>
+{
+    cache_name: String,
+    sorted_set_name: S,
+    elements: E,
+    collection_ttl: Option<CollectionTtl>,
+    max_concurrency: usize,
+    // V is only used for the `IntoSortedSetElement`'s generic type parameter.
+    _marker: PhantomData<V>,
+}
+
+impl<S: IntoBytes, V: IntoBytes, E: IntoSortedSetElements<V>>
+    SortedSetIncrementScoresRequest<S, V, E>
+{
+    /// Constructs a new SortedSetIncrementScoresRequest.
+    pub fn new(cache_name: impl Into<String>, sorted_set_name: S, elements: E) -> Self {
+        let collection_ttl = CollectionTtl::default();
+        Self {
+            cache_name: cache_name.into(),
+            sorted_set_name,
+            elements,
+            collection_ttl: Some(collection_ttl),
+            max_concurrency: DEFAULT_INCREMENT_SCORES_CONCURRENCY,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Set the time-to-live for the collection.
+    pub fn ttl(mut self, collection_ttl: impl Into<Option<CollectionTtl>>) -> Self {
+        self.collection_ttl = collection_ttl.into();
+        self
+    }
+
+    collection_ttl_shortcuts!("sorted set");
+
+    /// Set the maximum number of increments in flight at once. Values below 1 are treated as 1.
+    pub fn max_concurrency(mut self, max_concurrency: usize) -> Self {
+        self.max_concurrency = max_concurrency.max(1);
+        self
+    }
+}
+
+impl<S: IntoBytes, V: IntoBytes, E: IntoSortedSetElements<V>> MomentoRequest
+    for SortedSetIncrementScoresRequest<S, V, E>
+{
+    type Response = SortedSetIncrementScoresResponse;
+
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new(
+            "SortedSetIncrementScores",
+            self.cache_name.clone(),
+        ))
+    }
+
+    fn is_composite(&self) -> bool {
+        true
+    }
+
+    async fn send(
+        self,
+        cache_client: &CacheClient,
+    ) -> MomentoResult<SortedSetIncrementScoresResponse> {
+        is_cache_name_valid(&self.cache_name)?;
+
+        let cache_name = &self.cache_name;
+        let sorted_set_name = self.sorted_set_name.into_bytes();
+        let collection_ttl = self.collection_ttl;
+        let increments = self
+            .elements
+            .into_sorted_set_elements()
+            .into_iter()
+            .map(|element| {
+                let request = SortedSetIncrementScoreRequest::new(
+                    cache_name,
+                    sorted_set_name.clone(),
+                    element.value,
+                    element.score,
+                )
+                .ttl(collection_ttl);
+                async move {
+                    cache_client
+                        .send_sub_request(request)
+                        .await
+                        .map(|response| response.score)
+                }
+            });
+        let results = futures::stream::iter(increments)
+            .buffered(self.max_concurrency)
+            .collect()
+            .await;
+
+        Ok(SortedSetIncrementScoresResponse { results })
+    }
+}
+
+/// Response for a [SortedSetIncrementScoresRequest].
+///
+/// Holds the result of each increment in the order the elements were given: the new score of
+/// the element, or the error that increment failed with. Use `try_into()` to convert it into a
+/// `Vec<f64>` of the new scores, which fails with the first error if any increment failed.
+#[derive(Debug)]
+pub struct SortedSetIncrementScoresResponse {
+    /// The new score of each element, or the error its increment failed with.
+    pub results: Vec<MomentoResult<f64>>,
+}
+
+impl SortedSetIncrementScoresResponse {
+    /// Returns true if every increment succeeded.
+    pub fn all_succeeded(&self) -> bool {
+        self.results.iter().all(|result| result.is_ok())
+    }
+}
+
+impl TryFrom<SortedSetIncrementScoresResponse> for Vec<f64> {
+    type Error = MomentoError;
+
+    fn try_from(response: SortedSetIncrementScoresResponse) -> Result<Self, Self::Error> {
+        response.results.into_iter().collect()
+    }
+}
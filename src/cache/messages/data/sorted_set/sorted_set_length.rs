@@ -3,8 +3,8 @@ use std::convert::TryFrom;
 use momento_protos::cache_client::sorted_set_length_response;
 
 use crate::{
-    cache::MomentoRequest, utils::prep_request_with_timeout, CacheClient, IntoBytes, MomentoError,
-    MomentoResult,
+    cache::MomentoRequest, utils::prep_request_with_timeout, CacheClient, ErrorContext, IntoBytes,
+    MomentoError, MomentoResult,
 };
 
 /// Get the number of entries in a sorted set collection.
@@ -51,19 +51,34 @@ impl<L: IntoBytes> SortedSetLengthRequest<L> {
 impl<L: IntoBytes> MomentoRequest for SortedSetLengthRequest<L> {
     type Response = SortedSetLengthResponse;
 
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new(
+            "SortedSetLength",
+            self.cache_name.clone(),
+        ))
+    }
+
     async fn send(self, cache_client: &CacheClient) -> MomentoResult<SortedSetLengthResponse> {
+        let error_context = self.error_context();
+        let sorted_set_name = self.sorted_set_name.into_bytes();
+        let error_context =
+            error_context.map(|context| context.with_collection_name(&sorted_set_name));
         let request = prep_request_with_timeout(
             &self.cache_name,
             cache_client.deadline_millis(),
             momento_protos::cache_client::SortedSetLengthRequest {
-                set_name: self.sorted_set_name.into_bytes(),
+                set_name: sorted_set_name,
             },
         )?;
 
         let response = cache_client
-            .next_data_client()
-            .sorted_set_length(request)
-            .await?
+            .send_with_retries(
+                request,
+                Self::is_idempotent(),
+                |mut client, request| async move { client.sorted_set_length(request).await },
+            )
+            .await
+            .map_err(|e| e.with_context(error_context))?
             .into_inner();
 
         match response.sorted_set {
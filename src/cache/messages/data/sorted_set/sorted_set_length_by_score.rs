@@ -0,0 +1,205 @@
+use std::convert::TryFrom;
+
+use momento_protos::cache_client::sorted_set_length_by_score_request::{Max, Min};
+use momento_protos::cache_client::sorted_set_length_by_score_response;
+use momento_protos::common::Unbounded;
+
+use crate::{
+    cache::MomentoRequest, utils::prep_request_with_timeout, CacheClient, ErrorContext, IntoBytes,
+    MomentoError, MomentoResult,
+};
+
+/// A bound on the scores of the elements in a sorted set.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub enum ScoreBound {
+    /// Elements with exactly this score are within the bound.
+    Inclusive(f64),
+    /// Elements with exactly this score are outside the bound.
+    Exclusive(f64),
+    /// There is no bound on the score.
+    #[default]
+    Unbounded,
+}
+
+/// Get the number of elements in a sorted set collection whose scores are within a range.
+///
+/// # Arguments
+/// * `cache_name` - name of cache
+/// * `sorted_set_name` - name of the sorted set
+///
+/// # Optional Arguments
+///
+/// * `min_score` - The lower bound on the scores of the elements to count. Defaults to
+///   [ScoreBound::Unbounded].
+/// * `max_score` - The upper bound on the scores of the elements to count. Defaults to
+///   [ScoreBound::Unbounded].
+///
+/// # Examples
+/// Assumes that a CacheClient named `cache_client` has been created and is available.
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// # use momento_test_util::create_doctest_cache_client;
+/// # tokio_test::block_on(async {
+/// use std::convert::TryInto;
+/// use momento::cache::{ScoreBound, SortedSetLengthByScoreRequest};
+/// # let (cache_client, cache_name) = create_doctest_cache_client();
+/// let sorted_set_name = "sorted_set";
+///
+/// # cache_client.sorted_set_put_elements(&cache_name, sorted_set_name.to_string(), vec![("value1", 1.0), ("value2", 2.0)]).await?;
+///
+/// let length_request = SortedSetLengthByScoreRequest::new(cache_name, sorted_set_name)
+///     .min_score(ScoreBound::Inclusive(1.0))
+///     .max_score(ScoreBound::Exclusive(2.0));
+/// let length: u32 = cache_client.send_request(length_request).await?.try_into().expect("Expected a sorted set length!");
+/// # assert_eq!(length, 1);
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+pub struct SortedSetLengthByScoreRequest<L: IntoBytes> {
+    cache_name: String,
+    sorted_set_name: L,
+    min_score: ScoreBound,
+    max_score: ScoreBound,
+}
+
+impl<L: IntoBytes> SortedSetLengthByScoreRequest<L> {
+    /// Constructs a new SortedSetLengthByScoreRequest.
+    pub fn new(cache_name: impl Into<String>, sorted_set_name: L) -> Self {
+        Self {
+            cache_name: cache_name.into(),
+            sorted_set_name,
+            min_score: ScoreBound::Unbounded,
+            max_score: ScoreBound::Unbounded,
+        }
+    }
+
+    /// Set the lower bound on the scores of the elements to count.
+    pub fn min_score(mut self, min_score: impl Into<Option<ScoreBound>>) -> Self {
+        self.min_score = min_score.into().unwrap_or_default();
+        self
+    }
+
+    /// Set the upper bound on the scores of the elements to count.
+    pub fn max_score(mut self, max_score: impl Into<Option<ScoreBound>>) -> Self {
+        self.max_score = max_score.into().unwrap_or_default();
+        self
+    }
+}
+
+impl<L: IntoBytes> MomentoRequest for SortedSetLengthByScoreRequest<L> {
+    type Response = SortedSetLengthByScoreResponse;
+
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new(
+            "SortedSetLengthByScore",
+            self.cache_name.clone(),
+        ))
+    }
+
+    async fn send(
+        self,
+        cache_client: &CacheClient,
+    ) -> MomentoResult<SortedSetLengthByScoreResponse> {
+        let error_context = self.error_context();
+        let sorted_set_name = self.sorted_set_name.into_bytes();
+        let error_context =
+            error_context.map(|context| context.with_collection_name(&sorted_set_name));
+        let min = match self.min_score {
+            ScoreBound::Inclusive(score) => Min::InclusiveMin(score),
+            ScoreBound::Exclusive(score) => Min::ExclusiveMin(score),
+            ScoreBound::Unbounded => Min::UnboundedMin(Unbounded {}),
+        };
+        let max = match self.max_score {
+            ScoreBound::Inclusive(score) => Max::InclusiveMax(score),
+            ScoreBound::Exclusive(score) => Max::ExclusiveMax(score),
+            ScoreBound::Unbounded => Max::UnboundedMax(Unbounded {}),
+        };
+        let request = prep_request_with_timeout(
+            &self.cache_name,
+            cache_client.deadline_millis(),
+            momento_protos::cache_client::SortedSetLengthByScoreRequest {
+                set_name: sorted_set_name,
+                min: Some(min),
+                max: Some(max),
+            },
+        )?;
+
+        let response =
+            cache_client
+                .send_with_retries(
+                    request,
+                    Self::is_idempotent(),
+                    |mut client, request| async move {
+                        client.sorted_set_length_by_score(request).await
+                    },
+                )
+                .await
+                .map_err(|e| e.with_context(error_context))?
+                .into_inner();
+
+        match response.sorted_set {
+            Some(sorted_set_length_by_score_response::SortedSet::Missing(_)) => {
+                Ok(SortedSetLengthByScoreResponse::Miss)
+            }
+            Some(sorted_set_length_by_score_response::SortedSet::Found(found)) => {
+                Ok(SortedSetLengthByScoreResponse::Hit {
+                    length: found.length,
+                })
+            }
+            _ => Err(MomentoError::unknown_error(
+                "SortedSetLengthByScore",
+                Some(format!("{:#?}", response)),
+            )),
+        }
+    }
+}
+
+/// Response for a sorted set length by score operation.
+///
+/// If you'd like to handle misses you can simply match and handle your response:
+/// ```
+/// # use momento::MomentoResult;
+/// use momento::cache::SortedSetLengthByScoreResponse;
+/// # let response = SortedSetLengthByScoreResponse::Hit { length: 5 };
+/// let length: u32 = match response {
+///     SortedSetLengthByScoreResponse::Hit { length } => length,
+///     SortedSetLengthByScoreResponse::Miss => return // probably you'll do something else here
+/// };
+/// ```
+///
+/// You can cast your result directly into a Result<u32, MomentoError> suitable for
+/// ?-propagation if you know you are expecting a SortedSetLengthByScoreResponse::Hit.
+///
+/// Of course, a Miss in this case will be turned into an Error. If that's what you want, then
+/// this is what you're after:
+/// ```
+/// # use momento::MomentoResult;
+/// use momento::cache::SortedSetLengthByScoreResponse;
+/// use std::convert::TryInto;
+/// # let response = SortedSetLengthByScoreResponse::Hit { length: 5 };
+/// let length: MomentoResult<u32> = response.try_into();
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub enum SortedSetLengthByScoreResponse {
+    /// The sorted set was found.
+    Hit {
+        /// The number of elements in the sorted set with scores in the requested range.
+        length: u32,
+    },
+    /// The sorted set was not found.
+    Miss,
+}
+
+impl TryFrom<SortedSetLengthByScoreResponse> for u32 {
+    type Error = MomentoError;
+
+    fn try_from(value: SortedSetLengthByScoreResponse) -> Result<Self, Self::Error> {
+        match value {
+            SortedSetLengthByScoreResponse::Hit { length } => Ok(length),
+            SortedSetLengthByScoreResponse::Miss => {
+                Err(MomentoError::miss("SortedSetLengthByScore"))
+            }
+        }
+    }
+}
@@ -3,6 +3,7 @@ use momento_protos::cache_client::{SortedSetElement, SortedSetPutRequest};
 use crate::cache::messages::MomentoRequest;
 use crate::cache::CollectionTtl;
 use crate::utils::prep_request_with_timeout;
+use crate::ErrorContext;
 use crate::{CacheClient, IntoBytes, MomentoResult};
 
 /// Request to add an element to a sorted set. If the element already exists, its score is updated.
@@ -69,18 +70,29 @@ impl<S: IntoBytes, V: IntoBytes> SortedSetPutElementRequest<S, V> {
         self.collection_ttl = collection_ttl.into();
         self
     }
+
+    collection_ttl_shortcuts!("sorted set");
 }
 
 impl<S: IntoBytes, V: IntoBytes> MomentoRequest for SortedSetPutElementRequest<S, V> {
     type Response = SortedSetPutElementResponse;
 
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new(
+            "SortedSetPutElement",
+            self.cache_name.clone(),
+        ))
+    }
+
     async fn send(self, cache_client: &CacheClient) -> MomentoResult<SortedSetPutElementResponse> {
+        let error_context = self.error_context();
         let collection_ttl = self.collection_ttl.unwrap_or_default();
         let element = SortedSetElement {
             value: self.value.into_bytes(),
             score: self.score,
         };
         let set_name = self.sorted_set_name.into_bytes();
+        let error_context = error_context.map(|context| context.with_collection_name(&set_name));
         let cache_name = &self.cache_name;
         let request = prep_request_with_timeout(
             cache_name,
@@ -94,9 +106,13 @@ impl<S: IntoBytes, V: IntoBytes> MomentoRequest for SortedSetPutElementRequest<S
         )?;
 
         let _ = cache_client
-            .next_data_client()
-            .sorted_set_put(request)
-            .await?;
+            .send_with_retries(
+                request,
+                Self::is_idempotent(),
+                |mut client, request| async move { client.sorted_set_put(request).await },
+            )
+            .await
+            .map_err(|e| e.with_context(error_context))?;
         Ok(SortedSetPutElementResponse {})
     }
 }
@@ -104,3 +120,44 @@ impl<S: IntoBytes, V: IntoBytes> MomentoRequest for SortedSetPutElementRequest<S
 /// The response type for a successful sorted set put element request.
 #[derive(Debug, PartialEq, Eq)]
 pub struct SortedSetPutElementResponse {}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn ttl_settings(collection_ttl: Option<CollectionTtl>) -> (Option<Duration>, bool) {
+        let collection_ttl = collection_ttl.unwrap_or_default();
+        (collection_ttl.ttl(), collection_ttl.refresh())
+    }
+
+    #[test]
+    fn ttl_shortcuts_set_the_collection_ttl() {
+        let ttl = Duration::from_secs(60);
+        assert_eq!(
+            ttl_settings(
+                SortedSetPutElementRequest::new("cache", "sorted-set", "value", 1.0)
+                    .ttl_with_refresh(ttl)
+                    .collection_ttl
+            ),
+            (Some(ttl), true)
+        );
+        assert_eq!(
+            ttl_settings(
+                SortedSetPutElementRequest::new("cache", "sorted-set", "value", 1.0)
+                    .ttl_no_refresh(ttl)
+                    .collection_ttl
+            ),
+            (Some(ttl), false)
+        );
+        assert_eq!(
+            ttl_settings(
+                SortedSetPutElementRequest::new("cache", "sorted-set", "value", 1.0)
+                    .keep_ttl()
+                    .collection_ttl
+            ),
+            (None, false)
+        );
+    }
+}
@@ -1,13 +1,12 @@
-use std::collections::HashMap;
-use std::marker::PhantomData;
-
 use momento_protos::cache_client::SortedSetElement as ProtoSortedSetElement;
 use momento_protos::cache_client::SortedSetPutRequest;
+use std::collections::HashMap;
+use std::marker::PhantomData;
 
 use crate::cache::messages::MomentoRequest;
 use crate::cache::CollectionTtl;
 use crate::utils::prep_request_with_timeout;
-use crate::{CacheClient, IntoBytes, MomentoResult};
+use crate::{CacheClient, ErrorContext, IntoBytes, MomentoResult};
 
 /// This trait defines an interface for converting a type into a vector of [SortedSetElement].
 pub trait IntoSortedSetElements<V: IntoBytes>: Send {
@@ -170,6 +169,8 @@ impl<S: IntoBytes, V: IntoBytes, E: IntoSortedSetElements<V>> SortedSetPutElemen
         self.collection_ttl = collection_ttl.into();
         self
     }
+
+    collection_ttl_shortcuts!("sorted set");
 }
 
 impl<S: IntoBytes, V: IntoBytes, E: IntoSortedSetElements<V>> MomentoRequest
@@ -177,10 +178,19 @@ impl<S: IntoBytes, V: IntoBytes, E: IntoSortedSetElements<V>> MomentoRequest
 {
     type Response = SortedSetPutElementsResponse;
 
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new(
+            "SortedSetPutElements",
+            self.cache_name.clone(),
+        ))
+    }
+
     async fn send(self, cache_client: &CacheClient) -> MomentoResult<SortedSetPutElementsResponse> {
+        let error_context = self.error_context();
         let collection_ttl = self.collection_ttl.unwrap_or_default();
         let elements = self.elements.into_sorted_set_elements();
         let set_name = self.sorted_set_name.into_bytes();
+        let error_context = error_context.map(|context| context.with_collection_name(&set_name));
         let cache_name = &self.cache_name;
         let request = prep_request_with_timeout(
             cache_name,
@@ -200,9 +210,13 @@ impl<S: IntoBytes, V: IntoBytes, E: IntoSortedSetElements<V>> MomentoRequest
         )?;
 
         let _ = cache_client
-            .next_data_client()
-            .sorted_set_put(request)
-            .await?;
+            .send_with_retries(
+                request,
+                Self::is_idempotent(),
+                |mut client, request| async move { client.sorted_set_put(request).await },
+            )
+            .await
+            .map_err(|e| e.with_context(error_context))?;
         Ok(SortedSetPutElementsResponse {})
     }
 }
@@ -3,6 +3,7 @@ use momento_protos::cache_client::SortedSetRemoveRequest;
 
 use crate::cache::messages::MomentoRequest;
 use crate::utils::prep_request_with_timeout;
+use crate::ErrorContext;
 use crate::{CacheClient, IntoBytes, IntoBytesIterable, MomentoResult};
 
 /// Remove multiple elements from the sorted set.
@@ -56,12 +57,21 @@ impl<S: IntoBytes, V: IntoBytesIterable> SortedSetRemoveElementsRequest<S, V> {
 impl<S: IntoBytes, V: IntoBytesIterable> MomentoRequest for SortedSetRemoveElementsRequest<S, V> {
     type Response = SortedSetRemoveElementsResponse;
 
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new(
+            "SortedSetRemoveElements",
+            self.cache_name.clone(),
+        ))
+    }
+
     async fn send(
         self,
         cache_client: &CacheClient,
     ) -> MomentoResult<SortedSetRemoveElementsResponse> {
+        let error_context = self.error_context();
         let values = self.values.into_bytes();
         let set_name = self.sorted_set_name.into_bytes();
+        let error_context = error_context.map(|context| context.with_collection_name(&set_name));
         let cache_name = &self.cache_name;
         let request = prep_request_with_timeout(
             cache_name,
@@ -73,9 +83,13 @@ impl<S: IntoBytes, V: IntoBytesIterable> MomentoRequest for SortedSetRemoveEleme
         )?;
 
         let _ = cache_client
-            .next_data_client()
-            .sorted_set_remove(request)
-            .await?;
+            .send_with_retries(
+                request,
+                Self::is_idempotent(),
+                |mut client, request| async move { client.sorted_set_remove(request).await },
+            )
+            .await
+            .map_err(|e| e.with_context(error_context))?;
         Ok(SortedSetRemoveElementsResponse {})
     }
 }
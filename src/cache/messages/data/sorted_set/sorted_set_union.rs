@@ -0,0 +1,216 @@
+use std::collections::HashMap;
+
+use futures::future::try_join_all;
+
+use crate::cache::messages::data::sorted_set::sorted_set_fetch_by_rank::{
+    SortedSetFetchByRankRequest, SortedSetOrder,
+};
+use crate::cache::messages::data::sorted_set::sorted_set_fetch_response::{
+    SortedSetElements, SortedSetFetchResponse,
+};
+use crate::cache::messages::MomentoRequest;
+use crate::utils::is_cache_name_valid;
+use crate::{CacheClient, ErrorContext, IntoBytes, MomentoError, MomentoErrorCode, MomentoResult};
+
+/// Request to compute the union of several sorted sets without storing the result.
+///
+/// Each source sorted set is given a weight. The score of an element in the union is the sum of
+/// its scores in each source set multiplied by that set's weight. Sources that do not exist are
+/// skipped; if none of them exist, the response is a [SortedSetFetchResponse::Miss].
+///
+/// # Arguments
+///
+/// * `cache_name` - The name of the cache containing the sorted sets.
+/// * `sources` - The names of the sorted sets to merge, each paired with a weight.
+///
+/// # Optional Arguments
+///
+/// * `order` - The order to sort the merged elements by. [SortedSetOrder::Ascending] or
+///   [SortedSetOrder::Descending]. Defaults to the client's default sorted set order, which is
+///   Ascending unless configured otherwise.
+/// * `limit` - The maximum number of merged elements to return. Defaults to all elements.
+///
+/// # Examples
+/// Assumes that a CacheClient named `cache_client` has been created and is available.
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// # use momento_test_util::create_doctest_cache_client;
+/// # tokio_test::block_on(async {
+/// use std::convert::TryInto;
+/// use momento::cache::{SortedSetElement, SortedSetOrder, SortedSetUnionRequest};
+/// # let (cache_client, cache_name) = create_doctest_cache_client();
+/// # cache_client.sorted_set_put_elements(&cache_name, "scores_day_1", vec![("alice", 1.0), ("bob", 2.0)]).await?;
+/// # cache_client.sorted_set_put_elements(&cache_name, "scores_day_2", vec![("alice", 3.0)]).await?;
+///
+/// let union_request = SortedSetUnionRequest::new(
+///     &cache_name,
+///     vec![("scores_day_1", 1.0), ("scores_day_2", 1.0)]
+/// )
+/// .order(SortedSetOrder::Descending)
+/// .limit(10);
+///
+/// let merged: Vec<SortedSetElement<String>> = cache_client
+///     .send_request(union_request)
+///     .await?
+///     .try_into()?;
+/// # assert_eq!(merged[0], SortedSetElement { value: "alice".to_string(), score: 4.0 });
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+pub struct SortedSetUnionRequest<S: IntoBytes> {
+    cache_name: String,
+    sources: Vec<(S, f64)>,
+    order: Option<SortedSetOrder>,
+    limit: Option<u32>,
+}
+
+impl<S: IntoBytes> SortedSetUnionRequest<S> {
+    /// Constructs a new SortedSetUnionRequest.
+    pub fn new(cache_name: impl Into<String>, sources: impl IntoIterator<Item = (S, f64)>) -> Self {
+        Self {
+            cache_name: cache_name.into(),
+            sources: sources.into_iter().collect(),
+            order: None,
+            limit: None,
+        }
+    }
+
+    /// Set the order of the merged elements.
+    pub fn order(mut self, order: impl Into<Option<SortedSetOrder>>) -> Self {
+        self.order = order.into();
+        self
+    }
+
+    /// Set the maximum number of merged elements to return.
+    pub fn limit(mut self, limit: impl Into<Option<u32>>) -> Self {
+        self.limit = limit.into();
+        self
+    }
+}
+
+impl<S: IntoBytes> MomentoRequest for SortedSetUnionRequest<S> {
+    type Response = SortedSetFetchResponse;
+
+    fn error_context(&self) -> Option<ErrorContext> {
+        Some(ErrorContext::new("SortedSetUnion", self.cache_name.clone()))
+    }
+
+    fn is_composite(&self) -> bool {
+        true
+    }
+
+    async fn send(self, cache_client: &CacheClient) -> MomentoResult<SortedSetFetchResponse> {
+        is_cache_name_valid(&self.cache_name)?;
+        if self.sources.is_empty() {
+            return Err(MomentoError {
+                message: "At least one source sorted set must be provided".into(),
+                error_code: MomentoErrorCode::InvalidArgumentError,
+                inner_error: None,
+                details: None,
+                context: None,
+            });
+        }
+
+        let order = self
+            .order
+            .unwrap_or_else(|| cache_client.default_sorted_set_order());
+        let cache_name = &self.cache_name;
+        let fetches = self.sources.into_iter().map(|(sorted_set_name, weight)| {
+            let request = SortedSetFetchByRankRequest::new(cache_name, sorted_set_name);
+            async move {
+                cache_client
+                    .send_sub_request(request)
+                    .await
+                    .map(|response| (response, weight))
+            }
+        });
+        let responses = try_join_all(fetches).await?;
+
+        Ok(union_elements(responses, order, self.limit))
+    }
+}
+
+fn union_elements(
+    responses: Vec<(SortedSetFetchResponse, f64)>,
+    order: SortedSetOrder,
+    limit: Option<u32>,
+) -> SortedSetFetchResponse {
+    let mut found = false;
+    let mut scores: HashMap<Vec<u8>, f64> = HashMap::new();
+    for (response, weight) in responses {
+        if let SortedSetFetchResponse::Hit { value } = response {
+            found = true;
+            for (value, score) in value.elements {
+                *scores.entry(value).or_insert(0.0) += score * weight;
+            }
+        }
+    }
+    if !found {
+        return SortedSetFetchResponse::Miss;
+    }
+
+    // Match the server's ordering: by score, with ties ordered lexicographically.
+    let mut elements: Vec<(Vec<u8>, f64)> = scores.into_iter().collect();
+    elements.sort_by(|a, b| a.1.total_cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
+    if order == SortedSetOrder::Descending {
+        elements.reverse();
+    }
+    if let Some(limit) = limit {
+        elements.truncate(limit as usize);
+    }
+
+    SortedSetFetchResponse::Hit {
+        value: SortedSetElements::new(elements),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hit(elements: Vec<(&str, f64)>) -> SortedSetFetchResponse {
+        SortedSetFetchResponse::Hit {
+            value: SortedSetElements::new(
+                elements
+                    .into_iter()
+                    .map(|(value, score)| (value.as_bytes().to_vec(), score))
+                    .collect(),
+            ),
+        }
+    }
+
+    #[test]
+    fn test_union_elements_sums_weighted_scores() {
+        let responses = vec![
+            (hit(vec![("a", 1.0), ("b", 2.0)]), 1.0),
+            (hit(vec![("a", 3.0), ("c", 1.0)]), 2.0),
+            (SortedSetFetchResponse::Miss, 5.0),
+        ];
+
+        let result = union_elements(responses, SortedSetOrder::Ascending, None);
+        assert_eq!(result, hit(vec![("b", 2.0), ("c", 2.0), ("a", 7.0)]));
+    }
+
+    #[test]
+    fn test_union_elements_descending_with_limit() {
+        let responses = vec![
+            (hit(vec![("a", 1.0), ("b", 2.0)]), 1.0),
+            (hit(vec![("c", 3.0)]), 1.0),
+        ];
+
+        let result = union_elements(responses, SortedSetOrder::Descending, Some(2));
+        assert_eq!(result, hit(vec![("c", 3.0), ("b", 2.0)]));
+    }
+
+    #[test]
+    fn test_union_elements_all_missing() {
+        let responses = vec![
+            (SortedSetFetchResponse::Miss, 1.0),
+            (SortedSetFetchResponse::Miss, 1.0),
+        ];
+
+        let result = union_elements(responses, SortedSetOrder::Ascending, None);
+        assert_eq!(result, SortedSetFetchResponse::Miss);
+    }
+}
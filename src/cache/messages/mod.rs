@@ -4,6 +4,8 @@ pub mod control;
 /// Data messages for the cache
 pub mod data;
 
+mod as_bytes;
 mod momento_request;
 
-pub use momento_request::MomentoRequest;
+pub use as_bytes::AsBytes;
+pub use momento_request::{MomentoRawRequest, MomentoRequest};
@@ -1,11 +1,49 @@
 use crate::CacheClient;
-use crate::MomentoResult;
+use crate::{ErrorContext, MomentoResult};
 
 /// A trait that allows Momento request types to define their interaction with the gRPC client.
 pub trait MomentoRequest {
     #[allow(missing_docs)]
     type Response;
 
+    /// Describes the operation and cache targeted by this request. Errors returned from
+    /// [CacheClient::send_request] carry this context.
+    #[doc(hidden)]
+    fn error_context(&self) -> Option<ErrorContext> {
+        None
+    }
+
+    /// Classifies a response as a hit or a miss for [HitRateTracker](crate::cache::HitRateTracker).
+    /// Responses that are neither return `None` and are not counted.
+    #[doc(hidden)]
+    fn is_hit(_response: &Self::Response) -> Option<bool> {
+        None
+    }
+
+    /// Returns the miss response for reads, used in place of a transient error when the client is
+    /// built with `treat_errors_as_miss`. Requests that are not reads return `None` and always
+    /// surface their errors.
+    #[doc(hidden)]
+    fn miss() -> Option<Self::Response> {
+        None
+    }
+
+    /// Returns false if sending the request twice could apply it twice, such as an increment or
+    /// a list push. After a failure, the request may already have been applied, so such requests
+    /// are only retried if the client's configuration allows retrying non-idempotent requests.
+    #[doc(hidden)]
+    fn is_idempotent() -> bool {
+        true
+    }
+
+    /// Returns true if the request is sent as separate sub-requests, such as the individual gets
+    /// of a batch. Each sub-request then takes its own permit from the client's concurrency
+    /// limit, so the request itself does not.
+    #[doc(hidden)]
+    fn is_composite(&self) -> bool {
+        false
+    }
+
     /// An internal fn that allows Momento request types to define their interaction with
     /// the gRPC client. You can impl this fn for your own types if you'd like to hand them
     /// to the Momento client directly, but that is not an explicitly supported scenario and
@@ -16,3 +54,21 @@ pub trait MomentoRequest {
         cache_client: &CacheClient,
     ) -> impl std::future::Future<Output = MomentoResult<Self::Response>> + Send;
 }
+
+/// A request that can also return the underlying `momento_protos` response, for reading fields
+/// the typed response does not expose yet. See [CacheClient::send_request_raw].
+///
+/// Like [MomentoRequest::send], this is not an explicitly supported API: the raw response type
+/// is tied to the SDK's protobuf dependency and may change between releases without notice.
+pub trait MomentoRawRequest: MomentoRequest {
+    /// The protobuf response returned by the server.
+    type RawResponse;
+
+    /// Sends the request and returns the typed response along with the raw response it was
+    /// converted from.
+    #[doc(hidden)]
+    fn send_raw(
+        self,
+        cache_client: &CacheClient,
+    ) -> impl std::future::Future<Output = MomentoResult<(Self::Response, Self::RawResponse)>> + Send;
+}
@@ -0,0 +1,278 @@
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::future::BoxFuture;
+
+use crate::{MomentoError, MomentoErrorCode, MomentoResult};
+
+/// Describes a request sent by a [CacheClient](crate::CacheClient), as seen by a [Middleware].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestMetadata {
+    /// The name of the operation, e.g. `Get` or `DictionaryFetch`.
+    pub operation: String,
+    /// The name of the cache the request targets, if any.
+    pub cache_name: Option<String>,
+}
+
+/// Describes the outcome of a request, as seen by a [Middleware].
+#[derive(Debug)]
+pub struct ResponseMetadata<'a> {
+    /// The error the request failed with, or `None` if it succeeded.
+    pub error: Option<&'a MomentoError>,
+    /// Whether a successful read was a hit or a miss. `None` for failed requests and for
+    /// responses that are neither, such as the response to a set.
+    pub hit: Option<bool>,
+    /// How long the request took. Zero if a middleware short-circuited it.
+    pub duration: Duration,
+}
+
+/// Hooks that run around every request sent by a [CacheClient](crate::CacheClient), for
+/// instrumentation such as logging, metrics or tracing.
+///
+/// Register middleware with the client builder's `add_middleware`. `on_request` hooks run in the
+/// order the middleware was added, before the request is sent. `on_response` hooks run in the
+/// reverse order once it completes, so the first middleware added wraps all the others.
+///
+/// An `on_request` hook can short-circuit a request by returning an error. The request is then
+/// not sent and later middleware does not see it. The `on_response` hooks of the middleware added
+/// before the one that short-circuited are called with that error, which is returned to the
+/// caller.
+///
+/// Middleware sees each call made through the client once. Requests that the SDK sends on your
+/// behalf as part of a larger operation, such as the individual gets of `dictionary_get_field_batch`,
+/// are not seen separately.
+///
+/// # Example
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// # use momento_test_util::{get_test_cache_name, get_test_credential_provider};
+/// # tokio_test::block_on(async {
+/// use std::time::Duration;
+/// use futures::future::BoxFuture;
+/// use momento::cache::{configurations, Middleware, RequestMetadata, ResponseMetadata};
+/// use momento::CacheClient;
+/// # let cache_name = get_test_cache_name();
+///
+/// #[derive(Debug)]
+/// struct PrintLatency;
+///
+/// impl Middleware for PrintLatency {
+///     fn on_response<'a>(
+///         &'a self,
+///         request: &'a RequestMetadata,
+///         response: &'a ResponseMetadata<'a>,
+///     ) -> BoxFuture<'a, ()> {
+///         Box::pin(async move {
+///             println!("{} took {:?}", request.operation, response.duration);
+///         })
+///     }
+/// }
+///
+/// let cache_client = CacheClient::builder()
+///     .default_ttl(Duration::from_secs(60))
+///     .configuration(configurations::Laptop::latest())
+///     .credential_provider(get_test_credential_provider())
+///     .add_middleware(PrintLatency)
+///     .build()?;
+///
+/// cache_client.get(&cache_name, "key").await?;
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+pub trait Middleware: Debug + Send + Sync {
+    /// Called before a request is sent. Return an error to fail the request without sending it.
+    fn on_request<'a>(&'a self, _request: &'a RequestMetadata) -> BoxFuture<'a, MomentoResult<()>> {
+        Box::pin(async { Ok(()) })
+    }
+
+    /// Called after a request completes, successfully or not.
+    fn on_response<'a>(
+        &'a self,
+        _request: &'a RequestMetadata,
+        _response: &'a ResponseMetadata<'a>,
+    ) -> BoxFuture<'a, ()> {
+        Box::pin(async {})
+    }
+}
+
+/// A [Middleware] that logs every request with its cache name, outcome and latency.
+///
+/// Successful requests are logged at the configured level, `Debug` unless set otherwise. Failed
+/// requests are logged at `Warn`, except for misses, which are logged like successful requests.
+#[derive(Debug, Clone)]
+pub struct LoggingMiddleware {
+    level: log::Level,
+}
+
+impl LoggingMiddleware {
+    /// Constructs a LoggingMiddleware that logs successful requests at `Debug`.
+    pub fn new() -> Self {
+        Self {
+            level: log::Level::Debug,
+        }
+    }
+
+    /// Sets the level successful requests are logged at.
+    pub fn with_level(mut self, level: log::Level) -> Self {
+        self.level = level;
+        self
+    }
+}
+
+impl Default for LoggingMiddleware {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Middleware for LoggingMiddleware {
+    fn on_response<'a>(
+        &'a self,
+        request: &'a RequestMetadata,
+        response: &'a ResponseMetadata<'a>,
+    ) -> BoxFuture<'a, ()> {
+        Box::pin(async move {
+            let cache_name = request.cache_name.as_deref().unwrap_or("-");
+            match response.error {
+                Some(error) if error.error_code != MomentoErrorCode::Miss => log::warn!(
+                    "{} on cache {} failed after {:?}: {:?}: {}",
+                    request.operation,
+                    cache_name,
+                    response.duration,
+                    error.error_code,
+                    error.message
+                ),
+                _ => {
+                    let outcome = match response.hit {
+                        Some(true) => "hit",
+                        Some(false) => "miss",
+                        None if response.error.is_some() => "miss",
+                        None => "ok",
+                    };
+                    log::log!(
+                        self.level,
+                        "{} on cache {}: {} in {:?}",
+                        request.operation,
+                        cache_name,
+                        outcome,
+                        response.duration
+                    )
+                }
+            }
+        })
+    }
+}
+
+/// One completed request, as reported by a [MetricsMiddleware].
+#[derive(Debug, Clone, PartialEq)]
+pub struct RequestMetric {
+    /// The name of the operation, e.g. `Get` or `DictionaryFetch`.
+    pub operation: String,
+    /// The name of the cache the request targets, if any.
+    pub cache_name: Option<String>,
+    /// The error code the request failed with, or `None` if it succeeded.
+    pub error_code: Option<MomentoErrorCode>,
+    /// Whether a successful read was a hit or a miss, if the response is either.
+    pub hit: Option<bool>,
+    /// How long the request took.
+    pub duration: Duration,
+}
+
+/// A [Middleware] that reports every completed request to a callback, for recording counts and
+/// latency histograms in the metrics library of your choice.
+///
+/// The callback runs on the task that made the request, so it should be quick, e.g. incrementing
+/// a counter and recording a histogram sample.
+///
+/// A [RequestMetric] never carries item keys, so the number of distinct label combinations is
+/// bounded by the operations and caches you use. If you have many caches, leave the cache name
+/// out with [with_cache_name](MetricsMiddleware::with_cache_name).
+///
+/// # Example
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// # use momento_test_util::{get_test_cache_name, get_test_credential_provider};
+/// # tokio_test::block_on(async {
+/// use std::time::Duration;
+/// use momento::cache::{configurations, MetricsMiddleware};
+/// use momento::CacheClient;
+/// # let cache_name = get_test_cache_name();
+///
+/// let metrics = MetricsMiddleware::new(|metric| {
+///     let result = match &metric.error_code {
+///         Some(error_code) => format!("{:?}", error_code),
+///         None => "Ok".to_string(),
+///     };
+///     println!(
+///         "momento_requests{{operation={},result={}}} latency_ms={}",
+///         metric.operation,
+///         result,
+///         metric.duration.as_millis()
+///     );
+/// });
+///
+/// let cache_client = CacheClient::builder()
+///     .default_ttl(Duration::from_secs(60))
+///     .configuration(configurations::Laptop::latest())
+///     .credential_provider(get_test_credential_provider())
+///     .add_middleware(metrics)
+///     .build()?;
+///
+/// cache_client.get(&cache_name, "key").await?;
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct MetricsMiddleware {
+    callback: Arc<dyn Fn(&RequestMetric) + Send + Sync>,
+    include_cache_name: bool,
+}
+
+impl MetricsMiddleware {
+    /// Constructs a MetricsMiddleware that calls `callback` once for every completed request.
+    pub fn new(callback: impl Fn(&RequestMetric) + Send + Sync + 'static) -> Self {
+        Self {
+            callback: Arc::new(callback),
+            include_cache_name: true,
+        }
+    }
+
+    /// Sets whether reported metrics carry the cache name. Defaults to `true`. When disabled,
+    /// [RequestMetric::cache_name] is always `None`.
+    pub fn with_cache_name(mut self, include_cache_name: bool) -> Self {
+        self.include_cache_name = include_cache_name;
+        self
+    }
+}
+
+impl Debug for MetricsMiddleware {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MetricsMiddleware")
+            .field("include_cache_name", &self.include_cache_name)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Middleware for MetricsMiddleware {
+    fn on_response<'a>(
+        &'a self,
+        request: &'a RequestMetadata,
+        response: &'a ResponseMetadata<'a>,
+    ) -> BoxFuture<'a, ()> {
+        Box::pin(async move {
+            (self.callback)(&RequestMetric {
+                operation: request.operation.clone(),
+                cache_name: request
+                    .cache_name
+                    .clone()
+                    .filter(|_| self.include_cache_name),
+                error_code: response.error.map(|error| error.error_code.clone()),
+                hit: response.hit,
+                duration: response.duration,
+            })
+        })
+    }
+}
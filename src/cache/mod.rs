@@ -1,7 +1,8 @@
 /// Contains the request and response types for cache operations.
 pub mod messages;
 
-pub use messages::MomentoRequest;
+pub use messages::data::DEFAULT_BATCH_CONCURRENCY;
+pub use messages::{AsBytes, MomentoRawRequest, MomentoRequest};
 
 pub use messages::control::create_cache::{CreateCacheRequest, CreateCacheResponse};
 pub use messages::control::delete_cache::{DeleteCacheRequest, DeleteCacheResponse};
@@ -10,12 +11,18 @@ pub use messages::control::list_caches::{
     CacheInfo, CacheLimits, ListCachesRequest, ListCachesResponse, TopicLimits,
 };
 
+pub use messages::data::dictionary::dictionary_copy::{
+    DictionaryCopyRequest, DictionaryCopyResponse,
+};
 pub use messages::data::dictionary::dictionary_fetch::{
     DictionaryFetchRequest, DictionaryFetchResponse,
 };
 pub use messages::data::dictionary::dictionary_get_field::{
     DictionaryGetFieldRequest, DictionaryGetFieldResponse,
 };
+pub use messages::data::dictionary::dictionary_get_field_batch::{
+    DictionaryGetFieldBatchRequest, DictionaryGetFieldBatchResponse,
+};
 pub use messages::data::dictionary::dictionary_get_fields::{
     DictionaryGetFieldsRequest, DictionaryGetFieldsResponse,
 };
@@ -38,22 +45,35 @@ pub use messages::data::dictionary::dictionary_set_fields::{
     DictionaryFieldValuePair, DictionarySetFieldsRequest, DictionarySetFieldsResponse,
     IntoDictionaryFieldValuePairs,
 };
+pub use messages::data::dictionary::dictionary_set_fields_if_not_exists::{
+    DictionarySetFieldsIfNotExistsRequest, DictionarySetFieldsIfNotExistsResponse,
+};
 
 pub use messages::data::scalar::decrease_ttl::{DecreaseTtlRequest, DecreaseTtlResponse};
 pub use messages::data::scalar::delete::{DeleteRequest, DeleteResponse};
 pub use messages::data::scalar::get::{GetRequest, GetResponse};
 pub use messages::data::scalar::get_batch::{GetBatchRequest, GetBatchResponse};
+pub use messages::data::scalar::get_sliding::GetSlidingRequest;
+pub use messages::data::scalar::get_with_metadata::{
+    GetWithMetadataRequest, GetWithMetadataResponse,
+};
 pub use messages::data::scalar::increase_ttl::{IncreaseTtlRequest, IncreaseTtlResponse};
 pub use messages::data::scalar::increment::{IncrementRequest, IncrementResponse};
 pub use messages::data::scalar::item_get_ttl::{ItemGetTtlRequest, ItemGetTtlResponse};
 pub use messages::data::scalar::item_get_type::{
     ItemGetTypeRequest, ItemGetTypeResponse, ItemType,
 };
+pub use messages::data::scalar::item_get_type_batch::{
+    ItemGetTypeBatchRequest, ItemGetTypeBatchResponse,
+};
 pub use messages::data::scalar::key_exists::{KeyExistsRequest, KeyExistsResponse};
 pub use messages::data::scalar::keys_exist::{KeysExistRequest, KeysExistResponse};
 pub use messages::data::scalar::set::{SetRequest, SetResponse};
 pub use messages::data::scalar::set_batch::{SetBatchRequest, SetBatchResponse};
 pub use messages::data::scalar::set_if_absent::{SetIfAbsentRequest, SetIfAbsentResponse};
+pub use messages::data::scalar::set_if_absent_batch::{
+    SetIfAbsentBatchRequest, SetIfAbsentBatchResponse,
+};
 pub use messages::data::scalar::set_if_absent_or_equal::{
     SetIfAbsentOrEqualRequest, SetIfAbsentOrEqualResponse,
 };
@@ -63,6 +83,9 @@ pub use messages::data::scalar::set_if_present::{SetIfPresentRequest, SetIfPrese
 pub use messages::data::scalar::set_if_present_and_not_equal::{
     SetIfPresentAndNotEqualRequest, SetIfPresentAndNotEqualResponse,
 };
+pub use messages::data::scalar::touch_ttl_if_below::{
+    TouchTtlIfBelowRequest, TouchTtlIfBelowResponse,
+};
 pub use messages::data::scalar::update_ttl::{UpdateTtlRequest, UpdateTtlResponse};
 
 pub use messages::data::set::set_add_elements::{SetAddElementsRequest, SetAddElementsResponse};
@@ -71,10 +94,16 @@ pub use messages::data::set::set_remove_elements::{
     SetRemoveElementsRequest, SetRemoveElementsResponse,
 };
 
+pub use messages::data::sorted_set::sorted_set_copy::{
+    SortedSetCopyRequest, SortedSetCopyResponse,
+};
 pub use messages::data::sorted_set::sorted_set_fetch_by_rank::{
     SortedSetFetchByRankRequest, SortedSetOrder,
 };
 pub use messages::data::sorted_set::sorted_set_fetch_by_score::SortedSetFetchByScoreRequest;
+pub use messages::data::sorted_set::sorted_set_fetch_extremes::{
+    SortedSetFetchExtremesRequest, SortedSetFetchExtremesResponse,
+};
 pub use messages::data::sorted_set::sorted_set_fetch_response::{
     SortedSetElements, SortedSetFetchResponse,
 };
@@ -90,9 +119,19 @@ pub use messages::data::sorted_set::sorted_set_get_scores::{
 pub use messages::data::sorted_set::sorted_set_increment_score::{
     SortedSetIncrementScoreRequest, SortedSetIncrementScoreResponse,
 };
+pub use messages::data::sorted_set::sorted_set_increment_score_and_get_rank::{
+    SortedSetIncrementScoreAndGetRankRequest, SortedSetIncrementScoreAndGetRankResponse,
+};
+pub use messages::data::sorted_set::sorted_set_increment_scores::{
+    SortedSetIncrementScoresRequest, SortedSetIncrementScoresResponse,
+    DEFAULT_INCREMENT_SCORES_CONCURRENCY,
+};
 pub use messages::data::sorted_set::sorted_set_length::{
     SortedSetLengthRequest, SortedSetLengthResponse,
 };
+pub use messages::data::sorted_set::sorted_set_length_by_score::{
+    ScoreBound, SortedSetLengthByScoreRequest, SortedSetLengthByScoreResponse,
+};
 pub use messages::data::sorted_set::sorted_set_put_element::{
     SortedSetPutElementRequest, SortedSetPutElementResponse,
 };
@@ -103,6 +142,7 @@ pub use messages::data::sorted_set::sorted_set_put_elements::{
 pub use messages::data::sorted_set::sorted_set_remove_elements::{
     SortedSetRemoveElementsRequest, SortedSetRemoveElementsResponse,
 };
+pub use messages::data::sorted_set::sorted_set_union::SortedSetUnionRequest;
 
 pub use messages::data::list::list_concatenate_back::{
     ListConcatenateBackRequest, ListConcatenateBackResponse,
@@ -110,6 +150,7 @@ pub use messages::data::list::list_concatenate_back::{
 pub use messages::data::list::list_concatenate_front::{
     ListConcatenateFrontRequest, ListConcatenateFrontResponse,
 };
+pub use messages::data::list::list_copy::{ListCopyRequest, ListCopyResponse};
 pub use messages::data::list::list_fetch::{ListFetchRequest, ListFetchResponse};
 pub use messages::data::list::list_length::{ListLengthRequest, ListLengthResponse};
 pub use messages::data::list::list_pop_back::{ListPopBackRequest, ListPopBackResponse};
@@ -119,6 +160,7 @@ pub use messages::data::list::list_push_front::{ListPushFrontRequest, ListPushFr
 pub use messages::data::list::list_remove_value::{
     ListRemoveValueRequest, ListRemoveValueResponse,
 };
+pub use messages::data::list::list_retain::{ListRetainRequest, ListRetainResponse};
 
 // Similar re-exporting with config::configuration and config::configurations
 // so import paths can be simpmlified to "momento::cache::Configuration" and
@@ -134,3 +176,17 @@ pub use collection_ttl::CollectionTtl;
 mod cache_client;
 mod cache_client_builder;
 pub use cache_client::CacheClient;
+
+mod request_limiter;
+pub use request_limiter::MaxConcurrentRequestsBehavior;
+
+mod hit_rate_tracker;
+pub use hit_rate_tracker::{HitRateStats, HitRateTracker};
+
+mod middleware;
+#[cfg(feature = "tracing")]
+mod request_span;
+pub use middleware::{
+    LoggingMiddleware, MetricsMiddleware, Middleware, RequestMetadata, RequestMetric,
+    ResponseMetadata,
+};
@@ -0,0 +1,135 @@
+use std::sync::Arc;
+
+use tokio::sync::{OwnedSemaphorePermit, Semaphore, TryAcquireError};
+
+use crate::{MomentoError, MomentoErrorCode, MomentoResult};
+
+/// Determines what a [CacheClient](crate::CacheClient) does with a new request when the maximum
+/// number of concurrent requests are already in flight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MaxConcurrentRequestsBehavior {
+    /// Wait until one of the in-flight requests completes before sending the request.
+    Wait,
+    /// Immediately return a [MomentoError] with a
+    /// [ClientResourceExhausted](MomentoErrorCode::ClientResourceExhausted) error code.
+    FailFast,
+}
+
+/// Caps the number of requests a client will have in flight at once.
+#[derive(Debug, Clone)]
+pub(crate) struct RequestLimiter {
+    max_concurrent_requests: usize,
+    behavior: MaxConcurrentRequestsBehavior,
+    semaphore: Arc<Semaphore>,
+}
+
+impl RequestLimiter {
+    pub(crate) fn new(
+        max_concurrent_requests: usize,
+        behavior: MaxConcurrentRequestsBehavior,
+    ) -> MomentoResult<Self> {
+        if max_concurrent_requests == 0 || max_concurrent_requests > Semaphore::MAX_PERMITS {
+            return Err(MomentoError {
+                message: format!(
+                    "Max concurrent requests must be between 1 and {}",
+                    Semaphore::MAX_PERMITS
+                ),
+                error_code: MomentoErrorCode::InvalidArgumentError,
+                inner_error: None,
+                details: None,
+                context: None,
+            });
+        }
+        Ok(Self {
+            max_concurrent_requests,
+            behavior,
+            semaphore: Arc::new(Semaphore::new(max_concurrent_requests)),
+        })
+    }
+
+    /// Acquires a permit to send a request. The request is considered in flight until the
+    /// returned permit is dropped.
+    pub(crate) async fn acquire(&self) -> MomentoResult<OwnedSemaphorePermit> {
+        match self.behavior {
+            MaxConcurrentRequestsBehavior::Wait => self
+                .semaphore
+                .clone()
+                .acquire_owned()
+                .await
+                .map_err(|e| MomentoError {
+                    message: "Unable to acquire a permit to send the request".into(),
+                    error_code: MomentoErrorCode::UnknownError,
+                    inner_error: Some(crate::ErrorSource::Unknown(Box::new(e))),
+                    details: None,
+                    context: None,
+                }),
+            MaxConcurrentRequestsBehavior::FailFast => self
+                .semaphore
+                .clone()
+                .try_acquire_owned()
+                .map_err(|e| match e {
+                    TryAcquireError::NoPermits => MomentoError {
+                        message: format!(
+                            "Maximum number of concurrent requests ({}) exceeded",
+                            self.max_concurrent_requests
+                        ),
+                        error_code: MomentoErrorCode::ClientResourceExhausted,
+                        inner_error: None,
+                        details: None,
+                        context: None,
+                    },
+                    TryAcquireError::Closed => MomentoError {
+                        message: "Unable to acquire a permit to send the request".into(),
+                        error_code: MomentoErrorCode::UnknownError,
+                        inner_error: Some(crate::ErrorSource::Unknown(Box::new(e))),
+                        details: None,
+                        context: None,
+                    },
+                }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero_max_concurrent_requests_is_invalid() {
+        let result = RequestLimiter::new(0, MaxConcurrentRequestsBehavior::Wait);
+        assert_eq!(
+            result.unwrap_err().error_code,
+            MomentoErrorCode::InvalidArgumentError
+        );
+    }
+
+    #[tokio::test]
+    async fn fail_fast_when_exhausted() -> MomentoResult<()> {
+        let limiter = RequestLimiter::new(1, MaxConcurrentRequestsBehavior::FailFast)?;
+        let permit = limiter.acquire().await?;
+
+        let result = limiter.acquire().await;
+        assert_eq!(
+            result.unwrap_err().error_code,
+            MomentoErrorCode::ClientResourceExhausted
+        );
+
+        drop(permit);
+        assert!(limiter.acquire().await.is_ok());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn wait_when_exhausted() -> MomentoResult<()> {
+        let limiter = RequestLimiter::new(1, MaxConcurrentRequestsBehavior::Wait)?;
+        let permit = limiter.acquire().await?;
+
+        let waiting =
+            tokio::time::timeout(std::time::Duration::from_millis(50), limiter.acquire()).await;
+        assert!(waiting.is_err(), "Expected acquire to wait for a permit");
+
+        drop(permit);
+        assert!(limiter.acquire().await.is_ok());
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+use tracing::field::{debug, Empty};
+use tracing::Span;
+
+use crate::{ErrorContext, MomentoResult};
+
+/// Creates the span a cache request runs in. Its outcome fields are filled in by
+/// [record_outcome] once the request completes, and `key_length` by requests that target a
+/// single key.
+pub(crate) fn request_span(context: Option<&ErrorContext>) -> Span {
+    let operation = context.map_or("Unknown", |c| c.operation.as_str());
+    tracing::info_span!(
+        "momento_request",
+        otel.name = operation,
+        operation = operation,
+        cache_name = context.and_then(|c| c.cache_name.as_deref()),
+        key_length = Empty,
+        result = Empty,
+        grpc_status = Empty,
+        error_code = Empty,
+    )
+}
+
+/// Records the outcome of a request on its span, then emits an event in the span so that
+/// subscribers which only print events, such as `tracing_subscriber::fmt`, show it.
+pub(crate) fn record_outcome<T>(span: &Span, result: &MomentoResult<T>, hit: Option<bool>) {
+    match result {
+        Ok(_) => {
+            let outcome = match hit {
+                Some(true) => "hit",
+                Some(false) => "miss",
+                None => "ok",
+            };
+            span.record("result", outcome);
+            span.record("grpc_status", debug(tonic::Code::Ok));
+        }
+        Err(e) => {
+            span.record("result", "error");
+            span.record("error_code", debug(&e.error_code));
+            if let Some(details) = &e.details {
+                span.record("grpc_status", debug(details.code));
+            }
+        }
+    }
+    tracing::debug!(parent: span, "momento request completed");
+}
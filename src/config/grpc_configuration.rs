@@ -8,6 +8,8 @@ pub struct GrpcConfiguration {
     /// The duration the client is willing to wait for an RPC to complete before it is terminated
     /// with a DeadlineExceeded error.
     pub(crate) deadline: Duration,
+    /// The duration the client is willing to wait for a connection to be established.
+    pub(crate) connect_timeout: Option<Duration>,
     /// The number of grpc channels (TCP connections) to create
     pub(crate) num_channels: usize,
     /// Indicates whether the client should send keep-alive pings.
@@ -31,6 +33,36 @@ impl GrpcConfiguration {
     pub fn builder() -> GrpcConfigurationBuilder<NeedsDeadline> {
         GrpcConfigurationBuilder(NeedsDeadline(()))
     }
+
+    /// Returns the duration the client waits for an RPC to complete before terminating it.
+    pub fn deadline(&self) -> Duration {
+        self.deadline
+    }
+
+    /// Returns how long the client waits for a connection to be established, if set.
+    pub fn connect_timeout(&self) -> Option<Duration> {
+        self.connect_timeout
+    }
+
+    /// Returns the number of gRPC channels (TCP connections) to create.
+    pub fn num_channels(&self) -> usize {
+        self.num_channels
+    }
+
+    /// Returns whether the client sends keep-alive pings, if set.
+    pub fn keep_alive_while_idle(&self) -> Option<bool> {
+        self.keep_alive_while_idle
+    }
+
+    /// Returns the interval at which keep-alive pings are sent, if set.
+    pub fn keep_alive_interval(&self) -> Option<Duration> {
+        self.keep_alive_interval
+    }
+
+    /// Returns how long the client waits for a keep-alive ping to be acknowledged, if set.
+    pub fn keep_alive_timeout(&self) -> Option<Duration> {
+        self.keep_alive_timeout
+    }
 }
 
 /// The initial state of the GrpcConfigurationBuilder.
@@ -42,6 +74,7 @@ pub struct NeedsDeadline(());
 /// The state of the GrpcConfigurationBuilder when it is ready to build a GrpcConfiguration.
 pub struct ReadyToBuild {
     deadline: Duration,
+    connect_timeout: Option<Duration>,
     num_channels: usize,
     keep_alive_while_idle: Option<bool>,
     keep_alive_interval: Option<Duration>,
@@ -51,9 +84,14 @@ pub struct ReadyToBuild {
 impl GrpcConfigurationBuilder<NeedsDeadline> {
     /// Sets the duration the client is willing to wait for an RPC to complete before it is
     /// terminated with a DeadlineExceeded error.
+    ///
+    /// The deadline applies to each attempt at a request separately. Connections are opened
+    /// lazily, so it also covers connecting if a request is the first to use a connection; use
+    /// [connect_timeout](GrpcConfigurationBuilder::connect_timeout) to give connecting longer.
     pub fn deadline(self, deadline: Duration) -> GrpcConfigurationBuilder<ReadyToBuild> {
         GrpcConfigurationBuilder(ReadyToBuild {
             deadline,
+            connect_timeout: None,
             num_channels: 1,
             keep_alive_while_idle: None,
             keep_alive_interval: None,
@@ -77,6 +115,18 @@ impl GrpcConfigurationBuilder<ReadyToBuild> {
         self
     }
 
+    /// The duration the client is willing to wait for a connection to be established, separately
+    /// from the [deadline](GrpcConfigurationBuilder::deadline) of the requests that use it.
+    ///
+    /// A request that is waiting for a connection still fails when its own deadline passes, but
+    /// the connection attempt carries on in the background until this timeout, so that later
+    /// requests can use it. This lets a cold start take longer to connect while each request
+    /// keeps a short deadline. If not set, connecting is not given a timeout of its own.
+    pub fn connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.0.connect_timeout = Some(connect_timeout);
+        self
+    }
+
     /// Indicates whether the client should send keep-alive pings.
     ///
     /// NOTE: keep-alives are very important for long-lived server environments where there may be periods of time
@@ -125,6 +175,7 @@ impl GrpcConfigurationBuilder<ReadyToBuild> {
         }
         GrpcConfiguration {
             deadline: self.0.deadline,
+            connect_timeout: self.0.connect_timeout,
             num_channels: self.0.num_channels,
             keep_alive_while_idle: self.0.keep_alive_while_idle,
             keep_alive_interval: self.0.keep_alive_interval,
@@ -1,4 +1,6 @@
 /// Low-level gRPC settings for communicating with Momento.
 pub mod grpc_configuration;
+/// Strategies for retrying failed requests.
+pub mod retry_strategy;
 /// Low-level settings for communicating with Momento.
 pub mod transport_strategy;
@@ -0,0 +1,255 @@
+use std::fmt::Debug;
+use std::time::Duration;
+
+use rand::Rng;
+
+use crate::{MomentoError, MomentoErrorCode};
+
+/// The error codes that the provided retry strategies retry unless configured otherwise.
+///
+/// `TimeoutError` is included, so a write that timed out on the client may already have been
+/// applied by the server. Requests that could be applied twice, such as increments, are therefore
+/// not retried unless the configuration allows it; see
+/// [Configuration::with_retry_non_idempotent_requests](crate::cache::Configuration::with_retry_non_idempotent_requests).
+pub const DEFAULT_RETRYABLE_ERROR_CODES: [MomentoErrorCode; 2] = [
+    MomentoErrorCode::ServerUnavailable,
+    MomentoErrorCode::TimeoutError,
+];
+
+/// Decides whether and when a failed request is retried.
+///
+/// Set one on a cache client with
+/// [Configuration::with_retry_strategy](crate::cache::Configuration::with_retry_strategy). A
+/// client without a retry strategy sends each request once.
+///
+/// Implement this trait to customize retries. Override
+/// [is_retryable](RetryStrategy::is_retryable) to change which errors are retried.
+pub trait RetryStrategy: Debug + Send + Sync {
+    /// Returns how long to wait before retrying a request that failed with `error`, or `None` if
+    /// it should not be retried. `attempt` is the number of attempts made so far, starting at 1.
+    fn determine_when_to_retry(&self, error: &MomentoError, attempt: u32) -> Option<Duration>;
+
+    /// Returns true if a request that failed with `error` may be retried. By default, only the
+    /// [DEFAULT_RETRYABLE_ERROR_CODES] are retried.
+    fn is_retryable(&self, error: &MomentoError) -> bool {
+        DEFAULT_RETRYABLE_ERROR_CODES.contains(&error.error_code)
+    }
+}
+
+/// Retries a failed request right away, up to a fixed number of attempts.
+///
+/// ```
+/// use momento::config::retry_strategy::FixedCountRetryStrategy;
+///
+/// // Send each request at most 3 times
+/// let retry_strategy = FixedCountRetryStrategy::new(3);
+/// ```
+#[derive(Clone, Debug)]
+pub struct FixedCountRetryStrategy {
+    max_attempts: u32,
+    retryable_error_codes: Vec<MomentoErrorCode>,
+}
+
+impl FixedCountRetryStrategy {
+    /// Constructs a strategy that sends a request at most `max_attempts` times, including the
+    /// first attempt.
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts,
+            retryable_error_codes: DEFAULT_RETRYABLE_ERROR_CODES.to_vec(),
+        }
+    }
+
+    /// Sets the error codes that are retried. Defaults to [DEFAULT_RETRYABLE_ERROR_CODES].
+    pub fn with_retryable_error_codes(
+        mut self,
+        error_codes: impl IntoIterator<Item = MomentoErrorCode>,
+    ) -> Self {
+        self.retryable_error_codes = error_codes.into_iter().collect();
+        self
+    }
+}
+
+impl RetryStrategy for FixedCountRetryStrategy {
+    fn determine_when_to_retry(&self, error: &MomentoError, attempt: u32) -> Option<Duration> {
+        if attempt >= self.max_attempts || !self.is_retryable(error) {
+            return None;
+        }
+        Some(Duration::ZERO)
+    }
+
+    fn is_retryable(&self, error: &MomentoError) -> bool {
+        self.retryable_error_codes.contains(&error.error_code)
+    }
+}
+
+/// Retries a failed request after a delay that doubles with each attempt, up to a fixed number
+/// of attempts.
+///
+/// The delay before retry `n` is `base_delay * 2^(n - 1)`, capped at `max_delay`. Jitter
+/// spreads out the retries of clients that failed at the same time: each delay is picked at
+/// random between half of that value and all of it.
+///
+/// ```
+/// use std::time::Duration;
+/// use momento::config::retry_strategy::ExponentialBackoffRetryStrategy;
+///
+/// // Send each request at most 4 times, waiting roughly 100ms, 200ms and 400ms between attempts
+/// let retry_strategy = ExponentialBackoffRetryStrategy::new(
+///     4,
+///     Duration::from_millis(100),
+///     Duration::from_secs(1),
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct ExponentialBackoffRetryStrategy {
+    max_attempts: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    retryable_error_codes: Vec<MomentoErrorCode>,
+}
+
+impl ExponentialBackoffRetryStrategy {
+    /// Constructs a strategy that sends a request at most `max_attempts` times, including the
+    /// first attempt, starting from a delay of `base_delay` and never waiting longer than
+    /// `max_delay`.
+    pub fn new(max_attempts: u32, base_delay: Duration, max_delay: Duration) -> Self {
+        Self {
+            max_attempts,
+            base_delay,
+            max_delay,
+            retryable_error_codes: DEFAULT_RETRYABLE_ERROR_CODES.to_vec(),
+        }
+    }
+
+    /// Sets the error codes that are retried. Defaults to [DEFAULT_RETRYABLE_ERROR_CODES].
+    pub fn with_retryable_error_codes(
+        mut self,
+        error_codes: impl IntoIterator<Item = MomentoErrorCode>,
+    ) -> Self {
+        self.retryable_error_codes = error_codes.into_iter().collect();
+        self
+    }
+
+    fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.base_delay
+            .checked_mul(factor)
+            .map_or(self.max_delay, |delay| delay.min(self.max_delay))
+    }
+}
+
+impl RetryStrategy for ExponentialBackoffRetryStrategy {
+    fn determine_when_to_retry(&self, error: &MomentoError, attempt: u32) -> Option<Duration> {
+        if attempt >= self.max_attempts || !self.is_retryable(error) {
+            return None;
+        }
+        let half = self.backoff(attempt) / 2;
+        let jitter = half.mul_f64(rand::thread_rng().gen::<f64>());
+        Some(half.saturating_add(jitter))
+    }
+
+    fn is_retryable(&self, error: &MomentoError) -> bool {
+        self.retryable_error_codes.contains(&error.error_code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error(error_code: MomentoErrorCode) -> MomentoError {
+        MomentoError {
+            message: "test error".to_string(),
+            error_code,
+            inner_error: None,
+            details: None,
+            context: None,
+        }
+    }
+
+    #[test]
+    fn fixed_count_retries_until_max_attempts() {
+        let strategy = FixedCountRetryStrategy::new(3);
+        let unavailable = error(MomentoErrorCode::ServerUnavailable);
+        assert_eq!(
+            strategy.determine_when_to_retry(&unavailable, 1),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(
+            strategy.determine_when_to_retry(&unavailable, 2),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(strategy.determine_when_to_retry(&unavailable, 3), None);
+    }
+
+    #[test]
+    fn default_retryable_error_codes() {
+        let strategy = FixedCountRetryStrategy::new(3);
+        assert!(strategy.is_retryable(&error(MomentoErrorCode::ServerUnavailable)));
+        assert!(strategy.is_retryable(&error(MomentoErrorCode::TimeoutError)));
+        assert!(!strategy.is_retryable(&error(MomentoErrorCode::InvalidArgumentError)));
+        assert!(!strategy.is_retryable(&error(MomentoErrorCode::CacheNotFoundError)));
+        assert!(!strategy.is_retryable(&error(MomentoErrorCode::ItemNotFoundError)));
+        assert!(!strategy.is_retryable(&error(MomentoErrorCode::AuthenticationError)));
+        assert!(!strategy.is_retryable(&error(MomentoErrorCode::PermissionError)));
+
+        let strategy = ExponentialBackoffRetryStrategy::new(
+            3,
+            Duration::from_millis(10),
+            Duration::from_millis(100),
+        );
+        assert!(strategy.is_retryable(&error(MomentoErrorCode::ServerUnavailable)));
+        assert!(!strategy.is_retryable(&error(MomentoErrorCode::AuthenticationError)));
+        assert!(!strategy.is_retryable(&error(MomentoErrorCode::PermissionError)));
+    }
+
+    #[test]
+    fn custom_retryable_error_codes() {
+        let strategy = ExponentialBackoffRetryStrategy::new(
+            3,
+            Duration::from_millis(10),
+            Duration::from_millis(100),
+        )
+        .with_retryable_error_codes(vec![MomentoErrorCode::LimitExceededError]);
+        assert!(strategy.is_retryable(&error(MomentoErrorCode::LimitExceededError)));
+        assert!(!strategy.is_retryable(&error(MomentoErrorCode::ServerUnavailable)));
+        assert_eq!(
+            strategy.determine_when_to_retry(&error(MomentoErrorCode::ServerUnavailable), 1),
+            None
+        );
+    }
+
+    #[test]
+    fn exponential_backoff_doubles_with_jitter_up_to_max_delay() {
+        let strategy = ExponentialBackoffRetryStrategy::new(
+            10,
+            Duration::from_millis(100),
+            Duration::from_millis(500),
+        );
+        let unavailable = error(MomentoErrorCode::ServerUnavailable);
+        for (attempt, backoff_millis) in [(1, 100), (2, 200), (3, 400), (4, 500), (9, 500)] {
+            let backoff = Duration::from_millis(backoff_millis);
+            let delay = strategy
+                .determine_when_to_retry(&unavailable, attempt)
+                .expect("attempt should be retried");
+            assert!(
+                delay >= backoff / 2 && delay <= backoff,
+                "attempt {attempt}: expected a delay between {:?} and {backoff:?}, got {delay:?}",
+                backoff / 2
+            );
+        }
+        assert_eq!(strategy.determine_when_to_retry(&unavailable, 10), None);
+    }
+
+    #[test]
+    fn exponential_backoff_does_not_overflow() {
+        let strategy = ExponentialBackoffRetryStrategy::new(
+            u32::MAX,
+            Duration::from_secs(u64::MAX / 2),
+            Duration::from_secs(60),
+        );
+        assert_eq!(strategy.backoff(3), Duration::from_secs(60));
+        assert_eq!(strategy.backoff(200), Duration::from_secs(60));
+    }
+}
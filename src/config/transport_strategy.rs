@@ -12,6 +12,11 @@ impl TransportStrategy {
     pub fn builder() -> TransportStrategyBuilder<NeedsGrpcConfiguration> {
         TransportStrategyBuilder(NeedsGrpcConfiguration(()))
     }
+
+    /// Returns the low-level gRPC settings of the transport strategy.
+    pub fn grpc_configuration(&self) -> &GrpcConfiguration {
+        &self.grpc_configuration
+    }
 }
 
 /// The initial state of the TransportStrategyBuilder.
@@ -41,7 +41,8 @@ pub enum MomentoErrorCode {
     FailedPreconditionError,
     /// Unknown error has occurred
     UnknownError,
-    /// Cache request responded with a Miss
+    /// Cache request responded with a Miss, e.g. when a miss response is converted into a value
+    /// with `try_into`
     Miss,
     /// Type error
     TypeError,
@@ -91,9 +92,52 @@ pub struct MomentoError {
 
     /// Contains details about the error from GRPC if such details are available
     pub details: Option<MomentoGrpcErrorDetails>,
+
+    /// The operation, cache and collection that produced the error, if known
+    pub(crate) context: Option<ErrorContext>,
 }
 
 impl MomentoError {
+    /// Creates an error with no inner error, details or context, e.g. for a
+    /// [Middleware](crate::cache::Middleware) to reject a request with.
+    pub fn new(error_code: MomentoErrorCode, message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            error_code,
+            inner_error: None,
+            details: None,
+            context: None,
+        }
+    }
+
+    /// Returns the operation, cache and collection that produced the error, if known.
+    pub fn context(&self) -> Option<&ErrorContext> {
+        self.context.as_ref()
+    }
+
+    /// Returns true if the credentials used do not grant access to the attempted resource,
+    /// e.g. a disposable token scoped to one cache being used against another.
+    pub fn is_permission_denied(&self) -> bool {
+        self.error_code == MomentoErrorCode::PermissionError
+    }
+
+    /// Attaches context to the error unless it already has some.
+    ///
+    /// Permission errors also have the attempted operation and cache appended to their message,
+    /// since the service's own message does not say which resource was denied.
+    pub(crate) fn with_context(mut self, context: Option<ErrorContext>) -> Self {
+        if self.context.is_none() {
+            match &context {
+                Some(context) if self.is_permission_denied() => {
+                    self.message = format!("{} (attempted {})", self.message, context);
+                }
+                _ => {}
+            }
+            self.context = context;
+        }
+        self
+    }
+
     pub(crate) fn unknown_error(method_name: &str, details: Option<String>) -> Self {
         Self {
             message: "Unknown error has occurred, unable to parse ".to_string()
@@ -103,17 +147,61 @@ impl MomentoError {
             error_code: MomentoErrorCode::UnknownError,
             inner_error: None,
             details: None,
+            context: None,
         }
     }
 
     pub(crate) fn miss(method_name: &str) -> Self {
         Self {
             message: "Received a MISS for ".to_string() + method_name,
-            error_code: MomentoErrorCode::UnknownError,
+            error_code: MomentoErrorCode::Miss,
             inner_error: None,
             details: None,
+            context: None,
+        }
+    }
+}
+
+/// Describes the operation that produced a [MomentoError].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorContext {
+    /// The name of the operation, e.g. `Get` or `DictionaryFetch`
+    pub operation: String,
+
+    /// The name of the cache the operation was performed against, if any
+    pub cache_name: Option<String>,
+
+    /// The name of the collection the operation was performed against, if any. Names that are
+    /// not valid UTF-8 are converted lossily.
+    pub collection_name: Option<String>,
+}
+
+impl ErrorContext {
+    pub(crate) fn new(operation: impl Into<String>, cache_name: impl Into<Option<String>>) -> Self {
+        Self {
+            operation: operation.into(),
+            cache_name: cache_name.into(),
+            collection_name: None,
         }
     }
+
+    pub(crate) fn with_collection_name(mut self, collection_name: &[u8]) -> Self {
+        self.collection_name = Some(String::from_utf8_lossy(collection_name).into_owned());
+        self
+    }
+}
+
+impl std::fmt::Display for ErrorContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.operation)?;
+        if let Some(collection_name) = &self.collection_name {
+            write!(f, " of collection {}", collection_name)?;
+        }
+        if let Some(cache_name) = &self.cache_name {
+            write!(f, " on cache {}", cache_name)?;
+        }
+        Ok(())
+    }
 }
 
 /// Indicates an error source
@@ -153,55 +241,64 @@ pub(crate) fn status_to_error(status: tonic::Status) -> MomentoError {
             message: "Invalid argument passed to Momento client".into(),
             error_code: MomentoErrorCode::InvalidArgumentError,
             inner_error: Some(status.clone().into()),
-            details: Some(status.into())
+            details: Some(status.into()),
+            context: None,
         },
         tonic::Code::Unimplemented => MomentoError {
             message: "The request was invalid; please contact us at support@momentohq.com".into(),
             error_code: MomentoErrorCode::BadRequestError,
             inner_error: Some(status.clone().into()),
-            details: Some(status.into())
+            details: Some(status.into()),
+            context: None,
         },
         tonic::Code::OutOfRange => MomentoError {
             message: "The request was invalid; please contact us at support@momentohq.com".into(),
             error_code: MomentoErrorCode::BadRequestError,
             inner_error: Some(status.clone().into()),
-            details: Some(status.into())
+            details: Some(status.into()),
+            context: None,
         },
         tonic::Code::FailedPrecondition => MomentoError {
             message: "System is not in a state required for the operation's execution".into(),
             error_code: MomentoErrorCode::FailedPreconditionError,
             inner_error: Some(status.clone().into()),
-            details: Some(status.into())
+            details: Some(status.into()),
+            context: None,
         },
         tonic::Code::Cancelled => MomentoError {
             message: "The request was cancelled by the server; please contact us at support@momentohq.com".into(),
             error_code: MomentoErrorCode::CancelledError,
             inner_error: Some(status.clone().into()),
-            details: Some(status.into())
+            details: Some(status.into()),
+            context: None,
         },
         tonic::Code::DeadlineExceeded => MomentoError {
             message: "The client's configured timeout was exceeded; you may need to use a Configuration with more lenient timeouts".into(),
             error_code: MomentoErrorCode::TimeoutError,
             inner_error: Some(status.clone().into()),
-            details: Some(status.into())
+            details: Some(status.into()),
+            context: None,
         },
         tonic::Code::PermissionDenied => MomentoError {
             message: "Insufficient permissions to perform an operation on a cache".into(),
             error_code: MomentoErrorCode::PermissionError,
             inner_error: Some(status.clone().into()),
-            details: Some(status.into())
+            details: Some(status.into()),
+            context: None,
         },
         tonic::Code::Unauthenticated => MomentoError {
             message: "Invalid authentication credentials to connect to cache service".into(),
             error_code: MomentoErrorCode::AuthenticationError,
             inner_error: Some(status.clone().into()),
-            details: Some(status.into())
+            details: Some(status.into()),
+            context: None,
         },
         tonic::Code::ResourceExhausted => MomentoError {
             message: determine_limit_exceeded_message_wrapper(status.metadata(), status.message()),
             error_code: MomentoErrorCode::LimitExceededError,
             inner_error: Some(status.clone().into()),
-            details: Some(status.into())
+            details: Some(status.into()),
+            context: None,
         },
         tonic::Code::NotFound => {
             match status.metadata().get("err") {
@@ -209,7 +306,8 @@ pub(crate) fn status_to_error(status: tonic::Status) -> MomentoError {
                     message: "A cache with the specified name does not exist.  To resolve this error, make sure you have created the cache before attempting to use it".into(),
                     error_code: MomentoErrorCode::CacheNotFoundError,
                     inner_error: Some(status.clone().into()),
-                    details: Some(status.into())
+                    details: Some(status.into()),
+                    context: None,
                 },
                 Some(err) => match err.to_str() {
                     Ok(err_str) => {
@@ -218,19 +316,22 @@ pub(crate) fn status_to_error(status: tonic::Status) -> MomentoError {
                                 message: "A store with the specified name does not exist.  To resolve this error, make sure you have created the store before attempting to use it".into(),
                                 error_code: MomentoErrorCode::StoreNotFoundError,
                                 inner_error: Some(status.clone().into()),
-                                details: Some(status.into())
+                                details: Some(status.into()),
+                                context: None,
                             },
                             "item_not_found" => MomentoError {
                                 message: "An item with the specified key does not exist.  To resolve this error, make sure you have created the item before attempting to use it".into(),
                                 error_code: MomentoErrorCode::ItemNotFoundError,
                                 inner_error: Some(status.clone().into()),
-                                details: Some(status.into())
+                                details: Some(status.into()),
+                                context: None,
                             },
                             _ => MomentoError {
                                 message: "A cache with the specified name does not exist.  To resolve this error, make sure you have created the cache before attempting to use it".into(),
                                 error_code: MomentoErrorCode::CacheNotFoundError,
                                 inner_error: Some(status.clone().into()),
-                                details: Some(status.into())
+                                details: Some(status.into()),
+                                context: None,
                             }
                         }
                     }
@@ -238,7 +339,8 @@ pub(crate) fn status_to_error(status: tonic::Status) -> MomentoError {
                         message: "Unknown error has occurred, unable to convert the error metadata into a string".into(),
                         error_code: MomentoErrorCode::UnknownError,
                         inner_error: Some(e.into()),
-                        details: Some(status.into())
+                        details: Some(status.into()),
+                        context: None,
                     }
                 }
             }
@@ -247,7 +349,8 @@ pub(crate) fn status_to_error(status: tonic::Status) -> MomentoError {
             message: "A cache with the specified name already exists.  To resolve this error, either delete the existing cache and make a new one, or use a different name".into(),
             error_code: MomentoErrorCode::AlreadyExistsError,
             inner_error: Some(status.clone().into()),
-            details: Some(status.into())
+            details: Some(status.into()),
+            context: None,
         },
         tonic::Code::Unknown => {
             match status
@@ -263,14 +366,16 @@ pub(crate) fn status_to_error(status: tonic::Status) -> MomentoError {
                                 message: "An unexpected error occurred while trying to fulfill the request, the request was interrupted by the server without an error; please contact us at support@momentohq.com".into(),
                                 error_code: MomentoErrorCode::InternalServerError,
                                 inner_error: Some(status.clone().into()),
-                                details: Some(status.into())
+                                details: Some(status.into()),
+                                context: None,
                             }
                         } else {
                             MomentoError {
                                 message: "Unknown error has occurred, the request was terminated locally without an error".into(),
                                 error_code: MomentoErrorCode::UnknownError,
                                 inner_error: Some(status.clone().into()),
-                                details: Some(status.into())
+                                details: Some(status.into()),
+                                context: None,
                             }
                         }
                     } else {
@@ -278,7 +383,8 @@ pub(crate) fn status_to_error(status: tonic::Status) -> MomentoError {
                             message: "An unexpected error occurred while trying to fulfill the request, an internal http2 error terminated the request; please contact us at support@momentohq.com".into(),
                             error_code: MomentoErrorCode::InternalServerError,
                             inner_error: Some(status.clone().into()),
-                            details: Some(status.into())
+                            details: Some(status.into()),
+                            context: None,
                         }
                     }
                 }
@@ -286,7 +392,8 @@ pub(crate) fn status_to_error(status: tonic::Status) -> MomentoError {
                     message: "An unexpected error occurred while trying to fulfill the request, an unknown error terminated the request; please contact us at support@momentohq.com".into(),
                     error_code: MomentoErrorCode::InternalServerError,
                     inner_error: Some(status.clone().into()),
-                    details: Some(status.into())
+                    details: Some(status.into()),
+                    context: None,
                 }
             }
         }
@@ -294,31 +401,36 @@ pub(crate) fn status_to_error(status: tonic::Status) -> MomentoError {
             message: "An unexpected error occurred while trying to fulfill the request, request was aborted; please contact us at support@momentohq.com".into(),
             error_code: MomentoErrorCode::InternalServerError,
             inner_error: Some(status.clone().into()),
-            details: Some(status.into())
+            details: Some(status.into()),
+            context: None,
         },
         tonic::Code::Internal => MomentoError {
             message: "An unexpected internal error occurred while trying to fulfill the request; please contact us at support@momentohq.com".into(),
             error_code: MomentoErrorCode::InternalServerError,
             inner_error: Some(status.clone().into()),
-            details: Some(status.into())
+            details: Some(status.into()),
+            context: None,
         },
         tonic::Code::Unavailable => MomentoError {
             message: "The server was unavailable to handle the request; consider retrying.  If the error persists, please contact Momento.".into(),
             error_code: MomentoErrorCode::ServerUnavailable,
             inner_error: Some(status.clone().into()),
-            details: Some(status.into())
+            details: Some(status.into()),
+            context: None,
         },
         tonic::Code::DataLoss => MomentoError {
             message: "An unexpected data loss error occurred while trying to fulfill the request; please contact us at support@momentohq.com".into(),
             error_code: MomentoErrorCode::InternalServerError,
             inner_error: Some(status.clone().into()),
-            details: Some(status.into())
+            details: Some(status.into()),
+            context: None,
         },
         _ => MomentoError {
             message: "The service returned an unknown response; please contact us at support@momentohq.com".into(),
             error_code: MomentoErrorCode::UnknownServiceError,
             inner_error: Some(status.clone().into()),
-            details: Some(status.into())
+            details: Some(status.into()),
+            context: None,
         },
     }
 }
@@ -399,3 +511,56 @@ fn determine_limit_exceeded_message_wrapper(metadata: &MetadataMap, message: &st
     };
     wrapper.value().to_string()
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::convert::TryFrom;
+    use std::time::Duration;
+
+    use crate::cache::{
+        DictionaryFetchResponse, GetResponse, ItemGetTtlResponse, ListFetchResponse,
+        SetFetchResponse, SortedSetFetchResponse,
+    };
+    use crate::{ErrorContext, MomentoError, MomentoErrorCode};
+
+    #[test]
+    fn miss_conversions_return_the_miss_error_code() {
+        let error_codes = [
+            String::try_from(GetResponse::Miss).unwrap_err().error_code,
+            Vec::<u8>::try_from(GetResponse::Miss)
+                .unwrap_err()
+                .error_code,
+            Duration::try_from(ItemGetTtlResponse::Miss)
+                .unwrap_err()
+                .error_code,
+            HashMap::<String, String>::try_from(DictionaryFetchResponse::Miss)
+                .unwrap_err()
+                .error_code,
+            Vec::<String>::try_from(ListFetchResponse::Miss)
+                .unwrap_err()
+                .error_code,
+            Vec::<String>::try_from(SetFetchResponse::Miss)
+                .unwrap_err()
+                .error_code,
+            Vec::<(String, f64)>::try_from(SortedSetFetchResponse::Miss)
+                .unwrap_err()
+                .error_code,
+        ];
+        for error_code in error_codes {
+            assert_eq!(error_code, MomentoErrorCode::Miss);
+        }
+    }
+
+    #[test]
+    fn permission_error_context_is_appended_to_the_message() {
+        let status = tonic::Status::permission_denied("denied by the service");
+        let error = MomentoError::from(status)
+            .with_context(Some(ErrorContext::new("Get", "cache".to_string())));
+        assert_eq!(
+            error.message,
+            "Insufficient permissions to perform an operation on a cache (attempted Get on cache cache)"
+        );
+        assert_eq!(error.context().map(|c| c.operation.as_str()), Some("Get"));
+    }
+}
@@ -6,15 +6,17 @@ use std::sync::Arc;
 #[derive(Clone)]
 pub struct HeaderInterceptor {
     auth_token: String,
-    sdk_agent: String,
+    sdk_agent: Option<String>,
     are_only_once_header_sent: Arc<AtomicBool>,
 }
 
 impl HeaderInterceptor {
-    pub fn new(authorization: &str, sdk_agent: &str) -> HeaderInterceptor {
+    /// Constructs a new HeaderInterceptor. If `sdk_agent` is None, the `agent` and
+    /// `runtime-version` headers are not sent.
+    pub fn new(authorization: &str, sdk_agent: Option<&str>) -> HeaderInterceptor {
         HeaderInterceptor {
             auth_token: authorization.to_string(),
-            sdk_agent: sdk_agent.to_string(),
+            sdk_agent: sdk_agent.map(|agent| agent.to_string()),
             are_only_once_header_sent: Arc::new(AtomicBool::new(false)),
         }
     }
@@ -40,7 +42,11 @@ impl tonic::service::Interceptor for HeaderInterceptor {
         self.insert_header(&mut request, "authorization", &self.auth_token)?;
 
         if !self.are_only_once_header_sent.load(Ordering::Relaxed) {
-            self.insert_header(&mut request, "agent", &self.sdk_agent)?;
+            let sdk_agent = match &self.sdk_agent {
+                Some(sdk_agent) => sdk_agent,
+                None => return Ok(request),
+            };
+            self.insert_header(&mut request, "agent", sdk_agent)?;
 
             // Because the `runtime-version` header makes more sense for interpreted languages,
             // we send this sentinel value to ensure we report *some* value for this sdk.
@@ -77,3 +83,40 @@ fn create_header_from_string(
     })?;
     Ok((header_name, header_value))
 }
+
+#[cfg(test)]
+mod tests {
+    use tonic::service::Interceptor;
+
+    use super::HeaderInterceptor;
+
+    fn intercept(interceptor: &mut HeaderInterceptor) -> tonic::Request<()> {
+        interceptor
+            .call(tonic::Request::new(()))
+            .expect("headers should be valid")
+    }
+
+    #[test]
+    fn agent_headers_are_left_out_without_an_agent() {
+        let mut interceptor = HeaderInterceptor::new("token", None);
+        let request = intercept(&mut interceptor);
+
+        assert_eq!(request.metadata().get("authorization").unwrap(), "token");
+        assert!(request.metadata().get("agent").is_none());
+        assert!(request.metadata().get("runtime-version").is_none());
+    }
+
+    #[test]
+    fn agent_override_is_sent_once() {
+        let mut interceptor = HeaderInterceptor::new("token", Some("my-app:1.0"));
+        let request = intercept(&mut interceptor);
+
+        assert_eq!(request.metadata().get("authorization").unwrap(), "token");
+        assert_eq!(request.metadata().get("agent").unwrap(), "my-app:1.0");
+        assert_eq!(request.metadata().get("runtime-version").unwrap(), "rust");
+
+        let request = intercept(&mut interceptor);
+        assert_eq!(request.metadata().get("authorization").unwrap(), "token");
+        assert!(request.metadata().get("agent").is_none());
+    }
+}
@@ -146,3 +146,18 @@ pub type MomentoResult<T> = Result<T, MomentoError>;
 /// Contains the [AuthClient] for calling Momento Auth APIs.
 pub mod auth;
 pub use auth::AuthClient;
+
+/// Contains a blocking [CacheClient](blocking::CacheClient) for programs that do not run inside an
+/// async runtime. Enabled by the `blocking` feature.
+#[cfg(feature = "blocking")]
+pub mod blocking;
+
+/// Contains [DistributedLock](lock::DistributedLock), a lock stored in a cache that renews itself
+/// while it is held. Enabled by the `lock` feature.
+#[cfg(feature = "lock")]
+pub mod lock;
+
+/// Contains [TestCache](testing::TestCache), a uniquely named cache for integration tests that is
+/// deleted when dropped. Enabled by the `testing` feature.
+#[cfg(feature = "testing")]
+pub mod testing;
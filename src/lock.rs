@@ -0,0 +1,317 @@
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use log::{debug, warn};
+use tokio::runtime::Handle;
+use tokio::task::JoinHandle;
+
+use crate::cache::{
+    GetResponse, SetIfAbsentRequest, SetIfAbsentResponse, SetIfEqualRequest, SetIfEqualResponse,
+};
+use crate::{CacheClient, IntoBytes, MomentoError, MomentoErrorCode, MomentoResult};
+
+/// How long [DistributedLock::lock_with_timeout] waits between attempts to acquire a lock.
+pub const LOCK_RETRY_INTERVAL: Duration = Duration::from_millis(100);
+
+/// The ttl written over a lock to release it, so that it expires right away.
+const RELEASED_TTL: Duration = Duration::from_millis(1);
+
+/// A lock shared between processes, stored as an item in a Momento cache.
+///
+/// A lock is acquired by setting its key with [set_if_absent](CacheClient::set_if_absent) to a
+/// token unique to this holder, with a ttl so that a crashed holder cannot keep it forever.
+/// While the lock is held, a background task renews the ttl every third of the ttl, but only if
+/// the key still holds this lock's token. If a renewal finds that the lock has expired or been
+/// taken by someone else, it stops and [is_held](DistributedLock::is_held) returns false. The
+/// same happens if renewals keep failing, for example because the service cannot be reached,
+/// until the ttl has passed since the last successful renewal, as the lock may then have expired.
+///
+/// The lock is released when it is dropped, or explicitly with
+/// [release](DistributedLock::release). The service has no conditional delete, so releasing
+/// overwrites the key with a value that expires immediately, and only if the key still holds
+/// this lock's token. A lock held by someone else is never released. Releasing on drop spawns
+/// the release onto the current tokio runtime and does not wait for it; call `release` to know
+/// that it happened.
+///
+/// Like any lease-based lock, this one cannot guarantee mutual exclusion if the holder stalls
+/// for longer than the ttl, for example in a long garbage collection pause or a network
+/// partition. Choose a ttl comfortably longer than such pauses.
+///
+/// # Examples
+/// Assumes that a CacheClient named `cache_client` has been created and is available.
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// # use momento_test_util::create_doctest_cache_client;
+/// # tokio_test::block_on(async {
+/// use std::time::Duration;
+/// use momento::lock::DistributedLock;
+/// # let (cache_client, cache_name) = create_doctest_cache_client();
+///
+/// let lock = DistributedLock::lock_with_timeout(
+///     &cache_client,
+///     &cache_name,
+///     "lock:report",
+///     Duration::from_secs(30),
+///     Duration::from_secs(5),
+/// ).await?;
+///
+/// match lock {
+///     Some(lock) => {
+///         // Do the work that needs the lock
+///         lock.release().await?;
+///     }
+///     None => println!("Someone else is generating the report"),
+/// }
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+pub struct DistributedLock {
+    client: CacheClient,
+    cache_name: String,
+    key: Vec<u8>,
+    token: String,
+    ttl: Duration,
+    acquired_at: Instant,
+    lease: Arc<Lease>,
+    renewal: Option<JoinHandle<()>>,
+}
+
+/// The state of a lock shared with its renewal task.
+struct Lease {
+    held: AtomicBool,
+    /// When the last successful renewal was sent, in milliseconds after the lock was acquired.
+    renewed_at_millis: AtomicU64,
+}
+
+impl DistributedLock {
+    /// Tries once to acquire the lock, returning `None` if it is already held.
+    ///
+    /// # Arguments
+    ///
+    /// * `cache_client` - the client to store the lock with
+    /// * `cache_name` - name of cache
+    /// * `key` - the key of the lock
+    /// * `ttl` - how long the lock is held if it is not renewed. Must be at least 3 milliseconds.
+    pub async fn try_lock(
+        cache_client: &CacheClient,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+        ttl: Duration,
+    ) -> MomentoResult<Option<Self>> {
+        if ttl < Duration::from_millis(3) {
+            return Err(MomentoError {
+                message: "lock ttl must be at least 3 milliseconds".to_string(),
+                error_code: MomentoErrorCode::InvalidArgumentError,
+                inner_error: None,
+                details: None,
+                context: None,
+            });
+        }
+
+        let cache_name = cache_name.into();
+        let key = key.into_bytes();
+        let token = format!("{:032x}", rand::random::<u128>());
+        let requested_at = Instant::now();
+        let response = cache_client
+            .send_request(
+                SetIfAbsentRequest::new(&cache_name, key.clone(), token.as_str()).ttl(ttl),
+            )
+            .await?;
+        // If the client retries conditional sets, an attempt that timed out after storing our
+        // token makes the retry report the key as taken, so check who holds it
+        if response == SetIfAbsentResponse::NotStored
+            && !holds_token(cache_client, &cache_name, key.clone(), &token).await?
+        {
+            return Ok(None);
+        }
+
+        let lease = Arc::new(Lease {
+            held: AtomicBool::new(true),
+            renewed_at_millis: AtomicU64::new(0),
+        });
+        let renewal = tokio::spawn(renew(
+            cache_client.clone(),
+            cache_name.clone(),
+            key.clone(),
+            token.clone(),
+            ttl,
+            requested_at,
+            lease.clone(),
+        ));
+        Ok(Some(Self {
+            client: cache_client.clone(),
+            cache_name,
+            key,
+            token,
+            ttl,
+            acquired_at: requested_at,
+            lease,
+            renewal: Some(renewal),
+        }))
+    }
+
+    /// Acquires the lock, retrying every [LOCK_RETRY_INTERVAL] while it is held by someone else.
+    /// Returns `None` if the lock could not be acquired within `timeout`.
+    ///
+    /// # Arguments
+    ///
+    /// * `cache_client` - the client to store the lock with
+    /// * `cache_name` - name of cache
+    /// * `key` - the key of the lock
+    /// * `ttl` - how long the lock is held if it is not renewed. Must be at least 3 milliseconds.
+    /// * `timeout` - how long to keep trying to acquire the lock
+    pub async fn lock_with_timeout(
+        cache_client: &CacheClient,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+        ttl: Duration,
+        timeout: Duration,
+    ) -> MomentoResult<Option<Self>> {
+        let cache_name = cache_name.into();
+        let key = key.into_bytes();
+        let deadline = Instant::now() + timeout;
+        loop {
+            if let Some(lock) = Self::try_lock(cache_client, &cache_name, key.clone(), ttl).await? {
+                return Ok(Some(lock));
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return Ok(None);
+            }
+            tokio::time::sleep(LOCK_RETRY_INTERVAL.min(deadline - now)).await;
+        }
+    }
+
+    /// Returns false once a renewal has found that the lock expired or was taken by someone
+    /// else, or once the ttl has passed without a successful renewal. Work protected by the lock
+    /// should stop when this happens.
+    pub fn is_held(&self) -> bool {
+        // Checked here as well as by the renewal task, which may be stuck waiting on a renewal
+        let renewed_at =
+            Duration::from_millis(self.lease.renewed_at_millis.load(Ordering::Relaxed));
+        self.lease.held.load(Ordering::Relaxed)
+            && self.acquired_at.elapsed() < renewed_at + self.ttl
+    }
+
+    /// Releases the lock if it is still held by this holder, stopping its renewal.
+    ///
+    /// Returns true if the lock was released, and false if it had already expired or been
+    /// taken by someone else.
+    pub async fn release(mut self) -> MomentoResult<bool> {
+        self.stop_renewal();
+        release(
+            &self.client,
+            &self.cache_name,
+            self.key.clone(),
+            &self.token,
+        )
+        .await
+    }
+
+    /// Stops the renewal task and marks the lock as no longer held, returning whether it was
+    /// still held.
+    fn stop_renewal(&mut self) -> bool {
+        if let Some(renewal) = self.renewal.take() {
+            renewal.abort();
+        }
+        self.lease.held.swap(false, Ordering::Relaxed)
+    }
+}
+
+impl Drop for DistributedLock {
+    fn drop(&mut self) {
+        if !self.stop_renewal() {
+            return;
+        }
+        match Handle::try_current() {
+            Ok(handle) => {
+                let client = self.client.clone();
+                let cache_name = self.cache_name.clone();
+                let key = self.key.clone();
+                let token = self.token.clone();
+                handle.spawn(async move {
+                    if let Err(e) = release(&client, &cache_name, key, &token).await {
+                        warn!("failed to release lock in cache {}: {}", cache_name, e);
+                    }
+                });
+            }
+            Err(_) => warn!(
+                "lock in cache {} dropped outside of a tokio runtime; it will be released when its ttl expires",
+                self.cache_name
+            ),
+        }
+    }
+}
+
+async fn renew(
+    client: CacheClient,
+    cache_name: String,
+    key: Vec<u8>,
+    token: String,
+    ttl: Duration,
+    acquired_at: Instant,
+    lease: Arc<Lease>,
+) {
+    // The ttl is measured from when a request was sent, since the server may have applied it
+    // any time after that
+    let mut last_renewed_at = acquired_at;
+    loop {
+        tokio::time::sleep(ttl / 3).await;
+        let requested_at = Instant::now();
+        let request =
+            SetIfEqualRequest::new(&cache_name, key.clone(), token.as_str(), token.as_str())
+                .ttl(ttl);
+        match client.send_request(request).await {
+            Ok(SetIfEqualResponse::Stored) => {
+                last_renewed_at = requested_at;
+                let millis = requested_at.duration_since(acquired_at).as_millis();
+                lease
+                    .renewed_at_millis
+                    .store(millis as u64, Ordering::Relaxed);
+            }
+            Ok(SetIfEqualResponse::NotStored) => {
+                debug!(
+                    "lock in cache {} was lost before it was renewed",
+                    cache_name
+                );
+                lease.held.store(false, Ordering::Relaxed);
+                return;
+            }
+            Err(e) if last_renewed_at.elapsed() >= ttl => {
+                warn!(
+                    "failed to renew lock in cache {} before its ttl expired: {}",
+                    cache_name, e
+                );
+                lease.held.store(false, Ordering::Relaxed);
+                return;
+            }
+            // Try again at the next interval; the lock is still held until its ttl expires
+            Err(e) => warn!("failed to renew lock in cache {}: {}", cache_name, e),
+        }
+    }
+}
+
+async fn holds_token(
+    client: &CacheClient,
+    cache_name: &str,
+    key: Vec<u8>,
+    token: &str,
+) -> MomentoResult<bool> {
+    match client.get(cache_name, key).await? {
+        GetResponse::Hit { value } => Ok(value.raw_item == token.as_bytes()),
+        GetResponse::Miss => Ok(false),
+    }
+}
+
+async fn release(
+    client: &CacheClient,
+    cache_name: &str,
+    key: Vec<u8>,
+    token: &str,
+) -> MomentoResult<bool> {
+    let request = SetIfEqualRequest::new(cache_name, key, "released", token).ttl(RELEASED_TTL);
+    let response = client.send_request(request).await?;
+    Ok(response == SetIfEqualResponse::Stored)
+}
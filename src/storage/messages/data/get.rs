@@ -1,5 +1,5 @@
 use crate::storage::messages::momento_storage_request::MomentoStorageRequest;
-use crate::storage::messages::storage_value::StorageValue;
+use crate::storage::messages::storage_value::{StorageValue, StorageValueType};
 use crate::storage::PreviewStorageClient;
 use crate::{utils, MomentoErrorCode};
 use crate::{MomentoError, MomentoResult};
@@ -132,12 +132,64 @@ pub enum GetResponse {
     NotFound,
 }
 
+impl GetResponse {
+    /// Returns the type of the value that was found, or `None` if the item was not in the store.
+    pub fn value_type(&self) -> Option<StorageValueType> {
+        match self {
+            GetResponse::Found { value } => Some(value.value_type()),
+            GetResponse::NotFound => None,
+        }
+    }
+
+    /// Returns the value if it is an integer.
+    ///
+    /// An item that was not found is returned as an `ItemNotFoundError`, and a value of another
+    /// type as an `InvalidArgumentError` that names the stored type.
+    ///
+    /// ```
+    /// # use momento::storage::GetResponse;
+    /// # use momento::MomentoErrorCode;
+    /// # let get_response = GetResponse::from(42);
+    /// assert_eq!(get_response.as_i64().unwrap(), 42);
+    /// assert_eq!(
+    ///     get_response.as_string().unwrap_err().error_code,
+    ///     MomentoErrorCode::InvalidArgumentError
+    /// );
+    /// ```
+    pub fn as_i64(&self) -> MomentoResult<i64> {
+        self.found_value()?.as_i64()
+    }
+
+    /// Returns the value if it is a double. See [as_i64](GetResponse::as_i64) for the errors.
+    pub fn as_f64(&self) -> MomentoResult<f64> {
+        self.found_value()?.as_f64()
+    }
+
+    /// Returns the value if it is a string. See [as_i64](GetResponse::as_i64) for the errors.
+    pub fn as_string(&self) -> MomentoResult<&str> {
+        self.found_value()?.as_string()
+    }
+
+    /// Returns the value if it is a byte array. See [as_i64](GetResponse::as_i64) for the errors.
+    pub fn as_bytes(&self) -> MomentoResult<&[u8]> {
+        self.found_value()?.as_bytes()
+    }
+
+    fn found_value(&self) -> MomentoResult<&StorageValue> {
+        match self {
+            GetResponse::Found { value } => Ok(value),
+            GetResponse::NotFound => Err(not_found_error()),
+        }
+    }
+}
+
 fn not_found_error() -> MomentoError {
     MomentoError {
         message: "Storage value not found".into(),
         error_code: MomentoErrorCode::ItemNotFoundError,
         inner_error: None,
         details: None,
+        context: None,
     }
 }
 
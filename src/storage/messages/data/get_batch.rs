@@ -0,0 +1,224 @@
+use std::collections::HashMap;
+use std::convert::{TryFrom, TryInto};
+
+use futures::TryStreamExt;
+
+use crate::storage::messages::data::get::{GetRequest, GetResponse};
+use crate::storage::messages::momento_storage_request::MomentoStorageRequest;
+use crate::storage::messages::storage_value::StorageValue;
+use crate::storage::PreviewStorageClient;
+use crate::utils::{bounded_fan_out, is_store_name_valid};
+use crate::{MomentoError, MomentoResult};
+
+/// The number of requests a storage batch request sends at once unless configured otherwise.
+pub const DEFAULT_STORAGE_BATCH_CONCURRENCY: usize = 10;
+
+/// Request to get a batch of items from a store.
+///
+/// The store has no batch get, so one get request is sent per key, with at most
+/// `max_concurrent_requests` of them in flight at once. If any of them fails, the batch fails
+/// with its error.
+///
+/// # Arguments
+///
+/// * `store_name` - name of the store
+/// * `keys` - list of keys to fetch
+///
+/// An empty batch returns an empty response without making a network call, even if the store
+/// does not exist. The store name is still validated.
+///
+/// # Optional Arguments
+///
+/// * `max_concurrent_requests` - The maximum number of get requests in flight at once. Defaults
+///   to [DEFAULT_STORAGE_BATCH_CONCURRENCY].
+///
+/// # Examples
+/// Assumes that a PreviewStorageClient named `storage_client` has been created and is available.
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// # use momento_test_util::create_doctest_storage_client;
+/// # tokio_test::block_on(async {
+/// # let (storage_client, store_name) = create_doctest_storage_client();
+/// use std::collections::HashMap;
+/// use std::convert::TryInto;
+/// use momento::storage::GetBatchRequest;
+/// # storage_client.put(&store_name, "key1", "value1").await?;
+/// # storage_client.put(&store_name, "key2", "value2").await?;
+///
+/// let get_batch_request = GetBatchRequest::new(&store_name, vec!["key1", "key2"])
+///     .max_concurrent_requests(2);
+/// let values: HashMap<String, String> = storage_client
+///     .send_request(get_batch_request)
+///     .await?
+///     .try_into()?;
+/// # assert_eq!(values.len(), 2);
+///
+/// for (key, value) in values {
+///     println!("Fetched value for key {}: {}", key, value);
+/// }
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+pub struct GetBatchRequest {
+    store_name: String,
+    keys: Vec<String>,
+    max_concurrent_requests: usize,
+}
+
+impl GetBatchRequest {
+    /// Construct a new GetBatchRequest.
+    pub fn new(
+        store_name: impl Into<String>,
+        keys: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        Self {
+            store_name: store_name.into(),
+            keys: keys.into_iter().map(Into::into).collect(),
+            max_concurrent_requests: DEFAULT_STORAGE_BATCH_CONCURRENCY,
+        }
+    }
+
+    /// Set the maximum number of get requests in flight at once. Values below 1 are treated as 1.
+    pub fn max_concurrent_requests(mut self, max_concurrent_requests: usize) -> Self {
+        self.max_concurrent_requests = max_concurrent_requests.max(1);
+        self
+    }
+}
+
+impl MomentoStorageRequest for GetBatchRequest {
+    type Response = GetBatchResponse;
+
+    async fn send(self, storage_client: &PreviewStorageClient) -> MomentoResult<GetBatchResponse> {
+        is_store_name_valid(&self.store_name)?;
+
+        let store_name = &self.store_name;
+        let gets = self.keys.into_iter().map(|key| {
+            let request = GetRequest::new(store_name, key.clone());
+            async move {
+                request
+                    .send(storage_client)
+                    .await
+                    .map(|response| (key, response))
+            }
+        });
+        let mut results = bounded_fan_out(gets, self.max_concurrent_requests);
+
+        let mut responses: HashMap<String, GetResponse> = HashMap::new();
+        while let Some((key, response)) = results.try_next().await? {
+            responses.insert(key, response);
+        }
+        Ok(GetBatchResponse {
+            results_dictionary: responses,
+        })
+    }
+}
+
+/// Response for a store get batch operation.
+///
+/// You can use `into()` to convert a `GetBatchResponse` into one of the following:
+/// - `HashMap<String, GetResponse>`
+/// - `HashMap<String, StorageValue>`
+///
+/// You can use `try_into()` to convert a `GetBatchResponse` into one of the following:
+/// - `HashMap<String, String>`
+/// - `HashMap<String, Vec<u8>>`
+/// - `HashMap<String, i64>`
+/// - `HashMap<String, f64>`
+///
+/// The `HashMap<String, GetResponse>` map holds every key, whether it was found or not. The
+/// other conversions leave out the keys that were not found. The `try_into()` conversions fail if
+/// a found value is not of the requested type.
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// # use momento_test_util::create_doctest_storage_client;
+/// # tokio_test::block_on(async {
+/// # let (storage_client, store_name) = create_doctest_storage_client();
+/// use std::collections::HashMap;
+/// use std::convert::TryInto;
+/// use momento::storage::{GetResponse, StorageValue};
+/// # storage_client.put(&store_name, "retries", 3).await?;
+///
+/// let response = storage_client.get_batch(&store_name, vec!["retries", "timeout"]).await?;
+/// let responses: HashMap<String, GetResponse> = response.into();
+/// # assert_eq!(responses.len(), 2);
+///
+/// let response = storage_client.get_batch(&store_name, vec!["retries", "timeout"]).await?;
+/// let found: HashMap<String, i64> = response.try_into()?;
+/// # assert_eq!(found, HashMap::from([("retries".to_string(), 3)]));
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct GetBatchResponse {
+    results_dictionary: HashMap<String, GetResponse>,
+}
+
+// (key, GetResponse) pairs -- does NOT filter out NotFound responses
+impl From<GetBatchResponse> for HashMap<String, GetResponse> {
+    fn from(response: GetBatchResponse) -> Self {
+        response.results_dictionary
+    }
+}
+
+// (key, StorageValue) pairs -- filters out NotFound responses
+impl From<GetBatchResponse> for HashMap<String, StorageValue> {
+    fn from(response: GetBatchResponse) -> Self {
+        response
+            .results_dictionary
+            .into_iter()
+            .filter_map(|(key, get_response)| match get_response {
+                GetResponse::Found { value } => Some((key, value)),
+                GetResponse::NotFound => None,
+            })
+            .collect()
+    }
+}
+
+fn found_values<T>(response: GetBatchResponse) -> Result<HashMap<String, T>, MomentoError>
+where
+    T: TryFrom<StorageValue, Error = MomentoError>,
+{
+    let values: HashMap<String, StorageValue> = response.into();
+    values
+        .into_iter()
+        .map(|(key, value)| value.try_into().map(|value| (key, value)))
+        .collect()
+}
+
+// (key, String value) pairs -- filters out NotFound responses
+impl TryFrom<GetBatchResponse> for HashMap<String, String> {
+    type Error = MomentoError;
+
+    fn try_from(response: GetBatchResponse) -> Result<Self, Self::Error> {
+        found_values(response)
+    }
+}
+
+// (key, bytes value) pairs -- filters out NotFound responses
+impl TryFrom<GetBatchResponse> for HashMap<String, Vec<u8>> {
+    type Error = MomentoError;
+
+    fn try_from(response: GetBatchResponse) -> Result<Self, Self::Error> {
+        found_values(response)
+    }
+}
+
+// (key, integer value) pairs -- filters out NotFound responses
+impl TryFrom<GetBatchResponse> for HashMap<String, i64> {
+    type Error = MomentoError;
+
+    fn try_from(response: GetBatchResponse) -> Result<Self, Self::Error> {
+        found_values(response)
+    }
+}
+
+// (key, double value) pairs -- filters out NotFound responses
+impl TryFrom<GetBatchResponse> for HashMap<String, f64> {
+    type Error = MomentoError;
+
+    fn try_from(response: GetBatchResponse) -> Result<Self, Self::Error> {
+        found_values(response)
+    }
+}
@@ -2,5 +2,11 @@
 pub mod delete;
 /// Contains the request and response types for getting an item from a store.
 pub mod get;
+/// Contains the request and response types for getting a batch of items from a store.
+pub mod get_batch;
 /// Contains the request and response types for putting an item in a store.
 pub mod put;
+/// Contains the request and response types for putting a batch of items in a store.
+pub mod put_batch;
+/// Contains the request and response types for putting an item in a store if it is absent.
+pub mod put_if_absent;
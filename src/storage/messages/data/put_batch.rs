@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+
+use futures::TryStreamExt;
+
+use crate::storage::messages::data::get_batch::DEFAULT_STORAGE_BATCH_CONCURRENCY;
+use crate::storage::messages::data::put::{PutRequest, PutResponse};
+use crate::storage::messages::momento_storage_request::MomentoStorageRequest;
+use crate::storage::messages::storage_value::StorageValue;
+use crate::storage::PreviewStorageClient;
+use crate::utils::{bounded_fan_out, is_store_name_valid};
+use crate::MomentoResult;
+
+/// Request to put a batch of items in a store.
+///
+/// The store has no batch put, so one put request is sent per item, with at most
+/// `max_concurrent_requests` of them in flight at once. If any of them fails, the batch fails
+/// with its error. The items that were already put are not rolled back.
+///
+/// # Arguments
+///
+/// * `store_name` - name of the store
+/// * `items` - (key, value) pairs to put
+///
+/// An empty batch returns an empty response without making a network call, even if the store
+/// does not exist. The store name is still validated.
+///
+/// # Optional Arguments
+///
+/// * `max_concurrent_requests` - The maximum number of put requests in flight at once. Defaults
+///   to [DEFAULT_STORAGE_BATCH_CONCURRENCY].
+///
+/// # Example
+/// Assumes that a PreviewStorageClient named `storage_client` has been created and is available.
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// # use momento_test_util::create_doctest_storage_client;
+/// # tokio_test::block_on(async {
+/// use std::collections::HashMap;
+/// use momento::storage::PutBatchRequest;
+/// # let (storage_client, store_name) = create_doctest_storage_client();
+///
+/// let items = HashMap::from([("region", "us-west-2"), ("tier", "gold")]);
+/// let put_batch_request = PutBatchRequest::new(&store_name, items).max_concurrent_requests(2);
+///
+/// storage_client.send_request(put_batch_request).await?;
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+pub struct PutBatchRequest {
+    store_name: String,
+    items: Vec<(String, StorageValue)>,
+    max_concurrent_requests: usize,
+}
+
+impl PutBatchRequest {
+    /// Construct a new PutBatchRequest.
+    pub fn new<K: Into<String>, V: Into<StorageValue>>(
+        store_name: impl Into<String>,
+        items: impl IntoIterator<Item = (K, V)>,
+    ) -> Self {
+        Self {
+            store_name: store_name.into(),
+            items: items
+                .into_iter()
+                .map(|(key, value)| (key.into(), value.into()))
+                .collect(),
+            max_concurrent_requests: DEFAULT_STORAGE_BATCH_CONCURRENCY,
+        }
+    }
+
+    /// Set the maximum number of put requests in flight at once. Values below 1 are treated as 1.
+    pub fn max_concurrent_requests(mut self, max_concurrent_requests: usize) -> Self {
+        self.max_concurrent_requests = max_concurrent_requests.max(1);
+        self
+    }
+}
+
+impl MomentoStorageRequest for PutBatchRequest {
+    type Response = PutBatchResponse;
+
+    async fn send(self, storage_client: &PreviewStorageClient) -> MomentoResult<PutBatchResponse> {
+        is_store_name_valid(&self.store_name)?;
+
+        let store_name = &self.store_name;
+        let puts = self.items.into_iter().map(|(key, value)| {
+            let request = PutRequest::new(store_name, key.clone(), value);
+            async move {
+                request
+                    .send(storage_client)
+                    .await
+                    .map(|response| (key, response))
+            }
+        });
+        let mut results = bounded_fan_out(puts, self.max_concurrent_requests);
+
+        let mut responses: HashMap<String, PutResponse> = HashMap::new();
+        while let Some((key, response)) = results.try_next().await? {
+            responses.insert(key, response);
+        }
+        Ok(PutBatchResponse {
+            results_dictionary: responses,
+        })
+    }
+}
+
+/// The response type for a successful put batch request.
+///
+/// You can use `into()` to convert a `PutBatchResponse` into a `HashMap<String, PutResponse>`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct PutBatchResponse {
+    results_dictionary: HashMap<String, PutResponse>,
+}
+
+impl From<PutBatchResponse> for HashMap<String, PutResponse> {
+    fn from(response: PutBatchResponse) -> Self {
+        response.results_dictionary
+    }
+}
@@ -0,0 +1,92 @@
+use crate::storage::messages::data::get::{GetRequest, GetResponse};
+use crate::storage::messages::data::put::PutRequest;
+use crate::storage::messages::momento_storage_request::MomentoStorageRequest;
+use crate::storage::messages::storage_value::StorageValue;
+use crate::storage::PreviewStorageClient;
+use crate::MomentoResult;
+
+/// Request to put a value in a store, but only if the key is not already present.
+///
+/// Items in a store have no time-to-live, so whether the value is stored depends only on whether
+/// the key exists. An item that was deleted counts as absent.
+///
+/// The store has no conditional put, so this reads the key with a get request and then writes it
+/// with a put request. The two requests are not atomic: if another process puts the same key
+/// between them, this request overwrites it. Use it to guard against keys written some time
+/// before, not against concurrent writers of the same key.
+///
+/// # Arguments
+///
+/// * `store_name` - The name of the store to add a value to.
+/// * `key` - key of the item whose value we are putting
+/// * `value` - data to store
+///
+/// # Example
+/// Assumes that a PreviewStorageClient named `storage_client` has been created and is available.
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// # use momento_test_util::create_doctest_storage_client;
+/// # tokio_test::block_on(async {
+/// use momento::storage::{PutIfAbsentRequest, PutIfAbsentResponse};
+/// # let (storage_client, store_name) = create_doctest_storage_client();
+///
+/// let put_request = PutIfAbsentRequest::new(&store_name, "key", "value1");
+///
+/// match storage_client.send_request(put_request).await? {
+///     PutIfAbsentResponse::Stored => println!("Value stored"),
+///     PutIfAbsentResponse::NotStored => println!("Key already present, value not stored"),
+/// }
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+pub struct PutIfAbsentRequest {
+    store_name: String,
+    key: String,
+    value: StorageValue,
+}
+
+impl PutIfAbsentRequest {
+    /// Construct a new PutIfAbsentRequest.
+    pub fn new(
+        store_name: impl Into<String>,
+        key: impl Into<String>,
+        value: impl Into<StorageValue>,
+    ) -> Self {
+        Self {
+            store_name: store_name.into(),
+            key: key.into(),
+            value: value.into(),
+        }
+    }
+}
+
+impl MomentoStorageRequest for PutIfAbsentRequest {
+    type Response = PutIfAbsentResponse;
+
+    async fn send(
+        self,
+        storage_client: &PreviewStorageClient,
+    ) -> MomentoResult<PutIfAbsentResponse> {
+        let existing = GetRequest::new(self.store_name.clone(), self.key.clone())
+            .send(storage_client)
+            .await?;
+        if let GetResponse::Found { .. } = existing {
+            return Ok(PutIfAbsentResponse::NotStored);
+        }
+
+        PutRequest::new(self.store_name, self.key, self.value)
+            .send(storage_client)
+            .await?;
+        Ok(PutIfAbsentResponse::Stored)
+    }
+}
+
+/// The response type for a put if absent request.
+#[derive(Debug, PartialEq, Eq)]
+pub enum PutIfAbsentResponse {
+    /// The key was absent and the value was stored.
+    Stored,
+    /// The key was already present, so the value was not stored.
+    NotStored,
+}
@@ -6,4 +6,4 @@ pub mod data;
 mod momento_storage_request;
 pub use momento_storage_request::MomentoStorageRequest;
 mod storage_value;
-pub use storage_value::StorageValue;
+pub use storage_value::{StorageValue, StorageValueType};
@@ -18,6 +18,101 @@ pub enum StorageValue {
     Double(f64),
 }
 
+/// The type of the value held by a [StorageValue].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StorageValueType {
+    /// A byte array.
+    Bytes,
+    /// A string.
+    String,
+    /// A 64-bit integer.
+    Integer,
+    /// A double.
+    Double,
+}
+
+impl std::fmt::Display for StorageValueType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            StorageValueType::Bytes => "a byte array",
+            StorageValueType::String => "a string",
+            StorageValueType::Integer => "an integer",
+            StorageValueType::Double => "a double",
+        };
+        f.write_str(name)
+    }
+}
+
+impl StorageValue {
+    /// Returns the type of the value, so you can branch on it before extracting the value.
+    ///
+    /// ```
+    /// use momento::storage::{StorageValue, StorageValueType};
+    ///
+    /// let value = StorageValue::from(42);
+    /// match value.value_type() {
+    ///     StorageValueType::Integer => println!("integer: {}", value.as_i64().unwrap()),
+    ///     StorageValueType::Double => println!("double: {}", value.as_f64().unwrap()),
+    ///     StorageValueType::String => println!("string: {}", value.as_string().unwrap()),
+    ///     StorageValueType::Bytes => println!("{} bytes", value.as_bytes().unwrap().len()),
+    /// }
+    /// ```
+    pub fn value_type(&self) -> StorageValueType {
+        match self {
+            StorageValue::Bytes(_) => StorageValueType::Bytes,
+            StorageValue::String(_) => StorageValueType::String,
+            StorageValue::Integer(_) => StorageValueType::Integer,
+            StorageValue::Double(_) => StorageValueType::Double,
+        }
+    }
+
+    /// Returns the value if it is an integer, or an `InvalidArgumentError` naming the stored
+    /// type if not.
+    pub fn as_i64(&self) -> Result<i64, MomentoError> {
+        match self {
+            StorageValue::Integer(value) => Ok(*value),
+            _ => Err(self.type_mismatch(StorageValueType::Integer)),
+        }
+    }
+
+    /// Returns the value if it is a double, or an `InvalidArgumentError` naming the stored
+    /// type if not.
+    pub fn as_f64(&self) -> Result<f64, MomentoError> {
+        match self {
+            StorageValue::Double(value) => Ok(*value),
+            _ => Err(self.type_mismatch(StorageValueType::Double)),
+        }
+    }
+
+    /// Returns the value if it is a string, or an `InvalidArgumentError` naming the stored
+    /// type if not.
+    pub fn as_string(&self) -> Result<&str, MomentoError> {
+        match self {
+            StorageValue::String(value) => Ok(value),
+            _ => Err(self.type_mismatch(StorageValueType::String)),
+        }
+    }
+
+    /// Returns the value if it is a byte array, or an `InvalidArgumentError` naming the stored
+    /// type if not.
+    pub fn as_bytes(&self) -> Result<&[u8], MomentoError> {
+        match self {
+            StorageValue::Bytes(value) => Ok(value),
+            _ => Err(self.type_mismatch(StorageValueType::Bytes)),
+        }
+    }
+
+    fn type_mismatch(&self, requested: StorageValueType) -> MomentoError {
+        MomentoError {
+            message: format!("item is {}, not {}", self.value_type(), requested),
+            error_code: MomentoErrorCode::InvalidArgumentError,
+            inner_error: None,
+            details: None,
+            context: None,
+        }
+    }
+}
+
 impl From<Vec<u8>> for StorageValue {
     fn from(bytes: Vec<u8>) -> Self {
         StorageValue::Bytes(bytes)
@@ -131,6 +226,7 @@ impl TryFrom<StorageValue> for Vec<u8> {
                 error_code: MomentoErrorCode::TypeError,
                 inner_error: None,
                 details: None,
+                context: None,
             }),
         }
     }
@@ -147,6 +243,7 @@ impl TryFrom<StorageValue> for String {
                 error_code: MomentoErrorCode::TypeError,
                 inner_error: None,
                 details: None,
+                context: None,
             }),
         }
     }
@@ -163,6 +260,7 @@ impl TryFrom<StorageValue> for i64 {
                 error_code: MomentoErrorCode::TypeError,
                 inner_error: None,
                 details: None,
+                context: None,
             }),
         }
     }
@@ -180,6 +278,7 @@ impl TryFrom<StorageValue> for i32 {
                     error_code: MomentoErrorCode::TypeError,
                     inner_error: None,
                     details: None,
+                    context: None,
                 }),
             },
             _ => Err(MomentoError {
@@ -187,6 +286,7 @@ impl TryFrom<StorageValue> for i32 {
                 error_code: MomentoErrorCode::TypeError,
                 inner_error: None,
                 details: None,
+                context: None,
             }),
         }
     }
@@ -203,6 +303,7 @@ impl TryFrom<StorageValue> for f64 {
                 error_code: MomentoErrorCode::TypeError,
                 inner_error: None,
                 details: None,
+                context: None,
             }),
         }
     }
@@ -221,6 +322,7 @@ impl TryFrom<StorageValue> for f32 {
                         error_code: MomentoErrorCode::TypeError,
                         inner_error: None,
                         details: None,
+                        context: None,
                     })
                 } else {
                     Ok(converted)
@@ -231,6 +333,7 @@ impl TryFrom<StorageValue> for f32 {
                 error_code: MomentoErrorCode::TypeError,
                 inner_error: None,
                 details: None,
+                context: None,
             }),
         }
     }
@@ -2,7 +2,7 @@
 pub mod messages;
 
 pub use messages::MomentoStorageRequest;
-pub use messages::StorageValue;
+pub use messages::{StorageValue, StorageValueType};
 
 pub use messages::control::create_store::{CreateStoreRequest, CreateStoreResponse};
 pub use messages::control::delete_store::{DeleteStoreRequest, DeleteStoreResponse};
@@ -10,7 +10,12 @@ pub use messages::control::list_stores::{ListStoresRequest, ListStoresResponse,
 
 pub use messages::data::delete::{DeleteRequest, DeleteResponse};
 pub use messages::data::get::{GetRequest, GetResponse};
+pub use messages::data::get_batch::{
+    GetBatchRequest, GetBatchResponse, DEFAULT_STORAGE_BATCH_CONCURRENCY,
+};
 pub use messages::data::put::{PutRequest, PutResponse};
+pub use messages::data::put_batch::{PutBatchRequest, PutBatchResponse};
+pub use messages::data::put_if_absent::{PutIfAbsentRequest, PutIfAbsentResponse};
 
 // Similar re-exporting with config::configuration and config::configurations
 // so import paths can be simplified to "momento::storage::Configuration" and
@@ -9,8 +9,10 @@ use crate::storage::preview_storage_client_builder::{
 };
 use crate::storage::{
     Configuration, CreateStoreRequest, CreateStoreResponse, DeleteRequest, DeleteResponse,
-    DeleteStoreRequest, DeleteStoreResponse, GetRequest, GetResponse, ListStoresRequest,
-    ListStoresResponse, MomentoStorageRequest, PutRequest, PutResponse, StorageValue,
+    DeleteStoreRequest, DeleteStoreResponse, GetBatchRequest, GetBatchResponse, GetRequest,
+    GetResponse, ListStoresRequest, ListStoresResponse, MomentoStorageRequest, PutBatchRequest,
+    PutBatchResponse, PutIfAbsentRequest, PutIfAbsentResponse, PutRequest, PutResponse,
+    StorageValue,
 };
 use crate::MomentoResult;
 
@@ -216,6 +218,81 @@ impl PreviewStorageClient {
         request.send(self).await
     }
 
+    /// Puts a batch of items in a Momento Store
+    ///
+    /// One put request is sent per item, with at most
+    /// [DEFAULT_STORAGE_BATCH_CONCURRENCY](crate::storage::DEFAULT_STORAGE_BATCH_CONCURRENCY) of
+    /// them in flight at once. Use a [PutBatchRequest] to change the limit.
+    ///
+    /// # Arguments
+    ///
+    /// * `store_name` - name of the store
+    /// * `items` - (key, value) pairs to put
+    ///
+    /// # Examples
+    /// Assumes that a PreviewStorageClient named `storage_client` has been created and is available.
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use momento_test_util::create_doctest_storage_client;
+    /// # tokio_test::block_on(async {
+    /// # let (storage_client, store_name) = create_doctest_storage_client();
+    /// use std::collections::HashMap;
+    ///
+    /// let items = HashMap::from([("retries", 3), ("timeout_ms", 500)]);
+    /// storage_client.put_batch(&store_name, items).await?;
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    /// You can also use the [send_request](PreviewStorageClient::send_request) method to put a batch of items using a [PutBatchRequest].
+    pub async fn put_batch<K: Into<String>, V: Into<StorageValue>>(
+        &self,
+        store_name: impl Into<String>,
+        items: impl IntoIterator<Item = (K, V)>,
+    ) -> MomentoResult<PutBatchResponse> {
+        let request = PutBatchRequest::new(store_name, items);
+        request.send(self).await
+    }
+
+    /// Puts an item in a Momento Store, but only if the key is not already present.
+    ///
+    /// Items in a store have no time-to-live, so this depends only on whether the key exists.
+    /// The check and the put are separate requests and are not atomic; see [PutIfAbsentRequest].
+    ///
+    /// # Arguments
+    ///
+    /// * `store_name` - name of the store
+    /// * `key` - key of the item whose value we are putting
+    /// * `value` - data to store
+    ///
+    /// # Examples
+    /// Assumes that a PreviewStorageClient named `storage_client` has been created and is available.
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use momento_test_util::create_doctest_storage_client;
+    /// # tokio_test::block_on(async {
+    /// # let (storage_client, store_name) = create_doctest_storage_client();
+    /// use momento::storage::PutIfAbsentResponse;
+    ///
+    /// match storage_client.put_if_absent(&store_name, "k1", "v1").await? {
+    ///     PutIfAbsentResponse::Stored => println!("Value stored"),
+    ///     PutIfAbsentResponse::NotStored => println!("Key already present"),
+    /// }
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    /// You can also use the [send_request](PreviewStorageClient::send_request) method to put an item using a [PutIfAbsentRequest].
+    pub async fn put_if_absent(
+        &self,
+        store_name: impl Into<String>,
+        key: impl Into<String>,
+        value: impl Into<StorageValue>,
+    ) -> MomentoResult<PutIfAbsentResponse> {
+        let request = PutIfAbsentRequest::new(store_name, key, value);
+        request.send(self).await
+    }
+
     /// Gets an item from a Momento Store
     ///
     /// # Arguments
@@ -252,6 +329,49 @@ impl PreviewStorageClient {
         request.send(self).await
     }
 
+    /// Gets a batch of items from a Momento Store
+    ///
+    /// One get request is sent per key, with at most
+    /// [DEFAULT_STORAGE_BATCH_CONCURRENCY](crate::storage::DEFAULT_STORAGE_BATCH_CONCURRENCY) of
+    /// them in flight at once. Use a [GetBatchRequest] to change the limit.
+    ///
+    /// # Arguments
+    ///
+    /// * `store_name` - name of the store
+    /// * `keys` - list of keys to fetch
+    ///
+    /// # Examples
+    /// Assumes that a PreviewStorageClient named `storage_client` has been created and is available.
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use momento_test_util::create_doctest_storage_client;
+    /// # tokio_test::block_on(async {
+    /// # let (storage_client, store_name) = create_doctest_storage_client();
+    /// use std::collections::HashMap;
+    /// use std::convert::TryInto;
+    /// # storage_client.put(&store_name, "key1", "value1").await?;
+    ///
+    /// let values: HashMap<String, String> = storage_client
+    ///     .get_batch(&store_name, vec!["key1", "key2"])
+    ///     .await?
+    ///     .try_into()?;
+    /// # assert_eq!(values, HashMap::from([("key1".to_string(), "value1".to_string())]));
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    /// You can also use the [send_request](PreviewStorageClient::send_request) method to get a batch of items using a [GetBatchRequest].
+    ///
+    /// For more examples of handling the response, see [GetBatchResponse].
+    pub async fn get_batch(
+        &self,
+        store_name: impl Into<String>,
+        keys: impl IntoIterator<Item = impl Into<String>>,
+    ) -> MomentoResult<GetBatchResponse> {
+        let request = GetBatchRequest::new(store_name, keys);
+        request.send(self).await
+    }
+
     /// Deletes an item in a Momento Store
     ///
     /// # Arguments
@@ -17,6 +17,7 @@ pub struct NeedsCredentialProvider {
 pub struct ReadyToBuild {
     configuration: Configuration,
     credential_provider: CredentialProvider,
+    user_agent: Option<String>,
 }
 
 impl PreviewStorageClientBuilder<NeedsConfiguration> {
@@ -38,13 +39,34 @@ impl PreviewStorageClientBuilder<NeedsCredentialProvider> {
         PreviewStorageClientBuilder(ReadyToBuild {
             configuration: self.0.configuration,
             credential_provider,
+            user_agent: Some(utils::user_agent("store")),
         })
     }
 }
 
 impl PreviewStorageClientBuilder<ReadyToBuild> {
+    /// Overrides the value of the `agent` header the client sends to identify the SDK.
+    pub fn with_user_agent(
+        self,
+        user_agent: impl Into<String>,
+    ) -> PreviewStorageClientBuilder<ReadyToBuild> {
+        PreviewStorageClientBuilder(ReadyToBuild {
+            user_agent: Some(user_agent.into()),
+            ..self.0
+        })
+    }
+
+    /// Stops the client from sending the `agent` and `runtime-version` headers that identify
+    /// the SDK and its version.
+    pub fn without_user_agent(self) -> PreviewStorageClientBuilder<ReadyToBuild> {
+        PreviewStorageClientBuilder(ReadyToBuild {
+            user_agent: None,
+            ..self.0
+        })
+    }
+
     pub fn build(self) -> MomentoResult<PreviewStorageClient> {
-        let agent_value = &utils::user_agent("store");
+        let agent_value = self.0.user_agent.as_deref();
 
         let data_channel = utils::connect_channel_lazily_configurable(
             &self.0.credential_provider.storage_endpoint,
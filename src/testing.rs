@@ -0,0 +1,158 @@
+use std::time::Duration;
+
+use log::warn;
+
+use crate::cache::{configurations, Configuration};
+use crate::{
+    CacheClient, CredentialProvider, ErrorSource, MomentoError, MomentoErrorCode, MomentoResult,
+};
+
+/// The default ttl of the clients created by [TestCache::new].
+pub const TEST_CACHE_DEFAULT_TTL: Duration = Duration::from_secs(60);
+
+/// A cache that exists for the duration of a test, for isolated integration tests.
+///
+/// Creating a TestCache creates a cache with a unique name and a [CacheClient] to use with it.
+/// The cache is deleted when the TestCache is dropped, or explicitly with
+/// [delete](TestCache::delete). Tests that each use their own TestCache can run in parallel
+/// without seeing each other's data.
+///
+/// Deleting on drop blocks the dropping thread until the cache is deleted. The delete is sent
+/// from a separate thread with its own runtime and client, so it works inside `#[tokio::test]`
+/// functions and other async code, where the runtime that created the client cannot be blocked
+/// on. If the delete fails, a warning is logged and the cache is left behind. Call `delete` to
+/// handle the error instead.
+///
+/// # Example
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// # tokio_test::block_on(async {
+/// use std::convert::TryInto;
+/// use momento::testing::TestCache;
+/// # let credential_provider = momento_test_util::get_test_credential_provider();
+///
+/// let test_cache = TestCache::new(credential_provider).await?;
+/// let client = test_cache.client();
+///
+/// client.set(test_cache.cache_name(), "key", "value").await?;
+/// let value: String = client.get(test_cache.cache_name(), "key").await?.try_into()?;
+/// assert_eq!(value, "value");
+///
+/// // The cache is deleted when test_cache is dropped
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+pub struct TestCache {
+    client: CacheClient,
+    cache_name: String,
+    credential_provider: CredentialProvider,
+    configuration: Configuration,
+    deleted: bool,
+}
+
+impl TestCache {
+    /// Creates a uniquely named cache, and a client for it with the
+    /// [Laptop](configurations::Laptop) configuration and a default ttl of
+    /// [TEST_CACHE_DEFAULT_TTL].
+    pub async fn new(credential_provider: CredentialProvider) -> MomentoResult<Self> {
+        Self::with_configuration(
+            credential_provider,
+            configurations::Laptop::latest(),
+            TEST_CACHE_DEFAULT_TTL,
+        )
+        .await
+    }
+
+    /// Creates a uniquely named cache, and a client for it with the given configuration and
+    /// default ttl.
+    pub async fn with_configuration(
+        credential_provider: CredentialProvider,
+        configuration: impl Into<Configuration>,
+        default_ttl: Duration,
+    ) -> MomentoResult<Self> {
+        let configuration = configuration.into();
+        let client = CacheClient::builder()
+            .default_ttl(default_ttl)
+            .configuration(configuration.clone())
+            .credential_provider(credential_provider.clone())
+            .build()?;
+        let cache_name = format!("rust-sdk-test-{:032x}", rand::random::<u128>());
+        client.create_cache(&cache_name).await?;
+        Ok(Self {
+            client,
+            cache_name,
+            credential_provider,
+            configuration,
+            deleted: false,
+        })
+    }
+
+    /// Returns the client to use with the cache.
+    pub fn client(&self) -> &CacheClient {
+        &self.client
+    }
+
+    /// Returns the name of the cache.
+    pub fn cache_name(&self) -> &str {
+        &self.cache_name
+    }
+
+    /// Deletes the cache now, returning any error instead of logging it.
+    pub async fn delete(mut self) -> MomentoResult<()> {
+        self.deleted = true;
+        self.client.delete_cache(&self.cache_name).await?;
+        Ok(())
+    }
+}
+
+impl Drop for TestCache {
+    fn drop(&mut self) {
+        if self.deleted {
+            return;
+        }
+        let cache_name = self.cache_name.clone();
+        let credential_provider = self.credential_provider.clone();
+        let configuration = self.configuration.clone();
+        // The client is tied to the runtime that created it, which may be the one this thread is
+        // running, so the delete gets a runtime and client of its own.
+        let deleted = std::thread::spawn(move || {
+            delete_cache(&cache_name, credential_provider, configuration)
+        })
+        .join();
+        match deleted {
+            Ok(Ok(())) => {}
+            Ok(Err(e)) => warn!("failed to delete test cache {}: {}", self.cache_name, e),
+            Err(_) => warn!(
+                "failed to delete test cache {}: the delete panicked",
+                self.cache_name
+            ),
+        }
+    }
+}
+
+fn delete_cache(
+    cache_name: &str,
+    credential_provider: CredentialProvider,
+    configuration: Configuration,
+) -> MomentoResult<()> {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(|e| MomentoError {
+            message: "Unable to start a runtime to delete the test cache".to_string(),
+            error_code: MomentoErrorCode::UnknownError,
+            inner_error: Some(ErrorSource::Unknown(Box::new(e))),
+            details: None,
+            context: None,
+        })?;
+    runtime.block_on(async {
+        let client = CacheClient::builder()
+            .default_ttl(TEST_CACHE_DEFAULT_TTL)
+            .configuration(configuration)
+            .credential_provider(credential_provider)
+            .build()?;
+        client.delete_cache(cache_name).await?;
+        Ok(())
+    })
+}
@@ -1,4 +1,5 @@
 pub mod publish;
+pub mod publish_stream;
 pub mod subscribe;
 pub mod subscription;
 
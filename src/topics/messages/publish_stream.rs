@@ -0,0 +1,124 @@
+use futures::{pin_mut, Stream, StreamExt};
+
+use crate::topics::{IntoTopicValue, MomentoRequest, PublishRequest};
+use crate::{MomentoError, MomentoErrorCode, MomentoResult, TopicClient};
+
+/// Publish every value from a stream to a topic, with a bounded number of publishes in flight.
+///
+/// The stream is drained until it ends. A failed publish does not stop the others: its error is
+/// recorded in the response together with the position of its value in the stream. Since
+/// publishes run concurrently, subscribers may receive the values out of order; use a
+/// concurrency of 1 to preserve the order of the stream.
+///
+/// # Arguments
+///
+/// * `cache_name` - The name of the cache to use as a namespace for the topic.
+/// * `topic` - The name of the topic to publish to.
+/// * `values` - The stream of values to publish.
+/// * `concurrency` - The maximum number of publishes in flight at once. Must be at least 1.
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// # tokio_test::block_on(async {
+/// use momento::topics::PublishStreamRequest;
+/// # let (topic_client, cache_name) = momento_test_util::create_doctest_topic_client();
+///
+/// let events = futures::stream::iter(vec!["created", "updated", "deleted"]);
+/// let request = PublishStreamRequest::new(cache_name, "topic", events, 10);
+/// let response = topic_client.send_request(request).await?;
+///
+/// println!("Published {} events", response.published);
+/// for (index, error) in response.errors {
+///     println!("Event {} was not published: {}", index, error);
+/// }
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+pub struct PublishStreamRequest<S> {
+    cache_name: String,
+    topic: String,
+    values: S,
+    concurrency: usize,
+}
+
+impl<S> PublishStreamRequest<S> {
+    /// Create a new PublishStreamRequest.
+    pub fn new(
+        cache_name: impl Into<String>,
+        topic: impl Into<String>,
+        values: S,
+        concurrency: usize,
+    ) -> Self {
+        Self {
+            cache_name: cache_name.into(),
+            topic: topic.into(),
+            values,
+            concurrency,
+        }
+    }
+}
+
+impl<S> MomentoRequest for PublishStreamRequest<S>
+where
+    S: Stream + Send,
+    S::Item: IntoTopicValue + Send,
+{
+    type Response = TopicPublishStreamResponse;
+
+    async fn send(self, topic_client: &TopicClient) -> MomentoResult<TopicPublishStreamResponse> {
+        if self.concurrency == 0 {
+            return Err(MomentoError {
+                message: "publish stream concurrency must be at least 1".to_string(),
+                error_code: MomentoErrorCode::InvalidArgumentError,
+                inner_error: None,
+                details: None,
+                context: None,
+            });
+        }
+
+        let cache_name = self.cache_name;
+        let topic = self.topic;
+        let publishes = self
+            .values
+            .enumerate()
+            .map(|(index, value)| {
+                let request = PublishRequest::new(cache_name.as_str(), topic.as_str(), value);
+                async move { (index, request.send(topic_client).await) }
+            })
+            .buffer_unordered(self.concurrency);
+        pin_mut!(publishes);
+
+        let mut response = TopicPublishStreamResponse {
+            published: 0,
+            errors: vec![],
+        };
+        while let Some((index, result)) = publishes.next().await {
+            match result {
+                Ok(_) => response.published += 1,
+                Err(e) => response.errors.push((index, e)),
+            }
+        }
+        response.errors.sort_by_key(|(index, _)| *index);
+        Ok(response)
+    }
+}
+
+/// The response type for a stream publish.
+#[derive(Debug)]
+pub struct TopicPublishStreamResponse {
+    /// The number of values that were published.
+    pub published: usize,
+    /// The values that failed to publish, as their position in the stream, counting from 0,
+    /// and the error they failed with. Sorted by position.
+    pub errors: Vec<(usize, MomentoError)>,
+}
+
+impl TopicPublishStreamResponse {
+    /// Returns the number of values that failed to publish.
+    pub fn failed(&self) -> usize {
+        self.errors.len()
+    }
+}
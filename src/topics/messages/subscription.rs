@@ -1,15 +1,16 @@
 use core::str;
 use std::convert::TryFrom;
 
-use futures::future::BoxFuture;
-use futures::{Future, FutureExt};
+use futures::future::{self, BoxFuture};
+use futures::{Future, FutureExt, Stream, StreamExt};
 use momento_protos::cache_client::pubsub::SubscriptionRequest;
 use momento_protos::cache_client::pubsub::{self, pubsub_client::PubsubClient};
 use tonic::{codegen::InterceptedService, transport::Channel};
 
 use crate::grpc::header_interceptor::HeaderInterceptor;
 use crate::utils::parse_string;
-use crate::MomentoError;
+use crate::{MomentoError, MomentoErrorCode, MomentoResult};
+use serde::de::DeserializeOwned;
 
 type ChannelType = InterceptedService<Channel, HeaderInterceptor>;
 
@@ -127,6 +128,52 @@ impl Subscription {
         }
     }
 
+    /// Deserializes each message on the subscription from JSON into a `T`.
+    ///
+    /// Messages that are not valid JSON for `T` are passed to `dead_letter` along with the
+    /// parsing error, and the stream moves on to the next message, so one malformed message
+    /// doesn't stall the consumer.
+    ///
+    /// ```no_run
+    /// # fn main() -> anyhow::Result<()> {
+    /// # tokio_test::block_on(async {
+    /// use futures::StreamExt;
+    /// # let (topic_client, cache_name) = momento_test_util::create_doctest_topic_client();
+    ///
+    /// #[derive(serde::Deserialize)]
+    /// struct Order {
+    ///     id: u64,
+    /// }
+    ///
+    /// let subscription = topic_client.subscribe(cache_name, "orders").await?;
+    /// let mut orders = subscription.json_values(|value, error| {
+    ///     eprintln!("Skipping malformed message {:?}: {}", value, error);
+    /// });
+    /// while let Some(order) = orders.next().await {
+    ///     let order: Order = order;
+    ///     println!("Received order {}", order.id);
+    /// }
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    pub fn json_values<T, F>(self, mut dead_letter: F) -> impl Stream<Item = T>
+    where
+        T: DeserializeOwned,
+        F: FnMut(SubscriptionValue, MomentoError),
+    {
+        self.filter_map(move |value| {
+            let item = match value.json() {
+                Ok(item) => Some(item),
+                Err(e) => {
+                    dead_letter(value, e);
+                    None
+                }
+            };
+            future::ready(item)
+        })
+    }
+
     /// Yeah this is a pain, but doing it here lets us yield a simpler-typed subscription stream.
     /// Also, we don't want to expose protocol buffers types outside of the sdk, so some type map
     /// had to happen. It's all one-off at the moment though so might as well leave it as one
@@ -320,6 +367,26 @@ pub struct SubscriptionValue {
     pub publisher_id: String,
 }
 
+impl SubscriptionValue {
+    /// Deserializes the published value from JSON into a `T`.
+    ///
+    /// Returns an `InvalidArgumentError` if the value is not valid JSON for `T`, as
+    /// [GetResponse::into_json](crate::cache::GetResponse::into_json) does.
+    pub fn json<T: DeserializeOwned>(&self) -> MomentoResult<T> {
+        let result = match &self.kind {
+            ValueKind::Text(text) => serde_json::from_str(text),
+            ValueKind::Binary(binary) => serde_json::from_slice(binary),
+        };
+        result.map_err(|e| MomentoError {
+            message: "topic value is not valid JSON for the requested type".to_string(),
+            error_code: MomentoErrorCode::InvalidArgumentError,
+            inner_error: Some(crate::ErrorSource::Unknown(Box::new(e))),
+            details: None,
+            context: None,
+        })
+    }
+}
+
 impl TryFrom<SubscriptionValue> for String {
     type Error = MomentoError;
 
@@ -397,3 +464,39 @@ impl IntoTopicValue for Vec<u8> {
         pubsub::topic_value::Kind::Binary(self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn value(kind: ValueKind) -> SubscriptionValue {
+        SubscriptionValue {
+            kind,
+            topic_sequence_number: 1,
+            topic_sequence_page: 0,
+            publisher_id: String::new(),
+        }
+    }
+
+    #[test]
+    fn json_parses_text_and_binary_values() {
+        let text: Vec<u32> = value(ValueKind::Text("[1, 2]".to_string()))
+            .json()
+            .expect("valid json");
+        assert_eq!(text, vec![1, 2]);
+
+        let binary: Vec<u32> = value(ValueKind::Binary(b"[3]".to_vec()))
+            .json()
+            .expect("valid json");
+        assert_eq!(binary, vec![3]);
+    }
+
+    #[test]
+    fn json_reports_malformed_values_as_invalid_arguments() {
+        let result: MomentoResult<Vec<u32>> = value(ValueKind::Text("not json".to_string())).json();
+        assert_eq!(
+            result.expect_err("invalid json").error_code,
+            MomentoErrorCode::InvalidArgumentError
+        );
+    }
+}
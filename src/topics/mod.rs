@@ -1,5 +1,6 @@
 mod messages;
 pub use messages::publish::{PublishRequest, TopicPublishResponse};
+pub use messages::publish_stream::{PublishStreamRequest, TopicPublishStreamResponse};
 pub use messages::subscribe::SubscribeRequest;
 pub use messages::subscription::*;
 pub use messages::MomentoRequest;
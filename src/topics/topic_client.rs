@@ -1,10 +1,14 @@
+use futures::Stream;
 use momento_protos::cache_client::pubsub;
 use tonic::{codegen::InterceptedService, transport::Channel};
 
 use crate::grpc::header_interceptor::HeaderInterceptor;
 use crate::topics::messages::MomentoRequest;
 use crate::topics::topic_client_builder::{NeedsConfiguration, TopicClientBuilder};
-use crate::topics::{Configuration, IntoTopicValue, PublishRequest, Subscription};
+use crate::topics::{
+    Configuration, IntoTopicValue, PublishRequest, PublishStreamRequest, Subscription,
+    TopicPublishStreamResponse,
+};
 use crate::{MomentoError, MomentoResult};
 
 use crate::topics::messages::publish::TopicPublishResponse;
@@ -114,6 +118,56 @@ impl TopicClient {
         request.send(self).await
     }
 
+    /// Publish every value from a stream to a topic, with at most `concurrency` publishes in
+    /// flight at once.
+    ///
+    /// The stream is drained until it ends. A failed publish does not stop the others; the
+    /// response counts the values that were published and lists the errors of those that were
+    /// not. Values may be published out of order unless `concurrency` is 1.
+    ///
+    /// # Arguments
+    ///
+    /// * `cache_name` - The name of the cache to use as a namespace for the topic.
+    /// * `topic` - The name of the topic to publish to.
+    /// * `values` - The stream of values to publish.
+    /// * `concurrency` - The maximum number of publishes in flight at once. Must be at least 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// # tokio_test::block_on(async {
+    /// use momento::TopicClient;
+    /// # let (topic_client, cache_name) = momento_test_util::create_doctest_topic_client();
+    ///
+    /// let events = futures::stream::iter(vec!["created", "updated", "deleted"]);
+    /// let response = topic_client
+    ///     .publish_stream(cache_name, "topic", events, 10)
+    ///     .await?;
+    ///
+    /// println!("Published {} events, {} failed", response.published, response.failed());
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    ///
+    /// You can also use the [send_request](TopicClient::send_request) method to publish a stream
+    /// using a [PublishStreamRequest].
+    pub async fn publish_stream<S>(
+        &self,
+        cache_name: impl Into<String>,
+        topic: impl Into<String>,
+        values: S,
+        concurrency: usize,
+    ) -> MomentoResult<TopicPublishStreamResponse>
+    where
+        S: Stream + Send,
+        S::Item: IntoTopicValue + Send,
+    {
+        let request = PublishStreamRequest::new(cache_name, topic, values, concurrency);
+        request.send(self).await
+    }
+
     /// Subscribe to a topic.
     /// The cache is used as a namespace for your topics, and it needs to exist.
     /// You don't create topics, you just start using them.
@@ -19,6 +19,7 @@ pub struct NeedsCredentialProvider {
 pub struct ReadyToBuild {
     configuration: Configuration,
     credential_provider: CredentialProvider,
+    user_agent: Option<String>,
 }
 
 impl TopicClientBuilder<NeedsConfiguration> {
@@ -40,13 +41,34 @@ impl TopicClientBuilder<NeedsCredentialProvider> {
         TopicClientBuilder(ReadyToBuild {
             configuration: self.0.configuration,
             credential_provider,
+            user_agent: Some(utils::user_agent("topic")),
         })
     }
 }
 
 impl TopicClientBuilder<ReadyToBuild> {
+    /// Overrides the value of the `agent` header the client sends to identify the SDK.
+    pub fn with_user_agent(
+        self,
+        user_agent: impl Into<String>,
+    ) -> TopicClientBuilder<ReadyToBuild> {
+        TopicClientBuilder(ReadyToBuild {
+            user_agent: Some(user_agent.into()),
+            ..self.0
+        })
+    }
+
+    /// Stops the client from sending the `agent` and `runtime-version` headers that identify
+    /// the SDK and its version.
+    pub fn without_user_agent(self) -> TopicClientBuilder<ReadyToBuild> {
+        TopicClientBuilder(ReadyToBuild {
+            user_agent: None,
+            ..self.0
+        })
+    }
+
     pub fn build(self) -> MomentoResult<TopicClient> {
-        let agent_value = &utils::user_agent("topic");
+        let agent_value = self.0.user_agent.as_deref();
         let channel = connect_channel_lazily(&self.0.credential_provider.cache_endpoint)?;
         let authorized_channel = InterceptedService::new(
             channel,
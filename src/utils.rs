@@ -6,13 +6,21 @@ use tonic::{
 };
 
 use crate::{
-    auth::{expiration::ExpiresIn, Expiration},
+    auth::{
+        expiration::ExpiresIn,
+        permissions::disposable_token_scope::{
+            CacheItemKeyPrefix, CacheItemSelector, DisposableTokenScope,
+        },
+        Expiration, ExpiresAt,
+    },
     MomentoResult,
 };
 use crate::{
     config::grpc_configuration::GrpcConfiguration,
     {ErrorSource, MomentoError, MomentoErrorCode},
 };
+use futures::stream::BoxStream;
+use futures::{Future, StreamExt};
 use std::convert::TryFrom;
 use std::time::{self, Duration};
 
@@ -31,6 +39,7 @@ pub(crate) fn request_meta_data<T>(
             error_code: MomentoErrorCode::InvalidArgumentError,
             inner_error: Some(crate::ErrorSource::Unknown(Box::new(e))),
             details: None,
+            context: None,
         })
 }
 
@@ -47,6 +56,7 @@ pub(crate) fn store_request_meta_data<T>(
             error_code: MomentoErrorCode::InvalidArgumentError,
             inner_error: Some(ErrorSource::Unknown(Box::new(e))),
             details: None,
+            context: None,
         })
 }
 
@@ -63,6 +73,33 @@ pub(crate) fn prep_request_with_timeout<R>(
     Ok(request)
 }
 
+/// Records the length of a request's key on the span of the request being sent when the
+/// `tracing` feature is enabled, and does nothing otherwise.
+#[inline]
+pub(crate) fn record_key_length(key: &[u8]) {
+    #[cfg(feature = "tracing")]
+    tracing::Span::current().record("key_length", key.len());
+    #[cfg(not(feature = "tracing"))]
+    let _ = key;
+}
+
+/// Runs `futures` with at most `max_concurrency` of them in flight at once, yielding their
+/// outputs in the order they complete. Used by batch requests that send one request per item.
+pub(crate) fn bounded_fan_out<'a, I>(
+    futures: I,
+    max_concurrency: usize,
+) -> BoxStream<'a, <I::Item as Future>::Output>
+where
+    I: IntoIterator,
+    I::IntoIter: Send + 'a,
+    I::Item: Future + Send + 'a,
+    <I::Item as Future>::Output: Send + 'a,
+{
+    futures::stream::iter(futures)
+        .buffer_unordered(max_concurrency.max(1))
+        .boxed()
+}
+
 pub(crate) fn prep_storage_request_with_timeout<R>(
     store_name: &str,
     timeout: Duration,
@@ -88,11 +125,37 @@ pub(crate) fn is_ttl_valid(ttl: Duration) -> MomentoResult<()> {
             error_code: MomentoErrorCode::InvalidArgumentError,
             inner_error: None,
             details: None,
+            context: None,
         });
     }
     Ok(())
 }
 
+pub(crate) fn expires_at_to_ttl(expires_at: &ExpiresAt) -> MomentoResult<Duration> {
+    if !expires_at.does_expire() {
+        return Err(MomentoError {
+            message: "Expiry time must be a finite epoch timestamp".into(),
+            error_code: MomentoErrorCode::InvalidArgumentError,
+            inner_error: None,
+            details: None,
+            context: None,
+        });
+    }
+    match expires_at.remaining() {
+        Some(ttl) => Ok(ttl),
+        None => Err(MomentoError {
+            message: format!(
+                "Expiry time provided, {}, must be in the future",
+                expires_at.epoch()
+            ),
+            error_code: MomentoErrorCode::InvalidArgumentError,
+            inner_error: None,
+            details: None,
+            context: None,
+        }),
+    }
+}
+
 pub(crate) fn is_cache_name_valid(cache_name: &str) -> Result<(), MomentoError> {
     if cache_name.trim().is_empty() {
         return Err(MomentoError {
@@ -100,6 +163,7 @@ pub(crate) fn is_cache_name_valid(cache_name: &str) -> Result<(), MomentoError>
             error_code: MomentoErrorCode::InvalidArgumentError,
             inner_error: None,
             details: None,
+            context: None,
         });
     }
     Ok(())
@@ -112,6 +176,7 @@ pub(crate) fn is_store_name_valid(store_name: &str) -> Result<(), MomentoError>
             error_code: MomentoErrorCode::InvalidArgumentError,
             inner_error: None,
             details: None,
+            context: None,
         });
     }
     Ok(())
@@ -124,6 +189,7 @@ pub(crate) fn is_disposable_token_expiry_valid(expires_in: ExpiresIn) -> Result<
             error_code: MomentoErrorCode::InvalidArgumentError,
             inner_error: None,
             details: None,
+            context: None,
         });
     }
     if expires_in.to_seconds() < 1 {
@@ -132,6 +198,7 @@ pub(crate) fn is_disposable_token_expiry_valid(expires_in: ExpiresIn) -> Result<
             error_code: MomentoErrorCode::InvalidArgumentError,
             inner_error: None,
             details: None,
+            context: None,
         });
     }
     if expires_in.to_seconds() > 60 * 60 {
@@ -140,6 +207,7 @@ pub(crate) fn is_disposable_token_expiry_valid(expires_in: ExpiresIn) -> Result<
             error_code: MomentoErrorCode::InvalidArgumentError,
             inner_error: None,
             details: None,
+            context: None,
         });
     }
     Ok(())
@@ -152,11 +220,37 @@ pub(crate) fn is_disposable_token_id_valid(token_id: &str) -> Result<(), Momento
             error_code: MomentoErrorCode::InvalidArgumentError,
             inner_error: None,
             details: None,
+            context: None,
         });
     }
     Ok(())
 }
 
+pub(crate) fn is_disposable_token_scope_valid(
+    scope: &DisposableTokenScope,
+) -> Result<(), MomentoError> {
+    let permissions = match scope {
+        DisposableTokenScope::DisposableTokenPermissions(permissions) => &permissions.permissions,
+        DisposableTokenScope::Permissions(_) => return Ok(()),
+    };
+    for permission in permissions {
+        if let CacheItemSelector::CacheItemKeyPrefix(CacheItemKeyPrefix { key_prefix }) =
+            &permission.item_selector
+        {
+            if key_prefix.is_empty() {
+                return Err(MomentoError {
+                    message: "Key prefix must not be empty".into(),
+                    error_code: MomentoErrorCode::InvalidArgumentError,
+                    inner_error: None,
+                    details: None,
+                    context: None,
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
 #[derive(Debug, Error)]
 pub(crate) enum ChannelConnectError {
     #[error("URI was invalid")]
@@ -174,12 +268,14 @@ impl From<ChannelConnectError> for MomentoError {
                 error_code: MomentoErrorCode::InvalidArgumentError,
                 inner_error: Some(ErrorSource::InvalidUri(err)),
                 details: None,
+                context: None,
             },
             ChannelConnectError::Connection(err) => MomentoError {
                 message: "connection failed".into(),
                 error_code: MomentoErrorCode::InternalServerError,
                 inner_error: Some(ErrorSource::Unknown(err.into())),
                 details: None,
+                context: None,
             },
         }
     }
@@ -200,6 +296,9 @@ pub(crate) fn connect_channel_lazily_configurable(
 ) -> Result<Channel, ChannelConnectError> {
     let uri = Uri::try_from(uri_string)?;
     let mut channel_builder = Channel::builder(uri).tls_config(ClientTlsConfig::default())?;
+    if let Some(connect_timeout) = grpc_config.connect_timeout {
+        channel_builder = channel_builder.connect_timeout(connect_timeout);
+    }
     if let Some(keep_alive_while_idle) = grpc_config.keep_alive_while_idle {
         channel_builder = channel_builder.keep_alive_while_idle(keep_alive_while_idle);
     }
@@ -222,6 +321,7 @@ pub(crate) fn parse_string(raw: Vec<u8>) -> MomentoResult<String> {
         error_code: MomentoErrorCode::TypeError,
         inner_error: Some(ErrorSource::Unknown(Box::new(e))),
         details: None,
+        context: None,
     })
 }
 
@@ -317,8 +417,10 @@ pub(crate) mod fmt {
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
+    use std::sync::atomic::{AtomicUsize, Ordering};
 
     use super::*;
+    use crate::auth::DisposableTokenScopes;
 
     #[test]
     fn test_request_meta_data() {
@@ -356,6 +458,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_expires_at_to_ttl() {
+        let now = time::SystemTime::now()
+            .duration_since(time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let result = expires_at_to_ttl(&ExpiresAt::from_epoch(now + 60));
+        assert!(result.is_ok(), "Expected Ok, but got {:?}", result);
+        assert!(result.unwrap() <= Duration::from_secs(60));
+
+        let result = expires_at_to_ttl(&ExpiresAt::from_epoch(now - 60));
+        assert!(result.is_err(), "Expected Err, but got {:?}", result);
+        assert_eq!(
+            result.unwrap_err().error_code,
+            MomentoErrorCode::InvalidArgumentError
+        );
+
+        let result = expires_at_to_ttl(&ExpiresAt::new(None));
+        assert!(result.is_err(), "Expected Err, but got {:?}", result);
+    }
+
     #[test]
     fn test_is_cache_name_valid() {
         let cache_name = "my_cache";
@@ -388,6 +511,7 @@ mod tests {
             keep_alive_interval: Some(Duration::from_secs(30)),
             keep_alive_timeout: Some(Duration::from_secs(60)),
             deadline: Duration::from_secs(30),
+            connect_timeout: Some(Duration::from_secs(60)),
             num_channels: 1,
         };
         let result = connect_channel_lazily_configurable(uri_string, grpc_config);
@@ -447,6 +571,20 @@ mod tests {
         assert!(is_disposable_token_id_valid(long_token_id).is_err());
     }
 
+    #[test]
+    fn test_is_disposable_token_scope_valid() {
+        let scope = DisposableTokenScopes::cache_key_prefix_read_write("cache", "prefix");
+        assert!(is_disposable_token_scope_valid(&scope).is_ok());
+
+        let scope = DisposableTokenScopes::cache_key_prefix_read_write("cache", "");
+        let error = is_disposable_token_scope_valid(&scope).unwrap_err();
+        assert_eq!(error.error_code, MomentoErrorCode::InvalidArgumentError);
+
+        // Only key prefixes are checked
+        let scope = DisposableTokenScopes::cache_key_read_write("cache", "");
+        assert!(is_disposable_token_scope_valid(&scope).is_ok());
+    }
+
     #[test]
     fn test_is_disposable_token_expiry_valid() {
         let expires_in = ExpiresIn::seconds(30);
@@ -470,4 +608,25 @@ mod tests {
         let never_expires = ExpiresIn::never();
         assert!(is_disposable_token_expiry_valid(never_expires).is_err());
     }
+
+    #[tokio::test]
+    async fn bounded_fan_out_respects_the_concurrency_cap() {
+        let in_flight = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+        let futures = (0..1000).map(|i| {
+            let (in_flight, peak) = (&in_flight, &peak);
+            async move {
+                let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                tokio::task::yield_now().await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+                i
+            }
+        });
+
+        let mut results: Vec<usize> = bounded_fan_out(futures, 10).collect().await;
+        results.sort_unstable();
+        assert_eq!(results, (0..1000).collect::<Vec<_>>());
+        assert_eq!(peak.load(Ordering::SeqCst), 10);
+    }
 }
@@ -5,7 +5,8 @@ use momento::auth::Expiration;
 use momento::auth::Permissions;
 use momento::{
     auth::{
-        DisposableTokenScope, DisposableTokenScopes, ExpiresIn, GenerateDisposableTokenResponse,
+        DisposableTokenScope, DisposableTokenScopes, ExpiresIn, GenerateDisposableTokenRequest,
+        GenerateDisposableTokenResponse,
     },
     CacheClient, CredentialProvider, MomentoResult, TopicClient,
 };
@@ -70,18 +71,24 @@ async fn assert_get_failure(
     key: &str,
 ) -> MomentoResult<()> {
     match cache_client.get(cache_name, key).await {
-        Ok(_) => Err(MomentoError {
-            message: format!(
+        Ok(_) => Err(MomentoError::new(
+            MomentoErrorCode::UnknownError,
+            format!(
                 "Expected getting key '{}' from cache '{}' to fail but it did not",
                 key, cache_name
             ),
-            error_code: MomentoErrorCode::UnknownError,
-            inner_error: None,
-            details: None,
-        }),
+        )),
         Err(e) => {
             match e.error_code {
-                MomentoErrorCode::PermissionError => {}
+                MomentoErrorCode::PermissionError => {
+                    assert!(e.is_permission_denied());
+                    assert!(
+                        e.message.contains(cache_name),
+                        "Expected permission error message to name cache '{}', got '{}'",
+                        cache_name,
+                        e.message
+                    );
+                }
                 MomentoErrorCode::AuthenticationError => {}
                 _ => {
                     eprintln!(
@@ -121,15 +128,13 @@ async fn assert_set_failure(
     value: &str,
 ) -> MomentoResult<()> {
     match cache_client.set(cache_name, key, value).await {
-        Ok(_) => Err(MomentoError {
-            message: format!(
+        Ok(_) => Err(MomentoError::new(
+            MomentoErrorCode::UnknownError,
+            format!(
                 "Expected setting value '{}' for key '{}' from cache '{}' to fail but it did not",
                 value, key, cache_name
             ),
-            error_code: MomentoErrorCode::UnknownError,
-            inner_error: None,
-            details: None,
-        }),
+        )),
         Err(e) => {
             match e.error_code {
                 MomentoErrorCode::PermissionError => {}
@@ -172,15 +177,13 @@ async fn assert_publish_failure(
     value: &str,
 ) -> MomentoResult<()> {
     match topic_client.publish(cache_name, topic_name, value).await {
-        Ok(_) => Err(MomentoError {
-            message: format!(
+        Ok(_) => Err(MomentoError::new(
+            MomentoErrorCode::UnknownError,
+            format!(
                 "Expected publishing value '{}' for topic '{}' in cache '{}' to fail but it did not",
                 value, topic_name, cache_name
             ),
-            error_code: MomentoErrorCode::UnknownError,
-            inner_error: None,
-            details: None,
-        }),
+        )),
         Err(e) => {
             match e.error_code {
                 MomentoErrorCode::PermissionError => {},
@@ -221,15 +224,13 @@ async fn assert_subscribe_failure(
     topic_name: &str,
 ) -> MomentoResult<()> {
     match topic_client.subscribe(cache_name, topic_name).await {
-        Ok(_) => Err(MomentoError {
-            message: format!(
+        Ok(_) => Err(MomentoError::new(
+            MomentoErrorCode::UnknownError,
+            format!(
                 "Expected subscribe to topic '{}' in cache '{}' to fail but it did not",
                 topic_name, cache_name
             ),
-            error_code: MomentoErrorCode::UnknownError,
-            inner_error: None,
-            details: None,
-        }),
+        )),
         Err(e) => {
             match e.error_code {
                 MomentoErrorCode::PermissionError => {}
@@ -789,6 +790,23 @@ mod disposable_tokens_cache_key_prefix {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_cache_key_prefix_must_not_be_empty() -> MomentoResult<()> {
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+        let result = CACHE_TEST_STATE
+            .auth_client
+            .generate_disposable_token(
+                DisposableTokenScopes::cache_key_prefix_read_write(cache_name.clone(), ""),
+                ExpiresIn::minutes(5),
+            )
+            .await;
+        assert_eq!(
+            result.unwrap_err().error_code,
+            MomentoErrorCode::InvalidArgumentError
+        );
+        Ok(())
+    }
 }
 
 mod disposable_tokens_cache {
@@ -1433,12 +1451,10 @@ mod disposable_tokens_all_data {
 
         // cannot create caches
         match cc.create_cache(second_cache).await {
-            Ok(_) => Err(MomentoError {
-                message: "Expected creating cache using AllDataReadWrite disposable token to fail but it did not".into(),
-                error_code: MomentoErrorCode::UnknownError,
-                inner_error: None,
-                details: None,
-            }),
+            Ok(_) => Err(MomentoError::new(
+                MomentoErrorCode::UnknownError,
+                "Expected creating cache using AllDataReadWrite disposable token to fail but it did not",
+            )),
             Err(e) => {
                 match e.error_code {
                     MomentoErrorCode::PermissionError => Ok(()),
@@ -1455,12 +1471,10 @@ mod disposable_tokens_all_data {
 
         // cannot delete caches
         match cc.delete_cache(second_cache).await {
-            Ok(_) => Err(MomentoError {
-                message: "Expected deleting cache using AllDataReadWrite disposable token to fail but it did not".into(),
-                error_code: MomentoErrorCode::UnknownError,
-                inner_error: None,
-                details: None,
-            }),
+            Ok(_) => Err(MomentoError::new(
+                MomentoErrorCode::UnknownError,
+                "Expected deleting cache using AllDataReadWrite disposable token to fail but it did not",
+            )),
             Err(e) => {
                 match e.error_code {
                     MomentoErrorCode::PermissionError => Ok(()),
@@ -1531,3 +1545,67 @@ mod disposable_tokens_expiry {
         Ok(())
     }
 }
+
+mod disposable_tokens_refresh {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_refresh_keeps_scope() -> MomentoResult<()> {
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+        let test_item = TestScalar::new();
+        let scope =
+            DisposableTokenScopes::cache_key_read_write(cache_name.clone(), test_item.key());
+        let response = CACHE_TEST_STATE
+            .auth_client
+            .send_request(
+                GenerateDisposableTokenRequest::new(scope.clone(), ExpiresIn::minutes(5))
+                    .token_id("refresh-test".to_string()),
+            )
+            .await?;
+
+        let refreshed = CACHE_TEST_STATE
+            .auth_client
+            .refresh_disposable_token(&response)
+            .await?;
+        assert_ne!(
+            refreshed.clone().auth_token(),
+            response.clone().auth_token()
+        );
+        assert_eq!(refreshed.scope(), &scope);
+        assert_eq!(refreshed.expires_in(), &ExpiresIn::minutes(5));
+        assert_eq!(refreshed.token_id(), Some("refresh-test"));
+        assert!(refreshed.clone().expires_at().epoch() >= response.clone().expires_at().epoch());
+
+        // The refreshed token has the same permissions as the original
+        let creds = new_credential_provider_from_token(refreshed.auth_token());
+        let cc = new_cache_client(creds);
+        assert_set_success(&cc, cache_name, test_item.key(), test_item.value()).await?;
+        assert_get_success(&cc, cache_name, test_item.key()).await?;
+        assert_get_failure(&cc, cache_name, &unique_key()).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_refresh_expired_token() -> MomentoResult<()> {
+        let response = CACHE_TEST_STATE
+            .auth_client
+            .generate_disposable_token(
+                Permissions::all_data_read_write().into(),
+                ExpiresIn::seconds(1),
+            )
+            .await?;
+
+        tokio::time::sleep(Duration::from_secs(2)).await;
+
+        let result = CACHE_TEST_STATE
+            .auth_client
+            .refresh_disposable_token(&response)
+            .await;
+        assert_eq!(
+            result.unwrap_err().error_code,
+            MomentoErrorCode::FailedPreconditionError
+        );
+        Ok(())
+    }
+}
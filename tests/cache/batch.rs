@@ -6,7 +6,9 @@ use std::collections::HashMap;
 use std::convert::TryInto;
 
 mod batch_get_set {
-    use momento::cache::SetBatchRequest;
+    use std::time::Duration;
+
+    use momento::cache::{GetBatchRequest, SetBatchRequest};
 
     use super::*;
 
@@ -29,6 +31,24 @@ mod batch_get_set {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn empty_batches_skip_the_network_call() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        // The cache doesn't exist, so these only succeed if no request is sent
+        let cache_name = unique_cache_name();
+
+        let keys: Vec<String> = vec![];
+        let response = client.get_batch(&cache_name, keys).await?;
+        let get_responses: HashMap<Vec<u8>, GetResponse> = response.into();
+        assert!(get_responses.is_empty());
+
+        let items: HashMap<String, String> = HashMap::new();
+        let response = client.set_batch(&cache_name, items).await?;
+        let set_responses: HashMap<Vec<u8>, SetResponse> = response.into();
+        assert!(set_responses.is_empty());
+        Ok(())
+    }
+
     #[tokio::test]
     async fn get_batch_happy_path_with_all_misses() -> MomentoResult<()> {
         let client = &CACHE_TEST_STATE.client;
@@ -211,6 +231,25 @@ mod batch_get_set {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn get_batch_cancelled_returns_partial_results() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = CACHE_TEST_STATE.cache_name.as_str();
+        let keys = vec![unique_key(), unique_key(), unique_key()];
+
+        let request = GetBatchRequest::new(cache_name, keys.clone()).cancel_on(async {});
+        let response = client.send_request(request).await?;
+        assert!(response.is_cancelled());
+        let get_responses: HashMap<Vec<u8>, GetResponse> = response.into();
+        assert!(get_responses.len() < keys.len());
+
+        let response = client.get_batch(cache_name, keys.clone()).await?;
+        assert!(!response.is_cancelled());
+        let get_responses: HashMap<Vec<u8>, GetResponse> = response.into();
+        assert_eq!(get_responses.len(), keys.len());
+        Ok(())
+    }
+
     #[tokio::test]
     async fn set_batch_invalid_cache_name() -> MomentoResult<()> {
         let client = &CACHE_TEST_STATE.client;
@@ -266,4 +305,64 @@ mod batch_get_set {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn set_batch_with_item_ttls() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = CACHE_TEST_STATE.cache_name.as_str();
+        let short = TestScalar::new();
+        let long = TestScalar::new();
+        let default = TestScalar::new();
+
+        let set_batch_request = SetBatchRequest::with_item_ttls(
+            cache_name,
+            vec![
+                (short.key(), short.value(), Some(Duration::from_secs(30))),
+                (long.key(), long.value(), Some(Duration::from_secs(3600))),
+                (default.key(), default.value(), None),
+            ],
+        )
+        .ttl(Duration::from_secs(600));
+        client.send_request(set_batch_request).await?;
+
+        let short_ttl: Duration = client
+            .item_get_ttl(cache_name, short.key())
+            .await?
+            .try_into()?;
+        assert!(short_ttl <= Duration::from_secs(30));
+        let long_ttl: Duration = client
+            .item_get_ttl(cache_name, long.key())
+            .await?
+            .try_into()?;
+        assert!(long_ttl > Duration::from_secs(600));
+        let default_ttl: Duration = client
+            .item_get_ttl(cache_name, default.key())
+            .await?
+            .try_into()?;
+        assert!(default_ttl > Duration::from_secs(30) && default_ttl <= Duration::from_secs(600));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn large_batches_with_max_concurrent_requests() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = CACHE_TEST_STATE.cache_name.as_str();
+        let items: HashMap<String, String> = (0..1000)
+            .map(|i| (unique_key(), format!("value-{}", i)))
+            .collect();
+
+        let set_batch_request =
+            SetBatchRequest::new(cache_name, items.clone()).max_concurrent_requests(10);
+        let set_responses: HashMap<String, SetResponse> =
+            client.send_request(set_batch_request).await?.try_into()?;
+        assert_eq!(set_responses.len(), items.len());
+
+        let get_batch_request =
+            GetBatchRequest::new(cache_name, items.keys().cloned().collect::<Vec<_>>())
+                .max_concurrent_requests(10);
+        let values: HashMap<String, String> =
+            client.send_request(get_batch_request).await?.try_into()?;
+        assert_eq!(values, items);
+        Ok(())
+    }
 }
@@ -5,6 +5,8 @@ use momento_test_util::CACHE_TEST_STATE;
 use momento_test_util::{unique_cache_name, TestScalar};
 
 mod create_delete_list_cache {
+    use futures::TryStreamExt;
+
     use super::*;
 
     #[tokio::test]
@@ -24,6 +26,31 @@ mod create_delete_list_cache {
         assert_eq!(result, CreateCacheResponse::AlreadyExists {});
         Ok(())
     }
+
+    #[tokio::test]
+    async fn cache_exists() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+        assert!(client.cache_exists(cache_name).await?);
+        assert!(!client.cache_exists(unique_cache_name()).await?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn list_caches_stream_includes_test_cache() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+        let caches: Vec<_> = client.list_caches_stream().try_collect().await?;
+        assert!(
+            caches
+                .iter()
+                .any(|cache_info| &cache_info.name == cache_name),
+            "Expected {} to be in streamed list of caches: {:#?}",
+            cache_name,
+            caches
+        );
+        Ok(())
+    }
 }
 
 mod flush_cache {
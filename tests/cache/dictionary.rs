@@ -1,9 +1,10 @@
 use momento::cache::{
-    DictionaryFetchResponse, DictionaryGetFieldResponse, DictionaryGetFieldsResponse,
-    DictionaryIncrementResponse, DictionaryLengthResponse, DictionaryRemoveFieldResponse,
-    DictionaryRemoveFieldsResponse, DictionarySetFieldResponse, DictionarySetFieldsResponse,
+    DictionaryCopyResponse, DictionaryFetchResponse, DictionaryGetFieldBatchRequest,
+    DictionaryGetFieldResponse, DictionaryGetFieldsResponse, DictionaryIncrementResponse,
+    DictionaryLengthResponse, DictionaryRemoveFieldResponse, DictionaryRemoveFieldsResponse,
+    DictionarySetFieldResponse, DictionarySetFieldsResponse,
 };
-use momento::{MomentoError, MomentoErrorCode, MomentoResult};
+use momento::{ErrorContext, MomentoError, MomentoErrorCode, MomentoResult};
 use momento_test_util::{
     unique_cache_name, unique_key, unique_value, TestDictionary, CACHE_TEST_STATE,
 };
@@ -21,6 +22,66 @@ fn assert_fetched_dictionary_equals_test_data(
     Ok(())
 }
 
+mod dictionary_copy {
+    use super::*;
+
+    #[tokio::test]
+    async fn replaces_destination() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+
+        let source = TestDictionary::new();
+        let destination = TestDictionary::new();
+        client
+            .dictionary_set_fields(cache_name, source.name(), source.value().clone())
+            .await?;
+        client
+            .dictionary_set_fields(cache_name, destination.name(), destination.value().clone())
+            .await?;
+
+        let response = client
+            .dictionary_copy(cache_name, source.name(), destination.name(), None)
+            .await?;
+        assert_eq!(
+            response,
+            DictionaryCopyResponse::Copied {
+                count: source.value().len()
+            }
+        );
+
+        // The destination holds only the source's fields, and the source is unchanged
+        let result = client
+            .dictionary_fetch(cache_name, destination.name())
+            .await?;
+        assert_fetched_dictionary_equals_test_data(result, &source)?;
+        let result = client.dictionary_fetch(cache_name, source.name()).await?;
+        assert_fetched_dictionary_equals_test_data(result, &source)?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn missing_source() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+
+        let destination = TestDictionary::new();
+        client
+            .dictionary_set_fields(cache_name, destination.name(), destination.value().clone())
+            .await?;
+
+        let response = client
+            .dictionary_copy(cache_name, unique_key(), destination.name(), None)
+            .await?;
+        assert_eq!(response, DictionaryCopyResponse::Miss);
+
+        let result = client
+            .dictionary_fetch(cache_name, destination.name())
+            .await?;
+        assert_fetched_dictionary_equals_test_data(result, &destination)?;
+        Ok(())
+    }
+}
+
 mod dictionary_fetch {
     use super::*;
 
@@ -65,6 +126,27 @@ mod dictionary_fetch {
         assert_eq!(result.error_code, MomentoErrorCode::CacheNotFoundError);
         Ok(())
     }
+
+    #[tokio::test]
+    async fn error_includes_collection_name() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = unique_cache_name();
+        let result = client
+            .dictionary_fetch(cache_name.as_str(), "my-dictionary")
+            .await
+            .unwrap_err();
+
+        assert_eq!(result.error_code, MomentoErrorCode::CacheNotFoundError);
+        assert_eq!(
+            result.context(),
+            Some(&ErrorContext {
+                operation: "DictionaryFetch".to_string(),
+                cache_name: Some(cache_name),
+                collection_name: Some("my-dictionary".to_string()),
+            })
+        );
+        Ok(())
+    }
 }
 
 mod dictionary_get_field {
@@ -125,6 +207,93 @@ mod dictionary_get_field {
     }
 }
 
+mod dictionary_get_field_batch {
+    use super::*;
+
+    #[tokio::test]
+    async fn happy_path() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+
+        let first = TestDictionary::new();
+        let second = TestDictionary::new();
+        client
+            .dictionary_set_fields(cache_name, first.name(), first.value().clone())
+            .await?;
+        client
+            .dictionary_set_fields(cache_name, second.name(), second.value().clone())
+            .await?;
+
+        let (first_field, first_value) = first.value().iter().next().unwrap();
+        let (second_field, second_value) = second.value().iter().next().unwrap();
+        let missing_dictionary = unique_key();
+
+        let result: Vec<Option<String>> = client
+            .dictionary_get_field_batch(
+                cache_name,
+                vec![
+                    (first.name(), first_field.as_str()),
+                    (missing_dictionary.as_str(), first_field.as_str()),
+                    (second.name(), second_field.as_str()),
+                    (first.name(), "missing-field"),
+                ],
+            )
+            .await?
+            .try_into()?;
+        assert_eq!(
+            result,
+            vec![
+                Some(first_value.clone()),
+                None,
+                Some(second_value.clone()),
+                None
+            ]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn invalid_cache_name() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let result = client
+            .dictionary_get_field_batch("   ", vec![("my-dictionary", "my-field")])
+            .await
+            .unwrap_err();
+        assert_eq!(result.error_code, MomentoErrorCode::InvalidArgumentError);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn bounded_concurrency_keeps_the_requested_order() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+
+        let item = TestDictionary::new();
+        client
+            .dictionary_set_fields(cache_name, item.name(), item.value().clone())
+            .await?;
+        let missing_dictionary = unique_key();
+
+        // Alternate hits and misses so that any reordering shows up
+        let mut pairs = Vec::new();
+        let mut expected = Vec::new();
+        for _ in 0..5 {
+            for (field, value) in item.value() {
+                pairs.push((item.name().to_string(), field.clone()));
+                expected.push(Some(value.clone()));
+                pairs.push((missing_dictionary.clone(), field.clone()));
+                expected.push(None);
+            }
+        }
+
+        let request =
+            DictionaryGetFieldBatchRequest::new(cache_name, pairs).max_concurrent_requests(3);
+        let result: Vec<Option<String>> = client.send_request(request).await?.try_into()?;
+        assert_eq!(result, expected);
+        Ok(())
+    }
+}
+
 mod dictionary_get_fields {
     use super::*;
 
@@ -469,6 +638,124 @@ mod dictionary_set_fields {
     }
 }
 
+mod dictionary_set_fields_if_not_exists {
+    use super::*;
+
+    fn sorted(mut fields: Vec<String>) -> Vec<String> {
+        fields.sort();
+        fields
+    }
+
+    #[tokio::test]
+    async fn all_absent() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+
+        let item = TestDictionary::new();
+        let response = client
+            .dictionary_set_fields_if_not_exists(cache_name, item.name(), item.value().clone())
+            .await?;
+        assert!(response.existing_fields().is_empty());
+        let written: Vec<String> = response.try_into()?;
+        assert_eq!(
+            sorted(written),
+            sorted(item.value().keys().cloned().collect())
+        );
+
+        let result = client.dictionary_fetch(cache_name, item.name()).await?;
+        assert_fetched_dictionary_equals_test_data(result, &item)?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn all_present() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+
+        let item = TestDictionary::new();
+        client
+            .dictionary_set_fields(cache_name, item.name(), item.value().clone())
+            .await?;
+
+        let overwrites: Vec<(String, String)> = item
+            .value()
+            .keys()
+            .map(|field| (field.clone(), unique_value()))
+            .collect();
+        let response = client
+            .dictionary_set_fields_if_not_exists(cache_name, item.name(), overwrites)
+            .await?;
+        assert!(response.written_fields().is_empty());
+        assert_eq!(response.existing_fields().len(), item.value().len());
+
+        let result = client.dictionary_fetch(cache_name, item.name()).await?;
+        assert_fetched_dictionary_equals_test_data(result, &item)?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn mixed() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+
+        let dictionary_name = unique_key();
+        let existing_field = unique_key();
+        let new_field = unique_key();
+        client
+            .dictionary_set_field(
+                cache_name,
+                dictionary_name.as_str(),
+                existing_field.as_str(),
+                "original",
+            )
+            .await?;
+
+        let response = client
+            .dictionary_set_fields_if_not_exists(
+                cache_name,
+                dictionary_name.as_str(),
+                vec![
+                    (existing_field.as_str(), "replacement"),
+                    (new_field.as_str(), "added"),
+                ],
+            )
+            .await?;
+        assert_eq!(
+            response.existing_fields(),
+            &[existing_field.clone().into_bytes()]
+        );
+        assert_eq!(response.written_fields(), &[new_field.clone().into_bytes()]);
+
+        let result: HashMap<String, String> = client
+            .dictionary_fetch(cache_name, dictionary_name.as_str())
+            .await?
+            .try_into()?;
+        assert_eq!(
+            result,
+            HashMap::from([
+                (existing_field, "original".to_string()),
+                (new_field, "added".to_string()),
+            ])
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn invalid_cache_name() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let result = client
+            .dictionary_set_fields_if_not_exists(
+                "   ",
+                "my-dictionary",
+                TestDictionary::default().value().clone(),
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(result.error_code, MomentoErrorCode::InvalidArgumentError);
+        Ok(())
+    }
+}
+
 mod dictionary_length {
     use super::*;
 
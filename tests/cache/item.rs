@@ -1,5 +1,5 @@
 use momento::{
-    cache::{ItemGetTypeResponse, ItemType},
+    cache::{ItemGetTypeBatchRequest, ItemGetTypeResponse, ItemType},
     MomentoErrorCode, MomentoResult,
 };
 use momento_test_util::{unique_cache_name, TestScalar, TestSet, TestSortedSet, CACHE_TEST_STATE};
@@ -91,3 +91,112 @@ mod item_get_type {
         Ok(())
     }
 }
+
+mod delete_and_report_type {
+    use super::*;
+
+    #[tokio::test]
+    async fn happy_path() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = CACHE_TEST_STATE.cache_name.as_str();
+        let item = TestSet::new();
+
+        // Expect miss when key is not set
+        let result = client
+            .delete_and_report_type(cache_name, item.name())
+            .await?;
+        assert_eq!(result, ItemGetTypeResponse::Miss {});
+
+        client
+            .set_add_elements(cache_name, item.name(), item.value().to_vec())
+            .await?;
+        let result = client
+            .delete_and_report_type(cache_name, item.name())
+            .await?;
+        assert_eq!(
+            result,
+            ItemGetTypeResponse::Hit {
+                key_type: ItemType::Set
+            }
+        );
+
+        // Expect the item to be gone
+        let result = client.item_get_type(cache_name, item.name()).await?;
+        assert_eq!(result, ItemGetTypeResponse::Miss {});
+        Ok(())
+    }
+}
+
+mod item_get_type_batch {
+    use std::collections::HashMap;
+    use std::convert::TryInto;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn invalid_cache_name() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let result = client
+            .item_get_type_batch("   ", vec!["a", "b"])
+            .await
+            .unwrap_err();
+        assert_eq!(result.error_code, MomentoErrorCode::InvalidArgumentError);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn happy_path() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = CACHE_TEST_STATE.cache_name.as_str();
+        let scalar = TestScalar::new();
+        let set = TestSet::new();
+        let missing = TestScalar::new();
+
+        client.set(cache_name, scalar.key(), scalar.value()).await?;
+        client
+            .set_add_elements(cache_name, set.name(), set.value().to_vec())
+            .await?;
+
+        let response = client
+            .item_get_type_batch(cache_name, vec![scalar.key(), set.name(), missing.key()])
+            .await?;
+        let item_types: HashMap<String, ItemGetTypeResponse> =
+            response.try_into().expect("string keys");
+        assert_eq!(item_types.len(), 3);
+        assert_eq!(
+            item_types[scalar.key()],
+            ItemGetTypeResponse::Hit {
+                key_type: ItemType::Scalar
+            }
+        );
+        assert_eq!(
+            item_types[set.name()],
+            ItemGetTypeResponse::Hit {
+                key_type: ItemType::Set
+            }
+        );
+        assert_eq!(item_types[missing.key()], ItemGetTypeResponse::Miss);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn bounded_concurrency() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = CACHE_TEST_STATE.cache_name.as_str();
+        let scalars: Vec<TestScalar> = (0..20).map(|_| TestScalar::new()).collect();
+        for scalar in &scalars[..10] {
+            client.set(cache_name, scalar.key(), scalar.value()).await?;
+        }
+
+        let keys: Vec<&str> = scalars.iter().map(|scalar| scalar.key()).collect();
+        let request = ItemGetTypeBatchRequest::new(cache_name, keys).max_concurrent_requests(3);
+        let item_types: HashMap<String, ItemType> =
+            client.send_request(request).await?.try_into()?;
+        let expected: HashMap<String, ItemType> = scalars[..10]
+            .iter()
+            .map(|scalar| (scalar.key().to_string(), ItemType::Scalar))
+            .collect();
+        assert_eq!(item_types, expected);
+        Ok(())
+    }
+}
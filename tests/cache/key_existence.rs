@@ -77,6 +77,21 @@ mod keys_exists {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn empty_keys_skip_the_network_call() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        // The cache doesn't exist, so this only succeeds if no request is sent
+        let cache_name = unique_cache_name();
+        let empty_vector: Vec<String> = vec![];
+        let keys_received: Vec<bool> = client.keys_exist(cache_name, empty_vector).await?.into();
+        assert!(keys_received.is_empty());
+
+        let empty_vector: Vec<String> = vec![];
+        let result = client.keys_exist("   ", empty_vector).await.unwrap_err();
+        assert_eq!(result.error_code, MomentoErrorCode::InvalidArgumentError);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn happy_path() -> MomentoResult<()> {
         let client = &CACHE_TEST_STATE.client;
@@ -1,8 +1,9 @@
 use momento::cache::{
     CollectionTtl, ListConcatenateBackRequest, ListConcatenateBackResponse,
-    ListConcatenateFrontRequest, ListConcatenateFrontResponse, ListFetchResponse,
+    ListConcatenateFrontRequest, ListConcatenateFrontResponse, ListCopyResponse, ListFetchResponse,
     ListLengthResponse, ListPopBackResponse, ListPopFrontResponse, ListPushBackRequest,
     ListPushBackResponse, ListPushFrontRequest, ListPushFrontResponse, ListRemoveValueResponse,
+    ListRetainRequest, ListRetainResponse,
 };
 use momento::{MomentoErrorCode, MomentoResult};
 
@@ -163,6 +164,82 @@ mod list_concatenate_front {
     }
 }
 
+mod list_copy {
+    use super::*;
+
+    #[tokio::test]
+    async fn replaces_destination() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+
+        let source = TestList::new();
+        let destination = TestList::new();
+        client
+            .list_concatenate_back(cache_name, source.name(), source.values().to_vec())
+            .await?;
+        client
+            .list_concatenate_back(
+                cache_name,
+                destination.name(),
+                destination.values().to_vec(),
+            )
+            .await?;
+
+        let response = client
+            .list_copy(
+                cache_name,
+                source.name(),
+                destination.name(),
+                Duration::from_secs(60),
+            )
+            .await?;
+        assert_eq!(
+            response,
+            ListCopyResponse::Copied {
+                count: source.values().len()
+            }
+        );
+
+        // The destination holds only the source's values, in the same order
+        assert_list_eq(
+            client.list_fetch(cache_name, destination.name()).await?,
+            source.values().to_vec(),
+        )?;
+        assert_list_eq(
+            client.list_fetch(cache_name, source.name()).await?,
+            source.values().to_vec(),
+        )?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn missing_source() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+
+        let source = TestList::new();
+        let destination = TestList::new();
+        client
+            .list_concatenate_back(
+                cache_name,
+                destination.name(),
+                destination.values().to_vec(),
+            )
+            .await?;
+
+        let response = client
+            .list_copy(cache_name, source.name(), destination.name(), None)
+            .await?;
+        assert_eq!(response, ListCopyResponse::Miss);
+
+        assert_list_eq(
+            client.list_fetch(cache_name, destination.name()).await?,
+            destination.values().to_vec(),
+        )?;
+        Ok(())
+    }
+}
+
 mod list_length {
     use super::*;
 
@@ -272,6 +349,24 @@ mod list_fetch {
         let fetch_slice = client.send_request(request).await?;
         assert_list_eq(fetch_slice, list2.values().to_vec())?;
 
+        // Fetch list slices using negative ranges
+        let fetch_tail = client
+            .list_fetch_range(cache_name, list1.name(), -2..)
+            .await?;
+        assert_list_eq(fetch_tail, list2.values().to_vec())?;
+
+        let fetch_head = client
+            .list_fetch_range(cache_name, list1.name(), ..-2)
+            .await?;
+        assert_list_eq(fetch_head, list1.values().to_vec())?;
+
+        // Fetch the list as strings
+        let fetch_strings = client.list_fetch_strings(cache_name, list1.name()).await?;
+        assert_eq!(
+            fetch_strings,
+            Some([list1.values().to_vec(), list2.values().to_vec()].concat())
+        );
+
         Ok(())
     }
 }
@@ -634,3 +729,75 @@ mod list_remove {
         Ok(())
     }
 }
+
+mod list_retain {
+    use super::*;
+
+    #[tokio::test]
+    async fn nonexistent_cache() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = unique_cache_name();
+
+        let result = client
+            .list_retain(cache_name, "list", None, None)
+            .await
+            .unwrap_err();
+
+        assert_eq!(result.error_code, MomentoErrorCode::CacheNotFoundError);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn nonexistent_list() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+        let list_name = unique_cache_name();
+
+        let result = client.list_retain(cache_name, list_name, 0, 1).await?;
+        assert_eq!(result, ListRetainResponse::Miss);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn happy_path() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+        let list_name = unique_cache_name();
+        let values: Vec<String> = (0..6).map(|i| i.to_string()).collect();
+
+        client
+            .list_concatenate_back(cache_name, list_name.as_str(), values.clone())
+            .await?;
+
+        // No indices keeps everything
+        let result = client
+            .list_retain(cache_name, list_name.as_str(), None, None)
+            .await?;
+        assert_eq!(result, ListRetainResponse::Hit);
+        assert_list_eq(
+            client.list_fetch(cache_name, list_name.as_str()).await?,
+            values.clone(),
+        )?;
+
+        let result = client
+            .list_retain(cache_name, list_name.as_str(), 1, 5)
+            .await?;
+        assert_eq!(result, ListRetainResponse::Hit);
+        assert_list_eq(
+            client.list_fetch(cache_name, list_name.as_str()).await?,
+            values[1..5].to_vec(),
+        )?;
+
+        // Negative bounds count back from the end: keep the last two of 1, 2, 3, 4
+        let request = ListRetainRequest::new(cache_name, list_name.as_str())
+            .range(-2..)
+            .ttl(CollectionTtl::new(Some(Duration::from_secs(60)), true));
+        let result = client.send_request(request).await?;
+        assert_eq!(result, ListRetainResponse::Hit);
+        assert_list_eq(
+            client.list_fetch(cache_name, list_name.as_str()).await?,
+            values[3..5].to_vec(),
+        )?;
+        Ok(())
+    }
+}
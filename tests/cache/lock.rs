@@ -0,0 +1,218 @@
+use std::time::Duration;
+
+use futures::future::BoxFuture;
+use momento::cache::{configurations, GetResponse, Middleware, RequestMetadata};
+use momento::lock::DistributedLock;
+use momento::{CacheClient, MomentoError, MomentoErrorCode, MomentoResult};
+use momento_test_util::{get_test_credential_provider, unique_key, CACHE_TEST_STATE};
+
+/// Fails every renewal, as if the service could not be reached after the lock was acquired.
+#[derive(Debug)]
+struct FailRenewals;
+
+impl Middleware for FailRenewals {
+    fn on_request<'a>(&'a self, request: &'a RequestMetadata) -> BoxFuture<'a, MomentoResult<()>> {
+        Box::pin(async move {
+            if request.operation != "SetIfEqual" {
+                return Ok(());
+            }
+            Err(MomentoError::new(
+                MomentoErrorCode::ServerUnavailable,
+                "renewal failed",
+            ))
+        })
+    }
+}
+
+/// Never completes a renewal, as if the service stopped responding after the lock was acquired.
+#[derive(Debug)]
+struct StallRenewals;
+
+impl Middleware for StallRenewals {
+    fn on_request<'a>(&'a self, request: &'a RequestMetadata) -> BoxFuture<'a, MomentoResult<()>> {
+        Box::pin(async move {
+            if request.operation == "SetIfEqual" {
+                futures::future::pending::<()>().await;
+            }
+            Ok(())
+        })
+    }
+}
+
+#[tokio::test]
+async fn try_lock_is_exclusive() -> MomentoResult<()> {
+    let client = &CACHE_TEST_STATE.client;
+    let cache_name = CACHE_TEST_STATE.cache_name.as_str();
+    let key = unique_key();
+    let ttl = Duration::from_secs(30);
+
+    let lock = DistributedLock::try_lock(client, cache_name, key.as_str(), ttl).await?;
+    assert!(lock.is_some(), "Expected to acquire a free lock");
+
+    let second = DistributedLock::try_lock(client, cache_name, key.as_str(), ttl).await?;
+    assert!(second.is_none(), "Expected a held lock not to be acquired");
+    Ok(())
+}
+
+#[tokio::test]
+async fn release_frees_the_lock() -> MomentoResult<()> {
+    let client = &CACHE_TEST_STATE.client;
+    let cache_name = CACHE_TEST_STATE.cache_name.as_str();
+    let key = unique_key();
+    let ttl = Duration::from_secs(30);
+
+    let lock = DistributedLock::try_lock(client, cache_name, key.as_str(), ttl)
+        .await?
+        .expect("Expected to acquire a free lock");
+    assert!(lock.release().await?);
+
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    let lock = DistributedLock::try_lock(client, cache_name, key.as_str(), ttl).await?;
+    assert!(lock.is_some(), "Expected to acquire a released lock");
+    Ok(())
+}
+
+#[tokio::test]
+async fn release_does_not_free_a_lock_held_by_someone_else() -> MomentoResult<()> {
+    let client = &CACHE_TEST_STATE.client;
+    let cache_name = CACHE_TEST_STATE.cache_name.as_str();
+    let key = unique_key();
+    let ttl = Duration::from_secs(30);
+
+    let lock = DistributedLock::try_lock(client, cache_name, key.as_str(), ttl)
+        .await?
+        .expect("Expected to acquire a free lock");
+    // Simulate the lock expiring and being taken over by another holder
+    client
+        .set(cache_name, key.as_str(), "another-holder")
+        .await?;
+
+    assert!(!lock.release().await?);
+    let result = client.get(cache_name, key.as_str()).await?;
+    assert_eq!(result, GetResponse::from("another-holder"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn renewal_keeps_the_lock_past_its_ttl() -> MomentoResult<()> {
+    let client = &CACHE_TEST_STATE.client;
+    let cache_name = CACHE_TEST_STATE.cache_name.as_str();
+    let key = unique_key();
+
+    let lock = DistributedLock::try_lock(client, cache_name, key.as_str(), Duration::from_secs(3))
+        .await?
+        .expect("Expected to acquire a free lock");
+    tokio::time::sleep(Duration::from_secs(5)).await;
+
+    assert!(lock.is_held());
+    let second =
+        DistributedLock::try_lock(client, cache_name, key.as_str(), Duration::from_secs(3)).await?;
+    assert!(
+        second.is_none(),
+        "Expected a renewed lock not to be acquired"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn failed_renewals_stop_holding_the_lock_after_its_ttl() -> MomentoResult<()> {
+    let client = CacheClient::builder()
+        .default_ttl(Duration::from_secs(30))
+        .configuration(configurations::Laptop::latest())
+        .credential_provider(get_test_credential_provider())
+        .add_middleware(FailRenewals)
+        .build()?;
+    let cache_name = CACHE_TEST_STATE.cache_name.as_str();
+    let key = unique_key();
+    let ttl = Duration::from_secs(1);
+
+    let lock = DistributedLock::try_lock(&client, cache_name, key.as_str(), ttl)
+        .await?
+        .expect("Expected to acquire a free lock");
+
+    // The first failed renewal happens after a third of the ttl; the lock is still held then
+    tokio::time::sleep(ttl / 2).await;
+    assert!(lock.is_held());
+
+    tokio::time::sleep(ttl * 2).await;
+    assert!(
+        !lock.is_held(),
+        "Expected the lock not to be held once its ttl passed without a renewal"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn stalled_renewal_stops_holding_the_lock_after_its_ttl() -> MomentoResult<()> {
+    let client = CacheClient::builder()
+        .default_ttl(Duration::from_secs(30))
+        .configuration(configurations::Laptop::latest())
+        .credential_provider(get_test_credential_provider())
+        .add_middleware(StallRenewals)
+        .build()?;
+    let cache_name = CACHE_TEST_STATE.cache_name.as_str();
+    let key = unique_key();
+    let ttl = Duration::from_secs(1);
+
+    let lock = DistributedLock::try_lock(&client, cache_name, key.as_str(), ttl)
+        .await?
+        .expect("Expected to acquire a free lock");
+    assert!(lock.is_held());
+
+    // The first renewal never returns, so only the ttl can tell that the lock may have expired
+    tokio::time::sleep(ttl + Duration::from_millis(100)).await;
+    assert!(
+        !lock.is_held(),
+        "Expected the lock not to be held once its ttl passed while a renewal was stuck"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn lock_with_timeout_waits_for_release() -> MomentoResult<()> {
+    let client = &CACHE_TEST_STATE.client;
+    let cache_name = CACHE_TEST_STATE.cache_name.as_str();
+    let key = unique_key();
+    let ttl = Duration::from_secs(30);
+
+    let lock = DistributedLock::try_lock(client, cache_name, key.as_str(), ttl)
+        .await?
+        .expect("Expected to acquire a free lock");
+
+    let timed_out = DistributedLock::lock_with_timeout(
+        client,
+        cache_name,
+        key.as_str(),
+        ttl,
+        Duration::from_millis(300),
+    )
+    .await?;
+    assert!(timed_out.is_none(), "Expected to time out on a held lock");
+
+    let waiter = DistributedLock::lock_with_timeout(
+        client,
+        cache_name,
+        key.clone(),
+        ttl,
+        Duration::from_secs(5),
+    );
+    let (acquired, released) = tokio::join!(waiter, lock.release());
+    assert!(released?);
+    assert!(
+        acquired?.is_some(),
+        "Expected to acquire the lock once released"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn ttl_too_short_is_invalid() -> MomentoResult<()> {
+    let client = &CACHE_TEST_STATE.client;
+    let cache_name = CACHE_TEST_STATE.cache_name.as_str();
+    let result = DistributedLock::try_lock(client, cache_name, unique_key(), Duration::ZERO)
+        .await
+        .err()
+        .expect("Expected an error for a zero ttl");
+    assert_eq!(result.error_code, MomentoErrorCode::InvalidArgumentError);
+    Ok(())
+}
@@ -4,7 +4,11 @@ mod dictionary;
 mod item;
 mod key_existence;
 mod list;
+#[cfg(feature = "lock")]
+mod lock;
 mod scalar;
 mod set;
 mod sorted_set;
+#[cfg(feature = "testing")]
+mod testing;
 mod ttl;
@@ -1,18 +1,20 @@
 use momento::cache::{
-    DeleteResponse, GetResponse, SetIfAbsentOrEqualRequest, SetIfAbsentOrEqualResponse,
-    SetIfAbsentRequest, SetIfAbsentResponse, SetIfEqualRequest, SetIfEqualResponse,
-    SetIfNotEqualRequest, SetIfNotEqualResponse, SetIfPresentAndNotEqualRequest,
-    SetIfPresentAndNotEqualResponse, SetIfPresentRequest, SetIfPresentResponse, SetRequest,
-    SetResponse,
+    DeleteResponse, GetRequest, GetResponse, GetWithMetadataResponse, SetIfAbsentBatchRequest,
+    SetIfAbsentOrEqualRequest, SetIfAbsentOrEqualResponse, SetIfAbsentRequest, SetIfAbsentResponse,
+    SetIfEqualRequest, SetIfEqualResponse, SetIfNotEqualRequest, SetIfNotEqualResponse,
+    SetIfPresentAndNotEqualRequest, SetIfPresentAndNotEqualResponse, SetIfPresentRequest,
+    SetIfPresentResponse, SetRequest, SetResponse,
 };
-use momento::{MomentoErrorCode, MomentoResult};
+use momento::{ErrorContext, MomentoErrorCode, MomentoResult};
 use momento_test_util::{
     unique_cache_name, unique_key, unique_string, TestScalar, CACHE_TEST_STATE,
 };
 use std::{convert::TryInto, time::Duration};
 
 mod get_set_delete {
-    use std::time::Duration;
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    use momento::auth::ExpiresAt;
 
     use super::*;
 
@@ -99,6 +101,58 @@ mod get_set_delete {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn set_with_expires_at_is_miss_after_expiration() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = CACHE_TEST_STATE.cache_name.as_str();
+        let item = TestScalar::new();
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("time should not go backwards")
+            .as_secs();
+        let expires_at = ExpiresAt::from_epoch(now + 2);
+        let result = client
+            .set_with_expires_at(cache_name, item.key(), item.value(), expires_at)
+            .await?;
+        assert_eq!(result, SetResponse {});
+
+        let result = client.get(cache_name, item.key()).await?;
+        assert_eq!(result, GetResponse::from(&item));
+
+        // Wait for the expiry time to pass
+        tokio::time::sleep(Duration::from_secs(3)).await;
+
+        let result = client.get(cache_name, item.key()).await?;
+        assert_eq!(
+            result,
+            GetResponse::Miss,
+            "Expected miss for key '{}' in cache {}, got {:?}",
+            item.key(),
+            cache_name,
+            result
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn set_with_expires_at_in_the_past_is_invalid() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = CACHE_TEST_STATE.cache_name.as_str();
+        let item = TestScalar::new();
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("time should not go backwards")
+            .as_secs();
+        let expires_at = ExpiresAt::from_epoch(now - 10);
+        let result = client
+            .set_with_expires_at(cache_name, item.key(), item.value(), expires_at)
+            .await
+            .unwrap_err();
+        assert_eq!(result.error_code, MomentoErrorCode::InvalidArgumentError);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn delete_invalid_cache_name() -> MomentoResult<()> {
         let client = &CACHE_TEST_STATE.client;
@@ -116,6 +170,23 @@ mod get_set_delete {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn error_includes_operation_and_cache_name() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = unique_cache_name();
+        let result = client.get(cache_name.as_str(), "key").await.unwrap_err();
+        assert_eq!(result.error_code, MomentoErrorCode::CacheNotFoundError);
+        assert_eq!(
+            result.context(),
+            Some(&ErrorContext {
+                operation: "Get".to_string(),
+                cache_name: Some(cache_name),
+                collection_name: None,
+            })
+        );
+        Ok(())
+    }
+
     #[tokio::test]
     async fn delete_happy_path() -> MomentoResult<()> {
         let client = &CACHE_TEST_STATE.client;
@@ -151,6 +222,101 @@ mod get_set_delete {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn set_and_get_json() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = CACHE_TEST_STATE.cache_name.as_str();
+        let key = unique_key();
+        let scores: Vec<(String, u32)> = vec![("alice".to_string(), 42), ("bob".to_string(), 7)];
+
+        let set_request = SetRequest::from_json(cache_name, key.as_str(), &scores)?;
+        client.send_request(set_request).await?;
+
+        let stored: Vec<(String, u32)> = client.get(cache_name, key.as_str()).await?.into_json()?;
+        assert_eq!(stored, scores);
+
+        // A value that is not JSON for the requested type is an invalid argument
+        client.set(cache_name, key.as_str(), "not json").await?;
+        let result = client
+            .get(cache_name, key.as_str())
+            .await?
+            .into_json::<Vec<(String, u32)>>()
+            .unwrap_err();
+        assert_eq!(result.error_code, MomentoErrorCode::InvalidArgumentError);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn send_request_raw_returns_the_protobuf_response() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = CACHE_TEST_STATE.cache_name.as_str();
+        let item = TestScalar::new();
+
+        let (response, _raw_response) = client
+            .send_request_raw(SetRequest::new(cache_name, item.key(), item.value()))
+            .await?;
+        assert_eq!(response, SetResponse {});
+
+        let (response, raw_response) = client
+            .send_request_raw(GetRequest::new(cache_name, item.key()))
+            .await?;
+        let value: String = response.try_into()?;
+        assert_eq!(value, item.value());
+        assert_eq!(raw_response.cache_body, item.value().as_bytes());
+
+        let result = client
+            .send_request_raw(GetRequest::new(unique_cache_name(), item.key()))
+            .await
+            .unwrap_err();
+        assert_eq!(result.error_code, MomentoErrorCode::CacheNotFoundError);
+        assert_eq!(result.context().map(|c| c.operation.as_str()), Some("Get"));
+        Ok(())
+    }
+}
+
+mod get_with_metadata {
+    use super::*;
+
+    #[tokio::test]
+    async fn miss_when_key_does_not_exist() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = CACHE_TEST_STATE.cache_name.as_str();
+        let result = client.get_with_metadata(cache_name, unique_key()).await?;
+        assert_eq!(result, GetWithMetadataResponse::Miss);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn hit_returns_value_and_remaining_ttl() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = CACHE_TEST_STATE.cache_name.as_str();
+        let item = TestScalar::new();
+        let ttl = Duration::from_secs(60);
+
+        let set_request = SetRequest::new(cache_name, item.key(), item.value()).ttl(ttl);
+        client.send_request(set_request).await?;
+
+        let (value, remaining_ttl): (String, Duration) = client
+            .get_with_metadata(cache_name, item.key())
+            .await?
+            .try_into()?;
+        assert_eq!(value, item.value());
+        assert!(remaining_ttl > Duration::ZERO && remaining_ttl <= ttl);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn invalid_cache_name() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let result = client
+            .get_with_metadata("   ", unique_key())
+            .await
+            .unwrap_err();
+        assert_eq!(result.error_code, MomentoErrorCode::InvalidArgumentError);
+        Ok(())
+    }
 }
 
 mod increment {
@@ -302,6 +468,93 @@ mod set_if_absent {
     }
 }
 
+mod set_if_absent_batch {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn invalid_cache_name() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let result = client
+            .set_if_absent_batch("   ", vec![("key", "value")], None)
+            .await
+            .unwrap_err();
+        assert_eq!(result.error_code, MomentoErrorCode::InvalidArgumentError);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn nonexistent_cache() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = unique_string("fake-cache");
+        let result = client
+            .set_if_absent_batch(cache_name, vec![("key", "value")], None)
+            .await
+            .unwrap_err();
+        assert_eq!(result.error_code, MomentoErrorCode::CacheNotFoundError);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn happy_path() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = CACHE_TEST_STATE.cache_name.as_str();
+        let held = TestScalar::new();
+        let free = TestScalar::new();
+
+        client.set(cache_name, held.key(), held.value()).await?;
+
+        let response = client
+            .set_if_absent_batch(
+                cache_name,
+                vec![(held.key(), "new-value"), (free.key(), free.value())],
+                Duration::from_secs(30),
+            )
+            .await?;
+        assert_eq!(response.stored_keys(), vec![free.key().as_bytes()]);
+        assert_eq!(response.not_stored_keys(), vec![held.key().as_bytes()]);
+
+        let results: HashMap<String, SetIfAbsentResponse> = response.try_into()?;
+        assert_eq!(
+            results,
+            HashMap::from([
+                (held.key().to_string(), SetIfAbsentResponse::NotStored),
+                (free.key().to_string(), SetIfAbsentResponse::Stored),
+            ])
+        );
+
+        // The held item keeps its value and the free one is stored
+        let result = client.get(cache_name, held.key()).await?;
+        assert_eq!(result, GetResponse::from(&held));
+        let result = client.get(cache_name, free.key()).await?;
+        assert_eq!(result, GetResponse::from(&free));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn bounded_concurrency() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = CACHE_TEST_STATE.cache_name.as_str();
+        let items: Vec<TestScalar> = (0..20).map(|_| TestScalar::new()).collect();
+
+        let request = SetIfAbsentBatchRequest::new(
+            cache_name,
+            items.iter().map(|item| (item.key(), item.value())),
+        )
+        .ttl(Duration::from_secs(30))
+        .max_concurrent_requests(3);
+        let response = client.send_request(request).await?;
+        assert_eq!(response.stored_keys().len(), items.len());
+
+        for item in &items {
+            let result = client.get(cache_name, item.key()).await?;
+            assert_eq!(result, GetResponse::from(item));
+        }
+        Ok(())
+    }
+}
+
 mod set_if_present {
     use super::*;
 
@@ -152,6 +152,34 @@ mod set_fetch {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn fetch_strings() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+        let test_set = TestSet::default();
+
+        // Should be None before set exists
+        let result = client
+            .set_fetch_strings(cache_name, test_set.name())
+            .await?;
+        assert_eq!(result, None);
+
+        client
+            .set_add_elements(cache_name, test_set.name(), test_set.value().to_vec())
+            .await?;
+
+        let mut result = client
+            .set_fetch_strings(cache_name, test_set.name())
+            .await?
+            .expect("Expected the set to exist");
+        result.sort();
+        let mut expected = test_set.value().to_vec();
+        expected.sort();
+        assert_eq!(result, expected);
+
+        Ok(())
+    }
 }
 
 mod set_length {}
@@ -1,9 +1,10 @@
 use std::sync::Arc;
 
 use momento::cache::{
-    IntoSortedSetElements, SortedSetElement, SortedSetElements, SortedSetFetchByRankRequest,
-    SortedSetFetchByScoreRequest, SortedSetFetchResponse, SortedSetGetRankResponse,
-    SortedSetGetScoreResponse, SortedSetLengthResponse,
+    IntoSortedSetElements, SortedSetCopyResponse, SortedSetElement, SortedSetElements,
+    SortedSetFetchByRankRequest, SortedSetFetchByScoreRequest, SortedSetFetchExtremesResponse,
+    SortedSetFetchResponse, SortedSetGetRankResponse, SortedSetGetScoreResponse,
+    SortedSetLengthResponse,
     SortedSetOrder::{Ascending, Descending},
     SortedSetPutElementsResponse, SortedSetRemoveElementsResponse,
 };
@@ -54,7 +55,59 @@ fn assert_fetched_sorted_set_eq_after_sorting(
     assert_fetched_sorted_set_eq(sorted_set_fetch_result, expected)
 }
 
+mod sorted_set_copy {
+    use super::*;
+
+    #[tokio::test]
+    async fn replaces_destination() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+
+        let source = TestSortedSet::new();
+        let destination = TestSortedSet::new();
+        client
+            .sorted_set_put_elements(cache_name, source.name(), source.value().clone())
+            .await?;
+        client
+            .sorted_set_put_elements(cache_name, destination.name(), destination.value().clone())
+            .await?;
+
+        let response = client
+            .sorted_set_copy(cache_name, source.name(), destination.name(), None)
+            .await?;
+        assert_eq!(
+            response,
+            SortedSetCopyResponse::Copied {
+                count: source.value().len()
+            }
+        );
+
+        // The destination holds only the source's elements, with their scores
+        assert_fetched_sorted_set_eq_after_sorting(
+            client
+                .sorted_set_fetch_by_rank(cache_name, destination.name(), Ascending, None, None)
+                .await?,
+            source.value().clone(),
+        )?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn missing_source() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+
+        let response = client
+            .sorted_set_copy(cache_name, unique_key(), unique_key(), None)
+            .await?;
+        assert_eq!(response, SortedSetCopyResponse::Miss);
+        Ok(())
+    }
+}
+
 mod sorted_set_fetch_by_rank {
+    use std::convert::TryInto;
+
     use super::*;
 
     #[tokio::test]
@@ -137,6 +190,44 @@ mod sorted_set_fetch_by_rank {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn page_with_scores() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+        let item = TestSortedSet {
+            name: unique_key(),
+            value: vec![
+                ("1".to_string(), 0.0),
+                ("2".to_string(), 1.0),
+                ("3".to_string(), 0.5),
+            ],
+        };
+        client
+            .sorted_set_put_elements(cache_name, item.name(), item.value().to_vec())
+            .await?;
+
+        let fetch_request = SortedSetFetchByRankRequest::new(cache_name, item.name())
+            .order(Descending)
+            .end_rank(2);
+        let page: Vec<SortedSetElement<String>> =
+            client.send_request(fetch_request).await?.try_into()?;
+        assert_eq!(
+            page,
+            vec![
+                SortedSetElement {
+                    value: "2".to_string(),
+                    score: 1.0
+                },
+                SortedSetElement {
+                    value: "3".to_string(),
+                    score: 0.5
+                },
+            ]
+        );
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn nonexistent_cache() -> MomentoResult<()> {
         let client = &CACHE_TEST_STATE.client;
@@ -153,6 +244,84 @@ mod sorted_set_fetch_by_rank {
     }
 }
 
+mod sorted_set_fetch_extremes {
+    use super::*;
+
+    #[tokio::test]
+    async fn happy_path() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+        let item = TestSortedSet {
+            name: unique_key(),
+            value: vec![
+                ("1".to_string(), 0.0),
+                ("2".to_string(), 1.0),
+                ("3".to_string(), 0.5),
+                ("4".to_string(), 2.0),
+                ("5".to_string(), 1.5),
+            ],
+        };
+
+        let result = client
+            .sorted_set_fetch_extremes(cache_name, item.name(), 2, 2)
+            .await?;
+        assert_eq!(result, SortedSetFetchExtremesResponse::Miss);
+
+        client
+            .sorted_set_put_elements(cache_name, item.name(), item.value().to_vec())
+            .await?;
+
+        let result = client
+            .sorted_set_fetch_extremes(cache_name, item.name(), 2, 3)
+            .await?;
+        let (top, bottom) = match result {
+            SortedSetFetchExtremesResponse::Hit { top, bottom } => (top, bottom),
+            SortedSetFetchExtremesResponse::Miss => panic!("Expected a hit, got a miss"),
+        };
+        assert_eq!(
+            top.into_strings()?,
+            vec![("4".to_string(), 2.0), ("5".to_string(), 1.5)]
+        );
+        assert_eq!(
+            bottom.into_strings()?,
+            vec![
+                ("1".to_string(), 0.0),
+                ("3".to_string(), 0.5),
+                ("2".to_string(), 1.0),
+            ]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn zero_count_is_invalid() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+
+        let result = client
+            .sorted_set_fetch_extremes(cache_name, "sorted-set", 0, 10)
+            .await
+            .unwrap_err();
+
+        assert_eq!(result.error_code, MomentoErrorCode::InvalidArgumentError);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn nonexistent_cache() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = unique_cache_name();
+
+        let result = client
+            .sorted_set_fetch_extremes(cache_name, "sorted-set", 10, 10)
+            .await
+            .unwrap_err();
+
+        assert_eq!(result.error_code, MomentoErrorCode::CacheNotFoundError);
+        Ok(())
+    }
+}
+
 mod sorted_set_fetch_by_score {
     use super::*;
 
@@ -466,6 +635,163 @@ mod sorted_set_increment_score {
     }
 }
 
+mod sorted_set_increment_score_and_get_rank {
+    use momento::cache::SortedSetIncrementScoreAndGetRankResponse;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn happy_path() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+        let sorted_set_name = unique_key();
+
+        client
+            .sorted_set_put_elements(
+                cache_name,
+                sorted_set_name.as_str(),
+                vec![("alice", 10.0), ("bob", 20.0), ("carol", 30.0)],
+            )
+            .await?;
+
+        let result = client
+            .sorted_set_increment_score_and_get_rank(
+                cache_name,
+                sorted_set_name.as_str(),
+                "alice",
+                15.0,
+                Descending,
+            )
+            .await?;
+        assert_eq!(
+            result,
+            SortedSetIncrementScoreAndGetRankResponse {
+                score: 25.0,
+                rank: Some(1)
+            }
+        );
+
+        let result = client
+            .sorted_set_increment_score_and_get_rank(
+                cache_name,
+                sorted_set_name.as_str(),
+                "alice",
+                10.0,
+                Ascending,
+            )
+            .await?;
+        assert_eq!(
+            result,
+            SortedSetIncrementScoreAndGetRankResponse {
+                score: 35.0,
+                rank: Some(2)
+            }
+        );
+
+        // A new element is added with the amount as its score
+        let result = client
+            .sorted_set_increment_score_and_get_rank(
+                cache_name,
+                sorted_set_name.as_str(),
+                "dave",
+                5.0,
+                Descending,
+            )
+            .await?;
+        assert_eq!(
+            result,
+            SortedSetIncrementScoreAndGetRankResponse {
+                score: 5.0,
+                rank: Some(3)
+            }
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn nonexistent_cache() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = unique_cache_name();
+
+        let result = client
+            .sorted_set_increment_score_and_get_rank(
+                cache_name,
+                "sorted-set",
+                "element1",
+                1.0,
+                Ascending,
+            )
+            .await
+            .unwrap_err();
+
+        assert_eq!(result.error_code, MomentoErrorCode::CacheNotFoundError);
+        Ok(())
+    }
+}
+
+mod sorted_set_increment_scores {
+    use std::convert::TryInto;
+
+    use momento::cache::SortedSetIncrementScoresRequest;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn happy_path() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+        let item = TestSortedSet::new();
+
+        client
+            .sorted_set_put_elements(cache_name, item.name(), item.value().to_vec())
+            .await?;
+
+        // Existing values are incremented and new values start at the increment amount
+        let new_value = unique_value();
+        let to_increment = vec![
+            (item.value[0].0.clone(), 0.5),
+            (new_value.clone(), 100.0),
+            (item.value[0].0.clone(), 1.0),
+        ];
+        let request = SortedSetIncrementScoresRequest::new(cache_name, item.name(), to_increment)
+            .max_concurrency(1);
+        let response = client.send_request(request).await?;
+        assert!(response.all_succeeded());
+        let scores: Vec<f64> = response.try_into()?;
+        assert_eq!(scores, vec![1.5, 100.0, 2.5]);
+
+        let result = client
+            .sorted_set_get_score(cache_name, item.name(), new_value)
+            .await?;
+        assert_eq!(result, SortedSetGetScoreResponse::Hit { score: 100.0 });
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn nonexistent_cache_fails_every_increment() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = unique_cache_name();
+
+        let response = client
+            .sorted_set_increment_scores(
+                cache_name,
+                "sorted-set",
+                vec![("element1".to_string(), 1.0), ("element2".to_string(), 2.0)],
+            )
+            .await?;
+
+        assert!(!response.all_succeeded());
+        for result in response.results {
+            assert_eq!(
+                result.unwrap_err().error_code,
+                MomentoErrorCode::CacheNotFoundError
+            );
+        }
+        Ok(())
+    }
+}
+
 mod sorted_set_remove_element {}
 
 mod sorted_set_remove_elements {
@@ -672,6 +998,33 @@ mod sorted_set_put_elements {
         assert_eq!(result.error_code, MomentoErrorCode::CacheNotFoundError);
         Ok(())
     }
+
+    #[tokio::test]
+    async fn put_elements_by() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = CACHE_TEST_STATE.cache_name.as_str();
+        let sorted_set_name = unique_key();
+        let players = vec![("alice".to_string(), 42u32), ("bob".to_string(), 7u32)];
+
+        client
+            .sorted_set_put_elements_by(
+                cache_name,
+                sorted_set_name.as_str(),
+                players,
+                |(_, points)| *points as f64,
+                |(name, _)| name.clone().into_bytes(),
+            )
+            .await?;
+
+        let result = client
+            .sorted_set_fetch_by_score(cache_name, sorted_set_name.as_str(), Ascending)
+            .await?;
+        assert_fetched_sorted_set_eq(
+            result,
+            vec![("bob".to_string(), 7.0), ("alice".to_string(), 42.0)],
+        )?;
+        Ok(())
+    }
 }
 
 mod sorted_set_length {
@@ -715,6 +1068,164 @@ mod sorted_set_length {
     }
 }
 
-mod sorted_set_length_by_score {}
+mod sorted_set_length_by_score {
+    use momento::cache::{ScoreBound, SortedSetLengthByScoreResponse};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn happy_path() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+        let sorted_set_name = unique_key();
+
+        // Miss before sorted set exists
+        let result = client
+            .sorted_set_length_by_score_range(
+                cache_name,
+                sorted_set_name.as_str(),
+                ScoreBound::Unbounded,
+                ScoreBound::Unbounded,
+            )
+            .await?;
+        assert_eq!(result, SortedSetLengthByScoreResponse::Miss);
+
+        client
+            .sorted_set_put_elements(
+                cache_name,
+                sorted_set_name.as_str(),
+                vec![("a", 1.0), ("b", 2.0), ("c", 3.0)],
+            )
+            .await?;
+
+        let result = client
+            .sorted_set_length_by_score_range(
+                cache_name,
+                sorted_set_name.as_str(),
+                ScoreBound::Unbounded,
+                ScoreBound::Unbounded,
+            )
+            .await?;
+        assert_eq!(result, SortedSetLengthByScoreResponse::Hit { length: 3 });
+
+        let result = client
+            .sorted_set_length_by_score_range(
+                cache_name,
+                sorted_set_name.as_str(),
+                ScoreBound::Inclusive(2.0),
+                ScoreBound::Unbounded,
+            )
+            .await?;
+        assert_eq!(result, SortedSetLengthByScoreResponse::Hit { length: 2 });
+
+        let result = client
+            .sorted_set_length_by_score_range(
+                cache_name,
+                sorted_set_name.as_str(),
+                ScoreBound::Exclusive(1.0),
+                ScoreBound::Exclusive(3.0),
+            )
+            .await?;
+        assert_eq!(result, SortedSetLengthByScoreResponse::Hit { length: 1 });
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn nonexistent_cache() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = unique_cache_name();
+
+        let result = client
+            .sorted_set_length_by_score_range(
+                cache_name,
+                "sorted-set",
+                ScoreBound::Unbounded,
+                ScoreBound::Unbounded,
+            )
+            .await
+            .unwrap_err();
+
+        assert_eq!(result.error_code, MomentoErrorCode::CacheNotFoundError);
+        Ok(())
+    }
+}
 
 mod delete_sorted_set {}
+
+mod sorted_set_union {
+    use super::*;
+
+    #[tokio::test]
+    async fn happy_path() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+        let first_name = unique_key();
+        let second_name = unique_key();
+        let missing_name = unique_key();
+
+        // Miss when none of the sources exist
+        let result = client
+            .sorted_set_union(
+                cache_name,
+                vec![(missing_name.as_str(), 1.0)],
+                Ascending,
+                None,
+            )
+            .await?;
+        assert_eq!(result, SortedSetFetchResponse::Miss);
+
+        client
+            .sorted_set_put_elements(
+                cache_name,
+                first_name.as_str(),
+                vec![("a", 1.0), ("b", 2.0)],
+            )
+            .await?;
+        client
+            .sorted_set_put_elements(
+                cache_name,
+                second_name.as_str(),
+                vec![("a", 3.0), ("c", 1.0)],
+            )
+            .await?;
+
+        let sources = vec![
+            (first_name.as_str(), 1.0),
+            (second_name.as_str(), 2.0),
+            (missing_name.as_str(), 1.0),
+        ];
+        let result = client
+            .sorted_set_union(cache_name, sources.clone(), Ascending, None)
+            .await?;
+        assert_fetched_sorted_set_eq(
+            result,
+            vec![
+                ("b".to_string(), 2.0),
+                ("c".to_string(), 2.0),
+                ("a".to_string(), 7.0),
+            ],
+        )?;
+
+        let result = client
+            .sorted_set_union(cache_name, sources, Descending, Some(1))
+            .await?;
+        assert_fetched_sorted_set_eq(result, vec![("a".to_string(), 7.0)])?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn no_sources() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+
+        let result = client
+            .sorted_set_union(cache_name, Vec::<(&str, f64)>::new(), Ascending, None)
+            .await
+            .unwrap_err();
+
+        assert_eq!(result.error_code, MomentoErrorCode::InvalidArgumentError);
+        Ok(())
+    }
+}
@@ -0,0 +1,37 @@
+use std::convert::TryInto;
+
+use momento::testing::TestCache;
+use momento::MomentoResult;
+use momento_test_util::{get_test_credential_provider, CACHE_TEST_STATE};
+
+#[tokio::test]
+async fn test_cache_is_deleted_on_drop() -> MomentoResult<()> {
+    let test_cache = TestCache::new(get_test_credential_provider()).await?;
+    let cache_name = test_cache.cache_name().to_string();
+    assert!(CACHE_TEST_STATE.client.cache_exists(&cache_name).await?);
+
+    test_cache
+        .client()
+        .set(test_cache.cache_name(), "key", "value")
+        .await?;
+    let value: String = test_cache
+        .client()
+        .get(test_cache.cache_name(), "key")
+        .await?
+        .try_into()?;
+    assert_eq!(value, "value");
+
+    drop(test_cache);
+    assert!(!CACHE_TEST_STATE.client.cache_exists(&cache_name).await?);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cache_can_be_deleted_explicitly() -> MomentoResult<()> {
+    let test_cache = TestCache::new(get_test_credential_provider()).await?;
+    let cache_name = test_cache.cache_name().to_string();
+
+    test_cache.delete().await?;
+    assert!(!CACHE_TEST_STATE.client.cache_exists(&cache_name).await?);
+    Ok(())
+}
@@ -3,8 +3,8 @@ use std::time::Duration;
 
 use momento::{
     cache::{
-        CollectionTtl, DecreaseTtlResponse, IncreaseTtlResponse, ItemGetTtlResponse, SetRequest,
-        SortedSetPutElementsRequest, UpdateTtlResponse,
+        CollectionTtl, DecreaseTtlResponse, GetResponse, IncreaseTtlResponse, ItemGetTtlResponse,
+        SetRequest, SortedSetPutElementsRequest, TouchTtlIfBelowResponse, UpdateTtlResponse,
     },
     MomentoErrorCode, MomentoResult,
 };
@@ -387,4 +387,190 @@ mod update_ttl {
         );
         Ok(())
     }
+
+    #[tokio::test]
+    async fn does_not_create_missing_collection() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = CACHE_TEST_STATE.cache_name.as_str();
+        let collection_name = unique_key();
+
+        let result = client
+            .update_ttl(
+                cache_name,
+                collection_name.as_str(),
+                Duration::from_secs(60),
+            )
+            .await?;
+        assert_eq!(result, UpdateTtlResponse::Miss {});
+
+        let result = client.item_get_ttl(cache_name, collection_name).await?;
+        assert_eq!(result, ItemGetTtlResponse::Miss);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn overwrites_ttl_for_existing_collection() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = CACHE_TEST_STATE.cache_name.as_str();
+        let item = TestSortedSet::new();
+
+        client
+            .send_request(
+                SortedSetPutElementsRequest::new(cache_name, item.name(), item.value().to_vec())
+                    .ttl(CollectionTtl::new(Some(Duration::from_secs(10)), true)),
+            )
+            .await?;
+
+        let result = client
+            .update_ttl(cache_name, item.name(), Duration::from_secs(60))
+            .await?;
+        assert_eq!(result, UpdateTtlResponse::Set {});
+
+        let ttl: Duration = client
+            .item_get_ttl(cache_name, item.name())
+            .await?
+            .try_into()
+            .expect("Expected an item ttl!");
+        assert!(
+            ttl > Duration::from_secs(10),
+            "Expected ttl to be refreshed, got {:?}",
+            ttl
+        );
+        Ok(())
+    }
+}
+
+mod touch_ttl_if_below {
+    use super::*;
+
+    #[tokio::test]
+    async fn nonexistent_key() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = CACHE_TEST_STATE.cache_name.as_str();
+        let key = unique_key();
+        let result = client
+            .touch_ttl_if_below(
+                cache_name,
+                key,
+                Duration::from_secs(5),
+                Duration::from_secs(20),
+            )
+            .await?;
+        assert_eq!(result, TouchTtlIfBelowResponse::Miss);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn refreshes_only_below_threshold() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = CACHE_TEST_STATE.cache_name.as_str();
+        let item = TestScalar::new();
+
+        client
+            .send_request(
+                SetRequest::new(cache_name, item.key(), item.value()).ttl(Duration::from_secs(10)),
+            )
+            .await?;
+
+        // Remaining ttl is above the threshold, so it is left alone
+        let result = client
+            .touch_ttl_if_below(
+                cache_name,
+                item.key(),
+                Duration::from_secs(5),
+                Duration::from_secs(20),
+            )
+            .await?;
+        assert!(
+            matches!(result, TouchTtlIfBelowResponse::NotRefreshed { remaining_ttl } if remaining_ttl <= Duration::from_secs(10)),
+            "Expected the ttl not to be refreshed, got {:?}",
+            result
+        );
+
+        // Remaining ttl is below the threshold, so it is refreshed
+        let result = client
+            .touch_ttl_if_below(
+                cache_name,
+                item.key(),
+                Duration::from_secs(15),
+                Duration::from_secs(20),
+            )
+            .await?;
+        assert_eq!(result, TouchTtlIfBelowResponse::Refreshed);
+
+        let ttl_after: Duration = client
+            .item_get_ttl(cache_name, item.key())
+            .await?
+            .try_into()
+            .expect("Expected an item ttl!");
+        assert!(
+            ttl_after.as_secs() > 10 && ttl_after.as_secs() <= 20,
+            "Expected ttl to be >10 and <=20 seconds, got {:?}",
+            ttl_after
+        );
+        Ok(())
+    }
+}
+
+mod get_sliding {
+    use super::*;
+
+    #[tokio::test]
+    async fn nonexistent_key() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = CACHE_TEST_STATE.cache_name.as_str();
+        let key = unique_key();
+        let result = client
+            .get_sliding(cache_name, key.as_str(), Duration::from_secs(20))
+            .await?;
+        assert_eq!(result, GetResponse::Miss);
+
+        // The ttl update must not create the item
+        let result = client.get(cache_name, key).await?;
+        assert_eq!(result, GetResponse::Miss);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn hit_resets_ttl() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = CACHE_TEST_STATE.cache_name.as_str();
+        let item = TestScalar::new();
+
+        client
+            .send_request(
+                SetRequest::new(cache_name, item.key(), item.value()).ttl(Duration::from_secs(5)),
+            )
+            .await?;
+
+        let result = client
+            .get_sliding(cache_name, item.key(), Duration::from_secs(20))
+            .await?;
+        let value: String = result.try_into()?;
+        assert_eq!(value, item.value());
+
+        let ttl_after: Duration = client
+            .item_get_ttl(cache_name, item.key())
+            .await?
+            .try_into()
+            .expect("Expected an item ttl!");
+        assert!(
+            ttl_after.as_secs() > 5 && ttl_after.as_secs() <= 20,
+            "Expected ttl to be >5 and <=20 seconds, got {:?}",
+            ttl_after
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn nonexistent_cache() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = unique_cache_name();
+        let result = client
+            .get_sliding(cache_name, "key", Duration::from_secs(20))
+            .await
+            .unwrap_err();
+        assert_eq!(result.error_code, MomentoErrorCode::CacheNotFoundError);
+        Ok(())
+    }
 }
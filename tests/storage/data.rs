@@ -147,3 +147,219 @@ mod get_set_delete {
         Ok(())
     }
 }
+
+mod put_if_absent {
+    use super::*;
+    use momento::storage::PutIfAbsentResponse;
+
+    #[tokio::test]
+    async fn stores_fresh_key() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.storage_client;
+        let store_name = CACHE_TEST_STATE.store_name.as_str();
+        let item = TestScalar::new();
+
+        let result = client
+            .put_if_absent(store_name, item.key(), item.value())
+            .await?;
+        assert_eq!(result, PutIfAbsentResponse::Stored);
+
+        let result = client.get(store_name, item.key()).await?;
+        assert_eq!(result, GetResponse::from(&item.value));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn does_not_overwrite_existing_key() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.storage_client;
+        let store_name = CACHE_TEST_STATE.store_name.as_str();
+        let item = TestScalar::new();
+
+        client.put(store_name, item.key(), item.value()).await?;
+        let result = client.put_if_absent(store_name, item.key(), 42).await?;
+        assert_eq!(result, PutIfAbsentResponse::NotStored);
+
+        let result = client.get(store_name, item.key()).await?;
+        assert_eq!(result, GetResponse::from(&item.value));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn nonexistent_store() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.storage_client;
+        let store_name = unique_store_name();
+        let result = client
+            .put_if_absent(store_name, "key", "value")
+            .await
+            .unwrap_err();
+        assert_eq!(result.error_code, MomentoErrorCode::StoreNotFoundError);
+        Ok(())
+    }
+}
+
+mod batch {
+    use super::*;
+    use momento::storage::{PutBatchRequest, StorageValue};
+    use momento_test_util::unique_key;
+    use std::collections::HashMap;
+    use std::convert::TryInto;
+
+    #[tokio::test]
+    async fn get_batch_hits_and_misses() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.storage_client;
+        let store_name = CACHE_TEST_STATE.store_name.as_str();
+        let (found1, found2, missing) = (unique_key(), unique_key(), unique_key());
+
+        client
+            .put_batch(
+                store_name,
+                vec![(found1.clone(), "value1"), (found2.clone(), "value2")],
+            )
+            .await?;
+
+        let keys = vec![found1.clone(), found2.clone(), missing.clone()];
+        let responses: HashMap<String, GetResponse> =
+            client.get_batch(store_name, keys.clone()).await?.into();
+        assert_eq!(
+            responses,
+            HashMap::from([
+                (found1.clone(), GetResponse::from("value1")),
+                (found2.clone(), GetResponse::from("value2")),
+                (missing, GetResponse::NotFound),
+            ])
+        );
+
+        let values: HashMap<String, String> =
+            client.get_batch(store_name, keys).await?.try_into()?;
+        assert_eq!(
+            values,
+            HashMap::from([
+                (found1, "value1".to_string()),
+                (found2, "value2".to_string())
+            ])
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn batch_keeps_value_types() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.storage_client;
+        let store_name = CACHE_TEST_STATE.store_name.as_str();
+        let (key1, key2) = (unique_key(), unique_key());
+
+        client
+            .send_request(
+                PutBatchRequest::new(store_name, vec![(key1.clone(), 1), (key2.clone(), 2)])
+                    .max_concurrent_requests(1),
+            )
+            .await?;
+
+        let values: HashMap<String, i64> = client
+            .get_batch(store_name, vec![key1.clone(), key2.clone()])
+            .await?
+            .try_into()?;
+        assert_eq!(
+            values,
+            HashMap::from([(key1.clone(), 1), (key2.clone(), 2)])
+        );
+
+        let values: HashMap<String, StorageValue> =
+            client.get_batch(store_name, vec![key1, key2]).await?.into();
+        assert!(values
+            .values()
+            .all(|value| matches!(value, StorageValue::Integer(_))));
+
+        let strings: MomentoResult<HashMap<String, String>> = client
+            .get_batch(store_name, values.into_keys())
+            .await?
+            .try_into();
+        assert_eq!(strings.unwrap_err().error_code, MomentoErrorCode::TypeError);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn empty_batch() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.storage_client;
+        let store_name = unique_store_name();
+        let keys: Vec<String> = vec![];
+        let responses: HashMap<String, GetResponse> =
+            client.get_batch(store_name, keys).await?.into();
+        assert!(responses.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn nonexistent_store() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.storage_client;
+        let store_name = unique_store_name();
+        let result = client.get_batch(store_name, vec!["key"]).await.unwrap_err();
+        assert_eq!(result.error_code, MomentoErrorCode::StoreNotFoundError);
+        Ok(())
+    }
+}
+
+mod typed_accessors {
+    use super::*;
+    use momento::storage::StorageValueType;
+    use momento_test_util::unique_key;
+
+    #[tokio::test]
+    async fn reads_back_each_type() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.storage_client;
+        let store_name = CACHE_TEST_STATE.store_name.as_str();
+        let (int_key, double_key, string_key, bytes_key) =
+            (unique_key(), unique_key(), unique_key(), unique_key());
+
+        client.put(store_name, &int_key, 42).await?;
+        client.put(store_name, &double_key, 4.2).await?;
+        client.put(store_name, &string_key, "value").await?;
+        client.put(store_name, &bytes_key, vec![1u8, 2, 3]).await?;
+
+        let response = client.get(store_name, &int_key).await?;
+        assert_eq!(response.value_type(), Some(StorageValueType::Integer));
+        assert_eq!(response.as_i64()?, 42);
+        assert_eq!(
+            response.as_f64().unwrap_err().error_code,
+            MomentoErrorCode::InvalidArgumentError
+        );
+
+        let response = client.get(store_name, &double_key).await?;
+        assert_eq!(response.value_type(), Some(StorageValueType::Double));
+        assert_eq!(response.as_f64()?, 4.2);
+        assert_eq!(
+            response.as_i64().unwrap_err().error_code,
+            MomentoErrorCode::InvalidArgumentError
+        );
+
+        let response = client.get(store_name, &string_key).await?;
+        assert_eq!(response.value_type(), Some(StorageValueType::String));
+        assert_eq!(response.as_string()?, "value");
+        assert_eq!(
+            response.as_bytes().unwrap_err().error_code,
+            MomentoErrorCode::InvalidArgumentError
+        );
+
+        let response = client.get(store_name, &bytes_key).await?;
+        assert_eq!(response.value_type(), Some(StorageValueType::Bytes));
+        assert_eq!(response.as_bytes()?, &[1u8, 2, 3]);
+        assert_eq!(
+            response.as_string().unwrap_err().error_code,
+            MomentoErrorCode::InvalidArgumentError
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn not_found() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.storage_client;
+        let store_name = CACHE_TEST_STATE.store_name.as_str();
+
+        let response = client.get(store_name, unique_key()).await?;
+        assert_eq!(response.value_type(), None);
+        assert_eq!(
+            response.as_i64().unwrap_err().error_code,
+            MomentoErrorCode::ItemNotFoundError
+        );
+        Ok(())
+    }
+}
@@ -2,7 +2,7 @@ use futures::StreamExt;
 use momento::topics::TopicPublishResponse;
 use momento::{MomentoErrorCode, MomentoResult};
 use momento_test_util::CACHE_TEST_STATE;
-use momento_test_util::{unique_cache_name, unique_topic_name};
+use momento_test_util::{unique_cache_name, unique_key, unique_topic_name};
 
 mod publish_and_subscribe {
     use std::convert::TryInto;
@@ -55,4 +55,103 @@ mod publish_and_subscribe {
         subscription_handle.abort();
         Ok(())
     }
+
+    #[tokio::test]
+    async fn set_and_publish() -> MomentoResult<()> {
+        let cache_client = &CACHE_TEST_STATE.client;
+        let topic_client = &CACHE_TEST_STATE.topic_client;
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+        let topic_name = unique_topic_name();
+        let key = unique_key();
+
+        let mut subscription = topic_client.subscribe(cache_name, &topic_name).await?;
+        let result = cache_client
+            .set_and_publish(topic_client, cache_name, &*key, "value", &topic_name, &*key)
+            .await?;
+        assert_eq!(result, TopicPublishResponse {});
+
+        let message: String = subscription
+            .next()
+            .await
+            .expect("Expected a message")
+            .try_into()
+            .expect("Expected to receive a string");
+        assert_eq!(message, key);
+
+        let value: String = cache_client.get(cache_name, &*key).await?.try_into()?;
+        assert_eq!(value, "value");
+        Ok(())
+    }
+}
+
+mod publish_stream {
+    use std::convert::TryInto;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn publishes_every_value_in_order() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.topic_client;
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+        let topic_name = unique_topic_name();
+        let values: Vec<String> = (0..10).map(|i| format!("value-{}", i)).collect();
+
+        let mut subscription = client.subscribe(cache_name, &topic_name).await?;
+        let response = client
+            .publish_stream(
+                cache_name,
+                &topic_name,
+                futures::stream::iter(values.clone()),
+                1,
+            )
+            .await?;
+        assert_eq!(response.published, values.len());
+        assert_eq!(response.failed(), 0);
+
+        for expected in values {
+            let message: String = subscription
+                .next()
+                .await
+                .expect("Expected a message")
+                .try_into()
+                .expect("Expected to receive a string");
+            assert_eq!(message, expected);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reports_errors_per_value() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.topic_client;
+        let cache_name = unique_cache_name();
+
+        let response = client
+            .publish_stream(
+                &cache_name,
+                "topic",
+                futures::stream::iter(vec!["a", "b", "c"]),
+                2,
+            )
+            .await?;
+        assert_eq!(response.published, 0);
+        let indexes: Vec<usize> = response.errors.iter().map(|(index, _)| *index).collect();
+        assert_eq!(indexes, vec![0, 1, 2]);
+        for (_, error) in response.errors {
+            assert_eq!(error.error_code, MomentoErrorCode::CacheNotFoundError);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn zero_concurrency_is_invalid() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.topic_client;
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+
+        let result = client
+            .publish_stream(cache_name, "topic", futures::stream::iter(vec!["a"]), 0)
+            .await
+            .unwrap_err();
+        assert_eq!(result.error_code, MomentoErrorCode::InvalidArgumentError);
+        Ok(())
+    }
 }